@@ -0,0 +1,211 @@
+/// Creates a Bound-like enum with custom variant names.
+///
+/// See [`examples::BoundExample`](crate::examples::BoundExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_bound;
+///
+/// alias_bound!(Limit, Closed, Open, Unlimited);
+///
+/// let closed = Limit::Closed(5);
+/// let unlimited: Limit<i32> = Limit::Unlimited;
+///
+/// assert!(closed.is_closed());
+/// assert!(!closed.is_open());
+/// assert!(unlimited.is_unlimited());
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_bound;
+/// alias_bound!(Limit, Closed, Open, Unlimited);
+///
+/// let open = Limit::Open(10);
+/// assert_eq!(open.map(|x| x * 2), Limit::Open(20));
+/// assert_eq!(Limit::<i32>::Unlimited.map(|x| x * 2), Limit::Unlimited);
+///
+/// let owned = Limit::Closed("low".to_string());
+/// assert_eq!(owned.as_ref(), Limit::Closed(&"low".to_string()));
+/// assert_eq!(owned.as_ref().cloned(), owned);
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `Bound<T>`.
+///
+/// ```
+/// use enumizer::alias_bound;
+/// use std::ops::Bound;
+/// alias_bound!(Limit, Closed, Open, Unlimited);
+///
+/// let from_bound: Limit<i32> = Bound::Excluded(3).into();
+/// assert_eq!(from_bound, Limit::Open(3));
+///
+/// let to_bound: Bound<i32> = Limit::Closed(3).into();
+/// assert_eq!(to_bound, Bound::Included(3));
+/// ```
+///
+/// # Range Bounds
+///
+/// A pair of limits can be turned into a `RangeBounds` implementation with `range_bounds`,
+/// and passed to any API that accepts a range.
+///
+/// ```
+/// use enumizer::alias_bound;
+/// use std::collections::BTreeSet;
+/// use std::ops::RangeBounds;
+/// alias_bound!(Limit, Closed, Open, Unlimited);
+///
+/// let range = Limit::range_bounds(Limit::Open(2), Limit::Closed(4));
+/// assert!(!range.contains(&2));
+/// assert!(range.contains(&4));
+///
+/// let set: BTreeSet<i32> = (0..10).collect();
+/// let selected: Vec<_> = set
+///     .range(Limit::range_bounds(Limit::Closed(7), Limit::Unlimited))
+///     .collect();
+/// assert_eq!(selected, [&7, &8, &9]);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_bound;
+/// alias_bound!(CustomBound, Inclusive, Exclusive, Infinite, traits: [Debug, Clone]);
+/// let val = CustomBound::Inclusive(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Inclusive(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_bound {
+    ($type_name:ident, $included_variant:ident, $excluded_variant:ident, $unbounded_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_bound [$type_name, $included_variant, $excluded_variant, $unbounded_variant] [T] [Debug, Clone, Copy, PartialEq, Eq, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $included_variant:ident, $excluded_variant:ident, $unbounded_variant:ident], [$T:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$T] [$type_name [$included_variant($T), $excluded_variant($T), $unbounded_variant]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T> {
+            $included_variant($T),
+            $excluded_variant($T),
+            $unbounded_variant,
+        }
+
+        #[allow(dead_code)]
+        impl<T> $type_name<T> {
+            /// Returns `true` if the bound is the included variant.
+            $vis fn [<is_ $included_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$included_variant(_))
+            }
+
+            /// Returns `true` if the bound is the excluded variant.
+            $vis fn [<is_ $excluded_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$excluded_variant(_))
+            }
+
+            /// Returns `true` if the bound is the unbounded variant.
+            $vis fn [<is_ $unbounded_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$unbounded_variant)
+            }
+
+            /// Behaves like [`Bound::as_ref`](https://doc.rust-lang.org/std/ops/enum.Bound.html#method.as_ref)
+            $vis fn as_ref(&self) -> $type_name<&T> {
+                match self {
+                    $type_name::$included_variant(v) => $type_name::$included_variant(v),
+                    $type_name::$excluded_variant(v) => $type_name::$excluded_variant(v),
+                    $type_name::$unbounded_variant => $type_name::$unbounded_variant,
+                }
+            }
+
+            /// Behaves like [`Bound::as_mut`](https://doc.rust-lang.org/std/ops/enum.Bound.html#method.as_mut)
+            $vis fn as_mut(&mut self) -> $type_name<&mut T> {
+                match self {
+                    $type_name::$included_variant(v) => $type_name::$included_variant(v),
+                    $type_name::$excluded_variant(v) => $type_name::$excluded_variant(v),
+                    $type_name::$unbounded_variant => $type_name::$unbounded_variant,
+                }
+            }
+
+            /// Behaves like [`Bound::map`](https://doc.rust-lang.org/std/ops/enum.Bound.html#method.map)
+            $vis fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U> {
+                match self {
+                    $type_name::$included_variant(v) => $type_name::$included_variant(f(v)),
+                    $type_name::$excluded_variant(v) => $type_name::$excluded_variant(f(v)),
+                    $type_name::$unbounded_variant => $type_name::$unbounded_variant,
+                }
+            }
+
+            /// Combines a start and an end limit into a pair that implements `RangeBounds<T>`.
+            $vis fn range_bounds(start: Self, end: Self) -> (::core::ops::Bound<T>, ::core::ops::Bound<T>) {
+                (start.into(), end.into())
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T: Clone> $type_name<&T> {
+            /// Behaves like [`Bound::cloned`](https://doc.rust-lang.org/std/ops/enum.Bound.html#method.cloned)
+            $vis fn cloned(self) -> $type_name<T> {
+                self.map(Clone::clone)
+            }
+        }
+
+        impl<T> From<::core::ops::Bound<T>> for $type_name<T> {
+            fn from(bound: ::core::ops::Bound<T>) -> Self {
+                match bound {
+                    ::core::ops::Bound::Included(v) => $type_name::$included_variant(v),
+                    ::core::ops::Bound::Excluded(v) => $type_name::$excluded_variant(v),
+                    ::core::ops::Bound::Unbounded => $type_name::$unbounded_variant,
+                }
+            }
+        }
+
+        impl<T> From<$type_name<T>> for ::core::ops::Bound<T> {
+            fn from(val: $type_name<T>) -> Self {
+                match val {
+                    $type_name::$included_variant(v) => ::core::ops::Bound::Included(v),
+                    $type_name::$excluded_variant(v) => ::core::ops::Bound::Excluded(v),
+                    $type_name::$unbounded_variant => ::core::ops::Bound::Unbounded,
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::ops::Bound;
+
+    alias_bound!(Limit, Closed, Open, Unlimited);
+
+    #[test]
+    fn range_bounds() {
+        let set: BTreeSet<u8> = (1..=6).collect();
+        let range = Limit::range_bounds(Limit::Open(2), Limit::Closed(4));
+        assert!(set.range(range).eq(&[3, 4]));
+        let range = Limit::range_bounds(Limit::Closed(5), Limit::Unlimited);
+        assert!(set.range(range).eq(&[5, 6]));
+    }
+
+    #[test]
+    fn map() {
+        assert_eq!(Limit::Closed(2).map(|x| x * 2), Limit::Closed(4));
+        assert_eq!(Limit::Open(2u8).map(u16::from), Limit::Open(2u16));
+        assert_eq!(Limit::<u8>::Unlimited.map(u16::from), Limit::Unlimited);
+
+        let mut limit = Limit::Open(1);
+        if let Limit::Open(v) = limit.as_mut() {
+            *v += 1;
+        }
+        assert_eq!(limit.as_ref().cloned(), Limit::Open(2));
+        assert_eq!(Bound::from(limit), Bound::Excluded(2));
+        assert_eq!(Limit::from(Bound::Included(3)), Limit::Closed(3));
+    }
+}
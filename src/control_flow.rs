@@ -0,0 +1,302 @@
+/// Creates a ControlFlow-like enum with custom variant names.
+///
+/// See [`examples::ControlFlowExample`](crate::examples::ControlFlowExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_control_flow;
+///
+/// alias_control_flow!(Step, Proceed, Halt);
+///
+/// let proceed: Step<&str> = Step::Proceed(());
+/// let halt: Step<&str> = Step::Halt("found it");
+///
+/// assert!(proceed.is_proceed());
+/// assert!(halt.is_halt());
+/// assert_eq!(halt.halt_value(), Some("found it"));
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_control_flow;
+/// alias_control_flow!(Step, Proceed, Halt);
+///
+/// let halt: Step<i32, String> = Step::Halt(10);
+/// assert_eq!(halt.clone().proceed_value(), None);
+/// assert_eq!(halt.map_halt(|x| x * 2), Step::Halt(20));
+///
+/// let proceed: Step<i32, String> = Step::Proceed("next".to_string());
+/// assert_eq!(proceed.clone().map_proceed(|s| s.len()), Step::Proceed(4));
+/// assert_eq!(proceed.proceed_value(), Some("next".to_string()));
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `ControlFlow<B, C>`.
+///
+/// ```
+/// use enumizer::alias_control_flow;
+/// use std::ops::ControlFlow;
+/// alias_control_flow!(Step, Proceed, Halt);
+///
+/// let from_break: Step<i32> = ControlFlow::Break(42).into();
+/// assert_eq!(from_break, Step::Halt(42));
+///
+/// let to_control_flow: ControlFlow<i32> = Step::Proceed(()).into();
+/// assert_eq!(to_control_flow, ControlFlow::Continue(()));
+///
+/// // Convert at the boundary of `Iterator::try_for_each`
+/// let first_negative: Step<i32> = [1, -2, 3]
+///     .into_iter()
+///     .try_for_each(|x| if x < 0 { ControlFlow::Break(x) } else { ControlFlow::Continue(()) })
+///     .into();
+/// assert_eq!(first_negative, Step::Halt(-2));
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_control_flow;
+/// alias_control_flow!(CustomStep, Next, Stop, traits: [Debug, Clone]);
+/// let val: CustomStep<i32> = CustomStep::Stop(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Stop(42)");
+/// ```
+///
+/// # Try Trait Support (Nightly Only)
+///
+/// Add `implement_try` to enable the `?` operator for early returns, like `ControlFlow<B, C>`.
+/// Requires nightly Rust with `#![feature(try_trait_v2, try_trait_v2_residual)]`.
+///
+/// ```ignore
+/// #![feature(try_trait_v2, try_trait_v2_residual)]
+/// use enumizer::alias_control_flow;
+///
+/// alias_control_flow!(Step, Proceed, Halt, implement_try);
+///
+/// fn check(x: i32) -> Step<String, i32> {
+///     if x < 0 { Step::Halt(format!("{x} is negative")) } else { Step::Proceed(x) }
+/// }
+///
+/// fn sum(a: i32, b: i32) -> Step<String, i32> {
+///     Step::Proceed(check(a)? + check(b)?)
+/// }
+///
+/// assert_eq!(sum(1, 2), Step::Proceed(3));
+/// assert_eq!(sum(1, -2), Step::Halt("-2 is negative".to_string()));
+/// ```
+#[macro_export]
+macro_rules! alias_control_flow {
+    ($type_name:ident, $continue_variant:ident, $break_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_control_flow [$type_name, $continue_variant, $break_variant] [B, C] [Debug, Clone, Copy, PartialEq, Eq, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $continue_variant:ident, $break_variant:ident], [$B:ident, $C:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        $crate::__alias_impls!(variants [$B, $C] [$type_name [$continue_variant($C), $break_variant($B)]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$B, $C = ()> {
+            $continue_variant($C),
+            $break_variant($B),
+        }
+
+        #[allow(dead_code)]
+        impl<B, C> $type_name<B, C> {
+            /// Behaves like [`ControlFlow::is_continue`](https://doc.rust-lang.org/std/ops/enum.ControlFlow.html#method.is_continue)
+            $vis fn [<is_ $continue_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$continue_variant(_))
+            }
+
+            /// Behaves like [`ControlFlow::is_break`](https://doc.rust-lang.org/std/ops/enum.ControlFlow.html#method.is_break)
+            $vis fn [<is_ $break_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$break_variant(_))
+            }
+
+            /// Behaves like [`ControlFlow::break_value`](https://doc.rust-lang.org/std/ops/enum.ControlFlow.html#method.break_value)
+            $vis fn [<$break_variant:lower _value>](self) -> Option<B> {
+                match self {
+                    $type_name::$continue_variant(_) => None,
+                    $type_name::$break_variant(b) => Some(b),
+                }
+            }
+
+            /// Behaves like [`ControlFlow::continue_value`](https://doc.rust-lang.org/std/ops/enum.ControlFlow.html#method.continue_value)
+            $vis fn [<$continue_variant:lower _value>](self) -> Option<C> {
+                match self {
+                    $type_name::$continue_variant(c) => Some(c),
+                    $type_name::$break_variant(_) => None,
+                }
+            }
+
+            /// Behaves like [`ControlFlow::map_break`](https://doc.rust-lang.org/std/ops/enum.ControlFlow.html#method.map_break)
+            $vis fn [<map_ $break_variant:lower>]<T, F: FnOnce(B) -> T>(self, f: F) -> $type_name<T, C> {
+                match self {
+                    $type_name::$continue_variant(c) => $type_name::$continue_variant(c),
+                    $type_name::$break_variant(b) => $type_name::$break_variant(f(b)),
+                }
+            }
+
+            /// Behaves like [`ControlFlow::map_continue`](https://doc.rust-lang.org/std/ops/enum.ControlFlow.html#method.map_continue)
+            $vis fn [<map_ $continue_variant:lower>]<T, F: FnOnce(C) -> T>(self, f: F) -> $type_name<B, T> {
+                match self {
+                    $type_name::$continue_variant(c) => $type_name::$continue_variant(f(c)),
+                    $type_name::$break_variant(b) => $type_name::$break_variant(b),
+                }
+            }
+        }
+
+        impl<B, C> From<::core::ops::ControlFlow<B, C>> for $type_name<B, C> {
+            fn from(flow: ::core::ops::ControlFlow<B, C>) -> Self {
+                match flow {
+                    ::core::ops::ControlFlow::Continue(c) => $type_name::$continue_variant(c),
+                    ::core::ops::ControlFlow::Break(b) => $type_name::$break_variant(b),
+                }
+            }
+        }
+
+        impl<B, C> From<$type_name<B, C>> for ::core::ops::ControlFlow<B, C> {
+            fn from(val: $type_name<B, C>) -> Self {
+                match val {
+                    $type_name::$continue_variant(c) => ::core::ops::ControlFlow::Continue(c),
+                    $type_name::$break_variant(b) => ::core::ops::ControlFlow::Break(b),
+                }
+            }
+        }
+        }
+
+        $(
+            $crate::__alias_control_flow_flag!($flag, $type_name, $continue_variant, $break_variant);
+        )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_control_flow_flag {
+    (implement_try, $type_name:ident, $continue_variant:ident, $break_variant:ident) => {
+        impl<B, C> ::core::ops::Try for $type_name<B, C> {
+            type Output = C;
+            type Residual = $type_name<B, ::core::convert::Infallible>;
+
+            fn from_output(output: Self::Output) -> Self {
+                $type_name::$continue_variant(output)
+            }
+
+            fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, Self::Output> {
+                match self {
+                    $type_name::$continue_variant(c) => ::core::ops::ControlFlow::Continue(c),
+                    $type_name::$break_variant(b) => {
+                        ::core::ops::ControlFlow::Break($type_name::$break_variant(b))
+                    }
+                }
+            }
+        }
+
+        impl<B, C> ::core::ops::FromResidual for $type_name<B, C> {
+            fn from_residual(residual: $type_name<B, ::core::convert::Infallible>) -> Self {
+                match residual {
+                    $type_name::$continue_variant(never) => match never {},
+                    $type_name::$break_variant(b) => $type_name::$break_variant(b),
+                }
+            }
+        }
+
+        impl<B, C> ::core::ops::Residual<C> for $type_name<B, ::core::convert::Infallible> {
+            type TryType = $type_name<B, C>;
+        }
+    };
+    (assert_same_size, $type_name:ident, $continue_variant:ident, $break_variant:ident) => {
+        $crate::__alias_assert_same_size!([], [
+            $type_name<u8, u16> => ::core::ops::ControlFlow<u8, u16>,
+            $type_name<bool> => ::core::ops::ControlFlow<bool>,
+            $type_name<::core::num::NonZeroU32> => ::core::ops::ControlFlow<::core::num::NonZeroU32>,
+            $type_name<&'static u8> => ::core::ops::ControlFlow<&'static u8>,
+            $type_name<&'static [u8], ::core::convert::Infallible> => ::core::ops::ControlFlow<&'static [u8], ::core::convert::Infallible>
+        ], []);
+    };
+    (generate_tests, $type_name:ident, $continue_variant:ident, $break_variant:ident) => {
+        paste::paste! {
+            #[cfg(test)]
+            mod [<$type_name:snake _conformance>] {
+                use super::*;
+                use ::core::ops::ControlFlow;
+
+                const VALUES: [ControlFlow<u8, u16>; 2] = [ControlFlow::Continue(1), ControlFlow::Break(2)];
+
+                fn alias(value: ControlFlow<u8, u16>) -> $type_name<u8, u16> {
+                    value.into()
+                }
+
+                #[test]
+                fn round_trip() {
+                    for value in VALUES {
+                        assert_eq!(ControlFlow::from(alias(value)), value);
+                    }
+                }
+
+                #[test]
+                fn size_equivalence() {
+                    assert_eq!(::core::mem::size_of::<$type_name<u8, u16>>(), ::core::mem::size_of::<ControlFlow<u8, u16>>());
+                    assert_eq!(::core::mem::size_of::<$type_name<&u8>>(), ::core::mem::size_of::<ControlFlow<&u8>>());
+                }
+
+                #[test]
+                fn methods() {
+                    for value in VALUES {
+                        assert_eq!(alias(value).[<is_ $continue_variant:lower>](), value.is_continue());
+                        assert_eq!(alias(value).[<is_ $break_variant:lower>](), value.is_break());
+                        assert_eq!(alias(value).[<$continue_variant:lower _value>](), value.continue_value());
+                        assert_eq!(alias(value).[<$break_variant:lower _value>](), value.break_value());
+                        assert_eq!(
+                            ControlFlow::from(alias(value).[<map_ $break_variant:lower>](u32::from)),
+                            value.map_break(u32::from)
+                        );
+                        assert_eq!(
+                            ControlFlow::from(alias(value).[<map_ $continue_variant:lower>](u32::from)),
+                            value.map_continue(u32::from)
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    alias_control_flow!(Step, Proceed, Halt);
+
+    #[test]
+    fn values() {
+        let proceed: Step<&str, u8> = Step::Proceed(1);
+        let halt: Step<&str, u8> = Step::Halt("stop");
+        assert_eq!(proceed.proceed_value(), Some(1));
+        assert_eq!(proceed.halt_value(), None);
+        assert_eq!(halt.halt_value(), Some("stop"));
+        assert_eq!(proceed.map_proceed(u16::from), Step::Proceed(1u16));
+        assert_eq!(halt.map_halt(str::len), Step::Halt(4));
+        assert_eq!(halt.map_proceed(u16::from), Step::Halt("stop"));
+    }
+
+    #[test]
+    fn conversions() {
+        let flow = (1..10).try_for_each(|x| {
+            ControlFlow::from(if x < 3 {
+                Step::Proceed(())
+            } else {
+                Step::Halt(x)
+            })
+        });
+        assert_eq!(Step::from(flow), Step::Halt(3));
+        assert_eq!(
+            ControlFlow::from(Step::<u8>::Proceed(())),
+            ControlFlow::Continue(())
+        );
+    }
+}
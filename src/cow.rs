@@ -0,0 +1,262 @@
+/// Creates a Cow-like enum with custom variant names.
+///
+/// See [`examples::CowExample`](crate::examples::CowExample) for a generated example.
+///
+/// Like `Cow<'a, B>`, the generated type is generic over a lifetime and a `?Sized` borrowed type
+/// implementing `ToOwned`. The standard traits (`Clone`, `Debug`, `Display`, `PartialEq`, `Eq`,
+/// `PartialOrd`, `Ord`, `Hash`, `Default`) are implemented the same way `Cow` implements them,
+/// comparing and hashing through the borrowed value regardless of the variant.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_cow;
+///
+/// alias_cow!(Text, Borrowed, Owned);
+///
+/// let borrowed: Text<str> = Text::Borrowed("hello");
+/// let owned: Text<str> = Text::Owned("hello".to_string());
+///
+/// assert!(borrowed.is_borrowed());
+/// assert!(owned.is_owned());
+/// assert_eq!(borrowed, owned);
+/// assert_eq!(borrowed.len(), 5);
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_cow;
+/// alias_cow!(Text, Borrowed, Owned);
+///
+/// let mut text: Text<str> = Text::Borrowed("hello");
+/// text.to_mut().push_str(" world");
+/// assert!(text.is_owned());
+/// assert_eq!(&*text, "hello world");
+///
+/// let owned: String = Text::Borrowed("borrowed").into_owned();
+/// assert_eq!(owned, "borrowed");
+///
+/// let numbers: Text<[i32]> = Text::Borrowed(&[1, 2, 3]);
+/// assert_eq!(numbers.into_owned(), vec![1, 2, 3]);
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `Cow<'a, B>`.
+///
+/// ```
+/// use enumizer::alias_cow;
+/// use std::borrow::Cow;
+/// alias_cow!(Text, Borrowed, Owned);
+///
+/// let from_cow: Text<str> = Cow::Borrowed("value").into();
+/// assert!(from_cow.is_borrowed());
+///
+/// let to_cow: Cow<str> = Text::<str>::Owned("value".to_string()).into();
+/// assert!(matches!(to_cow, Cow::Owned(_)));
+///
+/// let from_ref: Text<str> = "value".into();
+/// assert_eq!(from_ref, Text::Borrowed("value"));
+/// ```
+#[macro_export]
+macro_rules! alias_cow {
+    ($type_name:ident, $borrowed_variant:ident, $owned_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_cow [$type_name, $borrowed_variant, $owned_variant] [B] [] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $borrowed_variant:ident, $owned_variant:ident], [$B:ident], [], [], [], [], [$(#[$attr:meta])*], []) => {
+        paste::paste! {
+        $(#[$attr])*
+        $vis enum $type_name<'a, $B: ?Sized + 'a + std::borrow::ToOwned> {
+            $borrowed_variant(&'a $B),
+            $owned_variant(<$B as std::borrow::ToOwned>::Owned),
+        }
+
+        #[allow(dead_code)]
+        impl<'a, B: ?Sized + std::borrow::ToOwned> $type_name<'a, B> {
+            /// Behaves like [`Cow::is_borrowed`](https://doc.rust-lang.org/std/borrow/enum.Cow.html#method.is_borrowed)
+            $vis fn [<is_ $borrowed_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$borrowed_variant(_))
+            }
+
+            /// Behaves like [`Cow::is_owned`](https://doc.rust-lang.org/std/borrow/enum.Cow.html#method.is_owned)
+            $vis fn [<is_ $owned_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$owned_variant(_))
+            }
+
+            /// Behaves like [`Cow::to_mut`](https://doc.rust-lang.org/std/borrow/enum.Cow.html#method.to_mut)
+            $vis fn to_mut(&mut self) -> &mut <B as std::borrow::ToOwned>::Owned {
+                if let $type_name::$borrowed_variant(borrowed) = *self {
+                    *self = $type_name::$owned_variant(borrowed.to_owned());
+                }
+                match self {
+                    $type_name::$borrowed_variant(_) => unreachable!(),
+                    $type_name::$owned_variant(owned) => owned,
+                }
+            }
+
+            /// Behaves like [`Cow::into_owned`](https://doc.rust-lang.org/std/borrow/enum.Cow.html#method.into_owned)
+            $vis fn into_owned(self) -> <B as std::borrow::ToOwned>::Owned {
+                match self {
+                    $type_name::$borrowed_variant(borrowed) => borrowed.to_owned(),
+                    $type_name::$owned_variant(owned) => owned,
+                }
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned> ::core::ops::Deref for $type_name<'_, B> {
+            type Target = B;
+
+            fn deref(&self) -> &B {
+                match self {
+                    $type_name::$borrowed_variant(borrowed) => borrowed,
+                    $type_name::$owned_variant(owned) => ::core::borrow::Borrow::borrow(owned),
+                }
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned> AsRef<B> for $type_name<'_, B> {
+            fn as_ref(&self) -> &B {
+                self
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned> ::core::borrow::Borrow<B> for $type_name<'_, B> {
+            fn borrow(&self) -> &B {
+                self
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned> Clone for $type_name<'_, B> {
+            fn clone(&self) -> Self {
+                match self {
+                    $type_name::$borrowed_variant(borrowed) => $type_name::$borrowed_variant(borrowed),
+                    $type_name::$owned_variant(owned) => {
+                        let borrowed: &B = ::core::borrow::Borrow::borrow(owned);
+                        $type_name::$owned_variant(borrowed.to_owned())
+                    }
+                }
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned> ::core::fmt::Debug for $type_name<'_, B>
+        where
+            B: ::core::fmt::Debug,
+            <B as std::borrow::ToOwned>::Owned: ::core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $type_name::$borrowed_variant(borrowed) => ::core::fmt::Debug::fmt(borrowed, f),
+                    $type_name::$owned_variant(owned) => ::core::fmt::Debug::fmt(owned, f),
+                }
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned + ::core::fmt::Display> ::core::fmt::Display for $type_name<'_, B> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&**self, f)
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned + PartialEq> PartialEq for $type_name<'_, B> {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned + Eq> Eq for $type_name<'_, B> {}
+
+        impl<B: ?Sized + std::borrow::ToOwned + PartialOrd> PartialOrd for $type_name<'_, B> {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                (**self).partial_cmp(&**other)
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned + Ord> Ord for $type_name<'_, B> {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned + ::core::hash::Hash> ::core::hash::Hash for $type_name<'_, B> {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                (**self).hash(state)
+            }
+        }
+
+        impl<B: ?Sized + std::borrow::ToOwned> Default for $type_name<'_, B>
+        where
+            <B as std::borrow::ToOwned>::Owned: Default,
+        {
+            fn default() -> Self {
+                $type_name::$owned_variant(Default::default())
+            }
+        }
+
+        impl<'a, B: ?Sized + std::borrow::ToOwned> From<&'a B> for $type_name<'a, B> {
+            fn from(borrowed: &'a B) -> Self {
+                $type_name::$borrowed_variant(borrowed)
+            }
+        }
+
+        impl<'a, B: ?Sized + std::borrow::ToOwned> From<std::borrow::Cow<'a, B>> for $type_name<'a, B> {
+            fn from(cow: std::borrow::Cow<'a, B>) -> Self {
+                match cow {
+                    std::borrow::Cow::Borrowed(borrowed) => $type_name::$borrowed_variant(borrowed),
+                    std::borrow::Cow::Owned(owned) => $type_name::$owned_variant(owned),
+                }
+            }
+        }
+
+        impl<'a, B: ?Sized + std::borrow::ToOwned> From<$type_name<'a, B>> for std::borrow::Cow<'a, B> {
+            fn from(val: $type_name<'a, B>) -> Self {
+                match val {
+                    $type_name::$borrowed_variant(borrowed) => std::borrow::Cow::Borrowed(borrowed),
+                    $type_name::$owned_variant(owned) => std::borrow::Cow::Owned(owned),
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    alias_cow!(Text, Shared, Local);
+
+    #[test]
+    fn to_mut() {
+        let source = String::from("shared");
+        let mut text: Text<str> = Text::from(source.as_str());
+        assert!(text.is_shared());
+        text.to_mut().push('!');
+        assert!(text.is_local());
+        assert_eq!(&*text, "shared!");
+        assert_eq!(source, "shared");
+        text.to_mut().push('?');
+        assert_eq!(text.into_owned(), "shared!?");
+    }
+
+    #[test]
+    fn comparisons() {
+        let shared: Text<str> = Text::Shared("text");
+        let local: Text<str> = Text::Local("text".to_string());
+        assert_eq!(shared, local);
+        assert!(Text::<str>::Shared("a") < local);
+        assert_eq!(format!("{shared} {local:?}"), "text \"text\"");
+        assert!(shared.clone().is_shared());
+        assert!(Text::<str>::default().is_empty());
+    }
+
+    #[test]
+    fn conversions() {
+        let borrowed = Text::from(Cow::Borrowed("text"));
+        assert!(borrowed.is_shared());
+        assert!(matches!(Cow::from(borrowed), Cow::Borrowed("text")));
+        let owned: Text<[u8]> = Text::from(Cow::Owned(vec![1, 2]));
+        assert!(owned.is_local());
+        assert_eq!(Cow::from(owned), Cow::<[u8]>::Owned(vec![1, 2]));
+    }
+}
@@ -0,0 +1,192 @@
+/// Creates an enum like one of the `alias_*` macros, from a declaration that reads like the enum.
+///
+/// The declaration names the std type the enum is like, and maps each of its variants to the new
+/// variant name. It is followed by options, one per statement, which are passed on to the alias
+/// macro. `derive(...)` replaces the default set of derived traits, like `traits:`, and `try`
+/// stands for `implement_try`. Any other statement is passed on as written, e.g. `skip: [map];` or
+/// `error = String;`. The visibility, the attributes and doc comments, and the generic parameter
+/// names of the declaration become the `vis:`, `attrs:` and `generics:` options.
+///
+/// The supported types are `Option`, `Result`, `Either`, `Poll`, `ControlFlow`, `Cow`, `Bound`,
+/// `Ordering`, `IpAddr` and `Entry`. Their variants are listed in the order the matching alias
+/// macro takes them, e.g. `Some` before `None`. Types with two variants accept either order.
+///
+/// # Example
+///
+/// ```
+/// enumizer::define! {
+///     /// The response of a remote call.
+///     pub enum Response like Result { Ok => Success, Err => Failure }
+///     derive(Debug, Clone, PartialEq);
+///     error = String;
+/// }
+///
+/// let response: Response<u8> = Response::Success(1);
+/// assert!(response.is_success());
+/// assert_eq!(Result::from(response), Ok(1));
+/// ```
+///
+/// # Options
+///
+/// Options that take a value end with a semicolon like the flags, and are written as they would be
+/// in the alias macro.
+///
+/// ```
+/// enumizer::define! {
+///     pub enum Response like Result { Ok => Success, Err => Failure }
+///     derive(Debug, Clone);
+///     method_prefix: response_;
+/// }
+///
+/// let response: Response<u8, ()> = Response::Success(1);
+/// assert_eq!(response.response_unwrap(), 1);
+/// ```
+///
+/// ```
+/// enumizer::define! {
+///     pub(crate) enum Slot<Item> like Option { None => Empty, Some => Filled }
+///     skip: [map];
+///     bounds: [Copy];
+///     none_last;
+/// }
+///
+/// let mut slots = vec![Slot::Empty, Slot::Filled(2), Slot::Filled(1)];
+/// slots.sort();
+/// assert_eq!(slots, [Slot::Filled(1), Slot::Filled(2), Slot::Empty]);
+/// ```
+///
+/// # Try Trait Support (Nightly Only)
+///
+/// ```ignore
+/// #![feature(try_trait_v2, try_trait_v2_residual)]
+///
+/// enumizer::define! {
+///     enum Lookup like Option { Some => Found, None => Missing }
+///     try;
+/// }
+/// ```
+#[macro_export]
+macro_rules! define {
+    ($(#[$attr:meta])* $vis:vis enum $type_name:ident $(<$($generic:ident),+ $(,)?>)? like $kind:ident { $($variants:tt)* } $($body:tt)*) => {
+        $crate::define!(@body [$kind $type_name { $($variants)* }] [, vis: $vis, attrs: [$(#[$attr]),*] $(, generics: [$($generic),+])?] [] $($body)*);
+    };
+    (@body $decl:tt [$($option:tt)*] [derive($($trait:tt)*)] ; $($rest:tt)*) => {
+        $crate::define!(@body $decl [$($option)*, traits: [$($trait)*]] [] $($rest)*);
+    };
+    (@body $decl:tt [$($option:tt)*] [try] ; $($rest:tt)*) => {
+        $crate::define!(@body $decl [$($option)*, implement_try] [] $($rest)*);
+    };
+    (@body $decl:tt [$($option:tt)*] [$($statement:tt)+] ; $($rest:tt)*) => {
+        $crate::define!(@body $decl [$($option)*, $($statement)+] [] $($rest)*);
+    };
+    (@body $decl:tt $options:tt [] ; $($rest:tt)*) => {
+        $crate::define!(@body $decl $options [] $($rest)*);
+    };
+    (@body $decl:tt $options:tt [$($statement:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::define!(@body $decl $options [$($statement)* $next] $($rest)*);
+    };
+    (@body $decl:tt $options:tt [$($statement:tt)+]) => {
+        $crate::define!(@body $decl $options [$($statement)+] ;);
+    };
+    (@body [$kind:ident $type_name:ident $variants:tt] [$($option:tt)*] []) => {
+        $crate::define!(@route $kind $type_name $variants [$($option)*]);
+    };
+    (@route Option $type_name:ident { Some => $some:ident, None => $none:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_option!($type_name, $some, $none $($option)*);
+    };
+    (@route Option $type_name:ident { None => $none:ident, Some => $some:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_option!($type_name, $some, $none $($option)*);
+    };
+    (@route Result $type_name:ident { Ok => $ok:ident, Err => $err:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_result!($type_name, $ok, $err $($option)*);
+    };
+    (@route Result $type_name:ident { Err => $err:ident, Ok => $ok:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_result!($type_name, $ok, $err $($option)*);
+    };
+    (@route Either $type_name:ident { Left => $left:ident, Right => $right:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_either!($type_name, $left, $right $($option)*);
+    };
+    (@route Either $type_name:ident { Right => $right:ident, Left => $left:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_either!($type_name, $left, $right $($option)*);
+    };
+    (@route Poll $type_name:ident { Ready => $ready:ident, Pending => $pending:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_poll!($type_name, $ready, $pending $($option)*);
+    };
+    (@route Poll $type_name:ident { Pending => $pending:ident, Ready => $ready:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_poll!($type_name, $ready, $pending $($option)*);
+    };
+    (@route ControlFlow $type_name:ident { Continue => $continue:ident, Break => $break:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_control_flow!($type_name, $continue, $break $($option)*);
+    };
+    (@route ControlFlow $type_name:ident { Break => $break:ident, Continue => $continue:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_control_flow!($type_name, $continue, $break $($option)*);
+    };
+    (@route Cow $type_name:ident { Borrowed => $borrowed:ident, Owned => $owned:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_cow!($type_name, $borrowed, $owned $($option)*);
+    };
+    (@route Cow $type_name:ident { Owned => $owned:ident, Borrowed => $borrowed:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_cow!($type_name, $borrowed, $owned $($option)*);
+    };
+    (@route IpAddr $type_name:ident { V4 => $v4:ident, V6 => $v6:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_ipaddr!($type_name, $v4, $v6 $($option)*);
+    };
+    (@route IpAddr $type_name:ident { V6 => $v6:ident, V4 => $v4:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_ipaddr!($type_name, $v4, $v6 $($option)*);
+    };
+    (@route Entry $type_name:ident { Occupied => $occupied:ident, Vacant => $vacant:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_entry!($type_name, $occupied, $vacant $($option)*);
+    };
+    (@route Entry $type_name:ident { Vacant => $vacant:ident, Occupied => $occupied:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_entry!($type_name, $occupied, $vacant $($option)*);
+    };
+    (@route Bound $type_name:ident { Included => $included:ident, Excluded => $excluded:ident, Unbounded => $unbounded:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_bound!($type_name, $included, $excluded, $unbounded $($option)*);
+    };
+    (@route Ordering $type_name:ident { Less => $less:ident, Equal => $equal:ident, Greater => $greater:ident $(,)? } [$($option:tt)*]) => {
+        $crate::alias_ordering!($type_name, $less, $equal, $greater $($option)*);
+    };
+    (@route $kind:ident $type_name:ident $variants:tt $options:tt) => {
+        compile_error!(concat!(
+            "`define!` can not create `", stringify!($type_name), "` like `", stringify!($kind), " ",
+            stringify!($variants), "`, expected a supported type with each of its variants mapped once, e.g. `like Result { Ok => Success, Err => Failure }`",
+        ));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    mod declared {
+        crate::define! {
+            pub(super) enum Fetch<Value> like Poll { Ready => Fetched, Pending => Fetching }
+            derive(Debug, PartialEq);
+            generate_tests
+        }
+
+        crate::define! {
+            #[serde(rename_all = "snake_case")]
+            pub(super) enum Step like ControlFlow { Break => Stop, Continue => Next }
+            traits_add: [serde::Serialize];
+            assert_same_size;
+        }
+    }
+
+    #[test]
+    fn statements() {
+        let fetch: declared::Fetch<u8> = declared::Fetch::Fetched(1);
+        assert_eq!(fetch.map(u16::from), declared::Fetch::Fetched(1));
+        let step: declared::Step<(), u8> = declared::Step::Next(1);
+        assert_eq!(serde_json::to_string(&step).unwrap(), r#"{"next":1}"#);
+    }
+
+    #[test]
+    fn like_either() {
+        crate::define! {
+            enum Route like Either { Left => Local, Right => Remote }
+            left_is_ok;
+            rename: [flip => swap];
+        }
+
+        let route: Route<u8, &str> = Ok(1).into();
+        assert_eq!(route.swap(), Route::Remote(1));
+    }
+}
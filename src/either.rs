@@ -28,8 +28,359 @@
 /// assert_eq!(val.as_primary(), Some(&10));
 /// assert_eq!(val.as_secondary(), None);
 ///
+/// // Consuming accessors return an `Option`, which converts into any option alias.
+/// assert_eq!(val.clone().primary(), Some(10));
+/// assert_eq!(val.clone().secondary(), None);
+/// enumizer::alias_option!(Slot, Filled, Empty);
+/// let slot: Slot<i32> = val.clone().primary().into();
+/// assert_eq!(slot, Slot::Filled(10));
+///
+/// // Fallible extraction returns the other side as the error. (Generic `TryFrom<Choice<L, R>> for L`
+/// // impls are not possible, since the orphan rules forbid implementing a foreign trait for `L`.)
+/// assert_eq!(val.clone().try_into_primary(), Ok(10));
+/// assert_eq!(val.clone().try_into_secondary(), Err(10));
+///
 /// let doubled = val.map_primary(|x| x * 2);
 /// assert_eq!(doubled.as_primary(), Some(&20));
+///
+/// let chained: Choice<i32, String> = doubled
+///     .clone()
+///     .primary_and_then(|x| if x > 10 { Choice::Secondary(format!("{x} too big")) } else { Choice::Primary(x) })
+///     .secondary_and_then(|s| Choice::Secondary(s.to_uppercase()));
+/// assert_eq!(chained, Choice::Secondary("20 TOO BIG".to_string()));
+///
+/// let both: Choice<String, usize> = doubled.clone().map_either(|x| x.to_string(), |s| s.len());
+/// assert_eq!(both, Choice::Primary("20".to_string()));
+/// let scaled: Choice<i32, usize> = doubled.clone().map_either_with(3, |k, x| x * k, |k, s| s.len() * k as usize);
+/// assert_eq!(scaled, Choice::Primary(60));
+///
+/// let flipped: Choice<String, i32> = doubled.clone().flip();
+/// assert_eq!(flipped, Choice::Secondary(20));
+///
+/// // Collapse to one side with a fallback
+/// assert_eq!(doubled.clone().primary_or(0), 20);
+/// assert_eq!(doubled.clone().secondary_or_default(), "");
+/// assert_eq!(doubled.secondary_or_else(|x| x.to_string()), "20");
+/// let secondary: Choice<i32, String> = Choice::Secondary("text".to_string());
+/// assert_eq!(secondary.clone().primary_or_else(|s| s.len() as i32), 4);
+/// assert_eq!(secondary.clone().primary_or_default(), 0);
+/// assert_eq!(secondary.secondary_or("other".to_string()), "text");
+/// ```
+///
+/// # Borrowed Views
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Choice, Primary, Secondary);
+/// let mut val: Choice<i32, String> = Choice::Secondary("text".to_string());
+///
+/// let view: Choice<&i32, &String> = val.as_ref();
+/// assert_eq!(view.cloned(), val);
+///
+/// if let Choice::Secondary(s) = val.as_mut() {
+///     s.push('!');
+/// }
+/// assert_eq!(val.as_secondary().map(String::as_str), Some("text!"));
+///
+/// let num: Choice<i32, u8> = Choice::Primary(1);
+/// assert_eq!(num.as_ref().copied(), num);
+/// ```
+///
+/// # Folding
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Choice, Primary, Secondary);
+/// let primary: Choice<i32, String> = Choice::Primary(10);
+/// let secondary: Choice<i32, String> = Choice::Secondary("text".to_string());
+///
+/// assert_eq!(primary.clone().either(|x| x as usize, |s| s.len()), 10);
+/// assert_eq!(secondary.clone().either(|x| x as usize, |s| s.len()), 4);
+///
+/// let mut log = Vec::new();
+/// primary.either_with(&mut log, |log, x| log.push(format!("primary {x}")), |log, s| log.push(s));
+/// secondary.either_with(&mut log, |log, x| log.push(format!("primary {x}")), |log, s| log.push(s));
+/// assert_eq!(log, ["primary 10", "text"]);
+///
+/// // When both sides share a type, the value can be extracted directly.
+/// let same: Choice<i32, i32> = Choice::Secondary(7);
+/// assert_eq!(same.into_inner(), 7);
+///
+/// // When one side converts into the other, both collapse into a single type.
+/// let name: Choice<String, &str> = Choice::Secondary("borrowed");
+/// assert_eq!(name.either_into::<String>(), "borrowed");
+/// ```
+///
+/// # Factoring
+///
+/// Nested `Option`s and `Result`s that share a shape on both sides can be factored out.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Choice, Primary, Secondary);
+///
+/// let found: Choice<Option<i32>, Option<String>> = Choice::Primary(Some(1));
+/// assert_eq!(found.factor_none(), Some(Choice::Primary(1)));
+/// let missing: Choice<Option<i32>, Option<String>> = Choice::Secondary(None);
+/// assert_eq!(missing.factor_none(), None);
+///
+/// let parsed: Choice<Result<i32, String>, Result<bool, String>> = Choice::Secondary(Ok(true));
+/// assert_eq!(parsed.factor_err(), Ok(Choice::Secondary(true)));
+///
+/// let failed: Choice<Result<i32, u8>, Result<i32, String>> = Choice::Primary(Err(4));
+/// assert_eq!(failed.factor_ok(), Err(Choice::Primary(4)));
+/// ```
+///
+/// # Deref Delegation
+///
+/// When both sides dereference to the same target, the generated type does too.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Label, Custom, Builtin);
+///
+/// let custom: Label<String, &'static str> = Label::Custom("mine".to_string());
+/// let builtin: Label<String, &'static str> = Label::Builtin("default");
+/// assert_eq!(custom.len() + builtin.len(), 11);
+/// assert_eq!(&*builtin, "default");
+/// ```
+///
+/// # Extend and AsRef Delegation
+///
+/// `Extend`, `AsRef` and `AsMut` are delegated when both sides implement them, so the generated
+/// type can stand in for buffers and sinks. Note that the inherent `as_ref` method takes precedence
+/// in method-call syntax; the trait impls are used by generic code such as `impl AsRef<str>`.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Buffer, Owned, Shared);
+///
+/// fn shout(text: impl AsRef<str>) -> String {
+///     text.as_ref().to_uppercase()
+/// }
+///
+/// let text: Buffer<String, Box<str>> = Buffer::Shared("hello".into());
+/// assert_eq!(shout(&text), "HELLO");
+///
+/// let mut sink: Buffer<Vec<u8>, std::collections::VecDeque<u8>> = Buffer::Owned(Vec::new());
+/// sink.extend(*b"raw");
+/// assert_eq!(sink.unwrap_owned(), b"raw");
+///
+/// let bytes: Buffer<Vec<u8>, &[u8]> = Buffer::Shared(b"raw");
+/// assert_eq!(AsRef::<[u8]>::as_ref(&bytes), b"raw");
+/// ```
+///
+/// # Display and Error Delegation
+///
+/// When both sides implement `Display` (and `Error`), so does the generated type, forwarding to
+/// whichever side is present. This makes it a lightweight two-way error type.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(ParseFailure, Int, Float);
+///
+/// fn parse(s: &str) -> Result<f64, ParseFailure<std::num::ParseIntError, std::num::ParseFloatError>> {
+///     if s.contains('.') {
+///         s.parse::<f64>().map_err(ParseFailure::Float)
+///     } else {
+///         s.parse::<i64>().map(|v| v as f64).map_err(ParseFailure::Int)
+///     }
+/// }
+///
+/// assert_eq!(parse("x").unwrap_err().to_string(), "invalid digit found in string");
+/// let boxed: Box<dyn std::error::Error> = Box::new(parse("1.x").unwrap_err());
+/// assert_eq!(boxed.to_string(), "invalid float literal");
+/// ```
+///
+/// # Iterator Delegation
+///
+/// When both sides are iterators over the same item type, the generated type is an iterator too.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Choice, Primary, Secondary);
+///
+/// fn evens_or_odds(evens: bool) -> Choice<impl Iterator<Item = u32>, impl Iterator<Item = u32>> {
+///     if evens {
+///         Choice::Primary((0..10).step_by(2))
+///     } else {
+///         Choice::Secondary((0..10).filter(|x| x % 2 == 1))
+///     }
+/// }
+///
+/// assert_eq!(evens_or_odds(true).collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+/// assert_eq!(evens_or_odds(false).sum::<u32>(), 25);
+///
+/// // Refinements such as `DoubleEndedIterator` and `ExactSizeIterator` are delegated as well.
+/// let forward: Choice<std::ops::Range<u32>, std::vec::IntoIter<u32>> = Choice::Primary(0..3);
+/// assert_eq!(forward.len(), 3);
+/// assert_eq!(forward.rev().collect::<Vec<_>>(), [2, 1, 0]);
+/// ```
+///
+/// # I/O Delegation
+///
+/// Add `implement_io` to delegate `Read`, `BufRead`, `Write` and `Seek` to whichever side is
+/// present, so that e.g. `Choice<File, Stdin>` can be used directly as a reader.
+///
+/// ```
+/// use enumizer::alias_either;
+/// use std::io::{BufRead, Cursor, Read, Write};
+/// alias_either!(Source, Memory, Bytes, implement_io);
+///
+/// let mut source: Source<Cursor<Vec<u8>>, &[u8]> = Source::Bytes(b"first\nsecond");
+/// let mut line = String::new();
+/// source.read_line(&mut line).unwrap();
+/// assert_eq!(line, "first\n");
+/// let mut rest = String::new();
+/// source.read_to_string(&mut rest).unwrap();
+/// assert_eq!(rest, "second");
+///
+/// let mut sink: Source<Vec<u8>, Cursor<Vec<u8>>> = Source::Memory(Vec::new());
+/// write!(sink, "{}-{}", 1, 2).unwrap();
+/// assert_eq!(sink.as_memory().unwrap(), b"1-2");
+/// ```
+///
+/// # Future Delegation
+///
+/// When both sides are futures with the same output, the generated type is a future too, so async
+/// code can return one of two futures without boxing. By default both futures have to be `Unpin`,
+/// like `as_pin_ref` and `as_pin_mut`, so the generated code needs no `unsafe`.
+///
+/// ```
+/// use enumizer::alias_either;
+/// use std::future::{ready, Future};
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// alias_either!(Fetch, Cached, Remote);
+///
+/// fn fetch(cached: bool) -> Fetch<impl Future<Output = u32> + Unpin, impl Future<Output = u32> + Unpin> {
+///     if cached {
+///         Fetch::Cached(ready(1))
+///     } else {
+///         Fetch::Remote(Box::pin(async { 2 }))
+///     }
+/// }
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(pin!(fetch(true)).poll(&mut cx), Poll::Ready(1));
+/// assert_eq!(pin!(fetch(false)).poll(&mut cx), Poll::Ready(2));
+/// ```
+///
+/// Add `pin_project` to drop the `Unpin` requirement, e.g. for `async` blocks. The projections then
+/// use `unsafe` code, which is sound because the payloads are pinned whenever the alias is: the
+/// alias is only `Unpin` if both payloads are, and it can not implement `Drop`. Implementing either
+/// of them by hand is an error, and so is combining the flag with `forbid_unsafe`.
+///
+/// ```
+/// use enumizer::alias_either;
+/// use std::future::Future;
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// alias_either!(Fetch, Cached, Remote, pin_project);
+///
+/// fn fetch(cached: bool) -> Fetch<impl Future<Output = u32>, impl Future<Output = u32>> {
+///     if cached {
+///         Fetch::Cached(async { 1 })
+///     } else {
+///         Fetch::Remote(async { 2 })
+///     }
+/// }
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(pin!(fetch(true)).poll(&mut cx), Poll::Ready(1));
+/// assert_eq!(pin!(fetch(false)).as_pin_mut().remote().map(|remote| remote.poll(&mut cx)), Some(Poll::Ready(2)));
+/// ```
+///
+/// ```compile_fail
+/// use enumizer::alias_either;
+/// alias_either!(Fetch, Cached, Remote, pin_project);
+///
+/// impl<L, R> Unpin for Fetch<L, R> {}
+/// ```
+///
+/// # Conversions
+///
+/// Following the `either` crate's convention, the right variant is treated as the ok side when
+/// converting to and from `Result`. Add `left_is_ok` to treat the left variant as ok instead.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Choice, Primary, Secondary);
+/// let from_ok: Choice<String, i32> = Ok(42).into();
+/// assert_eq!(from_ok, Choice::Secondary(42));
+/// let to_result: Result<i32, String> = Choice::Primary("failed".to_string()).into();
+/// assert_eq!(to_result, Err("failed".to_string()));
+/// assert_eq!(Choice::<String, i32>::from_option_or(None, "missing".to_string()), Choice::Primary("missing".to_string()));
+///
+/// alias_either!(Preferred, Main, Fallback, left_is_ok);
+/// let from_ok: Preferred<i32, String> = Ok(42).into();
+/// assert_eq!(from_ok, Preferred::Main(42));
+/// assert_eq!(Preferred::<i32, String>::Fallback("x".to_string()).into_result(), Err("x".to_string()));
+/// assert_eq!(Preferred::from_option_or_else(Some(1), || "missing".to_string()), Preferred::Main(1));
+/// ```
+///
+/// Like the other flags, `left_is_ok` can be given in any position.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Lookup, Hit, Miss, traits: [Debug, PartialEq], left_is_ok, bounds: [Copy]);
+/// assert_eq!(Lookup::<u8, ()>::from(Ok(1)), Lookup::Hit(1));
+/// ```
+///
+/// # Default Variant
+///
+/// Add `default_left` or `default_right` to implement `Default` with the chosen variant holding its
+/// default value, so the generated type can be used in `#[derive(Default)]` structs and with
+/// `std::mem::take`.
+///
+/// ```
+/// use enumizer::alias_either;
+/// alias_either!(Store, Memory, Disk, default_left);
+///
+/// let mut store: Store<Vec<u8>, std::path::PathBuf> = Store::Memory(vec![1, 2]);
+/// assert_eq!(std::mem::take(&mut store), Store::Memory(vec![1, 2]));
+/// assert_eq!(store, Store::Memory(Vec::new()));
+/// ```
+///
+/// # Try Trait Support (Nightly Only)
+///
+/// Add `implement_try` to enable the `?` operator for early returns.
+/// The variant treated as ok by the `Result` conversions continues, and the other variant breaks,
+/// so by default `?` unwraps the right variant and returns early on the left variant. Combine with
+/// `left_is_ok` to unwrap the left variant and break on the right variant instead.
+/// Requires nightly Rust with `#![feature(try_trait_v2, try_trait_v2_residual)]`.
+///
+/// ```ignore
+/// #![feature(try_trait_v2, try_trait_v2_residual)]
+/// use enumizer::alias_either;
+///
+/// alias_either!(Lookup, Hit, Miss, left_is_ok, implement_try);
+///
+/// fn both(a: Lookup<i32, String>, b: Lookup<i32, String>) -> Lookup<i32, String> {
+///     let x = a?;
+///     let y = b?;
+///     Lookup::Hit(x + y)
+/// }
+///
+/// assert_eq!(both(Lookup::Hit(1), Lookup::Hit(2)), Lookup::Hit(3));
+/// assert_eq!(both(Lookup::Miss("a".into()), Lookup::Hit(2)), Lookup::Miss("a".into()));
+/// ```
+///
+/// # `either` Crate Interop
+///
+/// With the `either` cargo feature enabled, the generated type converts to and from
+/// [`either::Either`](https://docs.rs/either/latest/either/enum.Either.html).
+///
+/// ```
+/// # #[cfg(feature = "either")] {
+/// use enumizer::alias_either;
+/// alias_either!(Choice, Primary, Secondary);
+/// let from_either: Choice<i32, String> = either::Either::Left(42).into();
+/// assert_eq!(from_either, Choice::Primary(42));
+///
+/// let to_either: either::Either<i32, String> = Choice::<i32, String>::Secondary("text".to_string()).into();
+/// assert_eq!(to_either, either::Either::Right("text".to_string()));
+/// # }
 /// ```
 ///
 /// # Custom Traits
@@ -48,99 +399,1490 @@
 /// ```
 #[macro_export]
 macro_rules! alias_either {
-    ($type_name:ident, $left_variant:ident, $right_variant:ident) => {
-        $crate::alias_either!($type_name, $left_variant, $right_variant, [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash]);
+    ($type_name:ident, $left_variant:ident, $right_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_validate!(alias_either, $left_variant, $right_variant, [mut, or, or_else, or_default, and_then]);
+        $crate::__alias_options!(alias_either [$type_name, $left_variant, $right_variant] [L, R] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $left_variant:ident, $right_variant:ident], $generics:tt, $bounds:tt, $methods:tt, $skip:tt, $traits:tt, $attrs:tt, [$($flag:ident),*]) => {
+        $crate::alias_either!(@side $vis [$type_name, $left_variant, $right_variant, right], $generics, $bounds, $methods, $skip, $traits, $attrs, [], [$($flag),*]);
     };
-    ($type_name:ident, $left_variant:ident, $right_variant:ident, traits: [$($trait:path),*]) => {
-        $crate::alias_either!($type_name, $left_variant, $right_variant, [$($trait),*]);
+    (@side $vis:vis [$type_name:ident, $left_variant:ident, $right_variant:ident, $ok_side:ident], $generics:tt, $bounds:tt, $methods:tt, $skip:tt, $traits:tt, $attrs:tt, $kept:tt, [left_is_ok $(, $rest:ident)*]) => {
+        $crate::alias_either!(@side $vis [$type_name, $left_variant, $right_variant, left], $generics, $bounds, $methods, $skip, $traits, $attrs, $kept, [$($rest),*]);
     };
-    ($type_name:ident, $left_variant:ident, $right_variant:ident, [$($trait:path),*]) => {
+    (@side $vis:vis [$($args:tt)*], $generics:tt, $bounds:tt, $methods:tt, $skip:tt, $traits:tt, $attrs:tt, [$($kept:ident),*], [$flag:ident $(, $rest:ident)*]) => {
+        $crate::alias_either!(@side $vis [$($args)*], $generics, $bounds, $methods, $skip, $traits, $attrs, [$($kept,)* $flag], [$($rest),*]);
+    };
+    (@side $vis:vis [$($args:tt)*], $generics:tt, $bounds:tt, $methods:tt, $skip:tt, $traits:tt, $attrs:tt, $flags:tt, []) => {
+        $crate::alias_either!(@sided $vis [$($args)*], $generics, $bounds, $methods, $skip, $traits, $attrs, $flags);
+    };
+    (@sided $vis:vis [$type_name:ident, $left_variant:ident, $right_variant:ident, $ok_side:ident], [$L:ident, $R:ident], [$($bound:tt)*], [$($method:ident),*], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        $crate::__alias_either_flag!(@flags $ok_side, [$($bound)*], [$($method),*], [$($skip),*], $type_name, $left_variant, $right_variant, [$($flag),*]);
+
+        $crate::__alias_variant_attrs!($type_name, [$left_variant, $right_variant], [$($skip),*], alias_either ([$vis] [$type_name, $left_variant, $right_variant], [$L, $R], [$($bound)*], [$($trait),*], [$(#[$attr])*]));
+        $crate::__alias_unsized!($vis $type_name, [$L, $R], [$($skip),*]);
+        $crate::__alias_serde!(either [$vis] [[$($bound)*], $type_name, $left_variant, $right_variant], [$($skip),*]);
+        $crate::__alias_impls!(either [$L, $R] [[$($bound)*], $type_name, $left_variant, $right_variant], [$($skip),*]);
+
+        $crate::__alias_forbid_unsafe!([$($skip),*], {
+        $crate::__alias_either_result!($ok_side, [$vis], [$($bound)*], [$($method),*], [$($skip),*], $type_name, $left_variant, $right_variant);
+        $crate::__alias_methods!(conversions, [$($method),*], {
+            $crate::__alias_either_interop!([$($bound)*], $type_name, $left_variant, $right_variant);
+        });
+
         paste::paste! {
-		#[derive($($trait),*)]
-		pub enum $type_name<L, R> {
-			$left_variant(L),
-			$right_variant(R),
+
+		#[allow(dead_code)]
+		impl<L: $($bound)*, R: $($bound)*> $type_name<L, R> {
+			$crate::__alias_doc_alias!($left_variant, Left, "is_left", {
+				$crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($left_variant), "`, consider wrapping this in an `assert!()` instead"), {
+					#[doc = concat!("Returns `true` if the value is [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ").")]
+					///
+					#[doc = concat!("For example, `", stringify!($type_name), "::<u8, u8>::", stringify!($left_variant), "(1).", stringify!([<is_ $left_variant:snake>]), "()` returns `true`.")]
+					///
+					/// Behaves like [`Either::is_left`](https://docs.rs/either/latest/either/enum.Either.html#method.is_left) from the `either` crate
+					$vis fn [<is_ $left_variant:snake>](&self) -> bool {
+						matches!(self, $type_name::$left_variant(_))
+					}
+				});
+			});
+
+			$crate::__alias_doc_alias!($right_variant, Right, "is_right", {
+				$crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($right_variant), "`, consider wrapping this in an `assert!()` instead"), {
+					#[doc = concat!("Returns `true` if the value is [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ").")]
+					///
+					#[doc = concat!("For example, `", stringify!($type_name), "::<u8, u8>::", stringify!($right_variant), "(1).", stringify!([<is_ $right_variant:snake>]), "()` returns `true`.")]
+					///
+					/// Behaves like [`Either::is_right`](https://docs.rs/either/latest/either/enum.Either.html#method.is_right) from the `either` crate
+					$vis fn [<is_ $right_variant:snake>](&self) -> bool {
+						matches!(self, $type_name::$right_variant(_))
+					}
+				});
+			});
+
+			$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+				#[doc = concat!("Returns a reference to the contained value if the value is [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ").")]
+				$vis fn [<as_ $left_variant:snake>](&self) -> Option<&L> {
+					match self {
+						$type_name::$left_variant(v) => Some(v),
+						_ => None,
+					}
+				}
+			});
+
+			$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+				#[doc = concat!("Returns a mutable reference to the contained value if the value is [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ").")]
+				$vis fn [<as_ $left_variant:snake _mut>](&mut self) -> Option<&mut L> {
+					match self {
+						$type_name::$left_variant(v) => Some(v),
+						_ => None,
+					}
+				}
+			});
+
+			$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+				#[doc = concat!("Returns a reference to the contained value if the value is [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ").")]
+				$vis fn [<as_ $right_variant:snake>](&self) -> Option<&R> {
+					match self {
+						$type_name::$right_variant(v) => Some(v),
+						_ => None,
+					}
+				}
+			});
+
+			$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+				#[doc = concat!("Returns a mutable reference to the contained value if the value is [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ").")]
+				$vis fn [<as_ $right_variant:snake _mut>](&mut self) -> Option<&mut R> {
+					match self {
+						$type_name::$right_variant(v) => Some(v),
+						_ => None,
+					}
+				}
+			});
+
+			$crate::__alias_methods!(conversions, [$($method),*], {
+				$crate::__alias_doc_alias!($left_variant, Left, "left", {
+					$crate::__alias_keyword!([<$left_variant:snake>], {
+						$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+							#[doc = concat!("Converts into an `Option` holding the [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ") value, discarding the `", stringify!($right_variant), "` value.")]
+							///
+							#[doc = concat!("For example, `", stringify!($type_name), "::<u8, u8>::", stringify!($left_variant), "(1).", stringify!([<$left_variant:snake>]), "()` returns `Some(1)`.")]
+							///
+							/// Behaves like [`Either::left`](https://docs.rs/either/latest/either/enum.Either.html#method.left) from the `either` crate
+							$vis fn [<$left_variant:snake>](self) -> Option<L> {
+								match self {
+									$type_name::$left_variant(v) => Some(v),
+									$type_name::$right_variant(_) => None,
+								}
+							}
+						});
+					});
+				});
+
+				$crate::__alias_doc_alias!($right_variant, Right, "right", {
+					$crate::__alias_keyword!([<$right_variant:snake>], {
+						$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+							#[doc = concat!("Converts into an `Option` holding the [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ") value, discarding the `", stringify!($left_variant), "` value.")]
+							///
+							#[doc = concat!("For example, `", stringify!($type_name), "::<u8, u8>::", stringify!($right_variant), "(1).", stringify!([<$right_variant:snake>]), "()` returns `Some(1)`.")]
+							///
+							/// Behaves like [`Either::right`](https://docs.rs/either/latest/either/enum.Either.html#method.right) from the `either` crate
+							$vis fn [<$right_variant:snake>](self) -> Option<R> {
+								match self {
+									$type_name::$left_variant(_) => None,
+									$type_name::$right_variant(v) => Some(v),
+								}
+							}
+						});
+					});
+				});
+
+				$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+					#[doc = concat!("Extracts the [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ") value, returning the `", stringify!($right_variant), "` value as the error otherwise.")]
+					$vis fn [<try_into_ $left_variant:snake>](self) -> Result<L, R> {
+						match self {
+							$type_name::$left_variant(v) => Ok(v),
+							$type_name::$right_variant(v) => Err(v),
+						}
+					}
+				});
+
+				$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+					#[doc = concat!("Extracts the [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ") value, returning the `", stringify!($left_variant), "` value as the error otherwise.")]
+					$vis fn [<try_into_ $right_variant:snake>](self) -> Result<R, L> {
+						match self {
+							$type_name::$left_variant(v) => Err(v),
+							$type_name::$right_variant(v) => Ok(v),
+						}
+					}
+				});
+			});
+
+			$crate::__alias_skip!(as_ref, [$($skip),*], {
+				$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+					/// Converts from `&Self` to an alias holding a reference to the value
+					$vis fn as_ref<'a>(&'a self) -> $type_name<&'a L, &'a R>
+					where
+						&'a L: $($bound)*,
+						&'a R: $($bound)*,
+					{
+						match self {
+							$type_name::$left_variant(v) => $type_name::$left_variant(v),
+							$type_name::$right_variant(v) => $type_name::$right_variant(v),
+						}
+					}
+				});
+			});
+
+			$crate::__alias_skip!(as_mut, [$($skip),*], {
+				$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+					/// Converts from `&mut Self` to an alias holding a mutable reference to the value
+					$vis fn as_mut<'a>(&'a mut self) -> $type_name<&'a mut L, &'a mut R>
+					where
+						&'a mut L: $($bound)*,
+						&'a mut R: $($bound)*,
+					{
+						match self {
+							$type_name::$left_variant(v) => $type_name::$left_variant(v),
+							$type_name::$right_variant(v) => $type_name::$right_variant(v),
+						}
+					}
+				});
+			});
+
+			$crate::__alias_if_flag!(pin_project, [$($flag),*], {
+				$crate::__alias_skip!(forbid_unsafe, [$($skip),*], {
+					$crate::__alias_skip!(as_pin_ref, [$($skip),*], {
+						$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+							/// Converts from a pinned reference to an alias of pinned references to the value
+							$vis fn as_pin_ref<'a>(self: ::core::pin::Pin<&'a Self>) -> $type_name<::core::pin::Pin<&'a L>, ::core::pin::Pin<&'a R>>
+							where
+								::core::pin::Pin<&'a L>: $($bound)*,
+								::core::pin::Pin<&'a R>: $($bound)*,
+							{
+								// SAFETY: the payload is structurally pinned; it is never moved out of a pinned alias.
+								unsafe {
+									match self.get_ref() {
+										$type_name::$left_variant(inner) => $type_name::$left_variant(::core::pin::Pin::new_unchecked(inner)),
+										$type_name::$right_variant(inner) => $type_name::$right_variant(::core::pin::Pin::new_unchecked(inner)),
+									}
+								}
+							}
+						});
+					});
+				});
+
+				$crate::__alias_skip!(forbid_unsafe, [$($skip),*], {
+					$crate::__alias_skip!(as_pin_mut, [$($skip),*], {
+						$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+							/// Converts from a pinned mutable reference to an alias of pinned mutable references to the value
+							$vis fn as_pin_mut<'a>(self: ::core::pin::Pin<&'a mut Self>) -> $type_name<::core::pin::Pin<&'a mut L>, ::core::pin::Pin<&'a mut R>>
+							where
+								::core::pin::Pin<&'a mut L>: $($bound)*,
+								::core::pin::Pin<&'a mut R>: $($bound)*,
+							{
+								// SAFETY: the payload is structurally pinned; it is never moved out of a pinned alias.
+								unsafe {
+									match self.get_unchecked_mut() {
+										$type_name::$left_variant(inner) => $type_name::$left_variant(::core::pin::Pin::new_unchecked(inner)),
+										$type_name::$right_variant(inner) => $type_name::$right_variant(::core::pin::Pin::new_unchecked(inner)),
+									}
+								}
+							}
+						});
+					});
+				});
+			}, {
+				$crate::__alias_skip!(as_pin_ref, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+						/// Converts from a pinned reference to an alias of pinned references to the value
+						///
+						/// Both payloads have to be `Unpin`, unless the alias was generated with `pin_project`.
+						$vis fn as_pin_ref<'a>(self: ::core::pin::Pin<&'a Self>) -> $type_name<::core::pin::Pin<&'a L>, ::core::pin::Pin<&'a R>>
+						where
+							L: ::core::marker::Unpin,
+							R: ::core::marker::Unpin,
+							::core::pin::Pin<&'a L>: $($bound)*,
+							::core::pin::Pin<&'a R>: $($bound)*,
+						{
+							match self.get_ref() {
+								$type_name::$left_variant(inner) => $type_name::$left_variant(::core::pin::Pin::new(inner)),
+								$type_name::$right_variant(inner) => $type_name::$right_variant(::core::pin::Pin::new(inner)),
+							}
+						}
+					});
+				});
+
+				$crate::__alias_skip!(as_pin_mut, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+						/// Converts from a pinned mutable reference to an alias of pinned mutable references to the value
+						///
+						/// Both payloads have to be `Unpin`, unless the alias was generated with `pin_project`.
+						$vis fn as_pin_mut<'a>(self: ::core::pin::Pin<&'a mut Self>) -> $type_name<::core::pin::Pin<&'a mut L>, ::core::pin::Pin<&'a mut R>>
+						where
+							L: ::core::marker::Unpin,
+							R: ::core::marker::Unpin,
+							::core::pin::Pin<&'a mut L>: $($bound)*,
+							::core::pin::Pin<&'a mut R>: $($bound)*,
+						{
+							match self.get_mut() {
+								$type_name::$left_variant(inner) => $type_name::$left_variant(::core::pin::Pin::new(inner)),
+								$type_name::$right_variant(inner) => $type_name::$right_variant(::core::pin::Pin::new(inner)),
+							}
+						}
+					});
+				});
+			});
+
+			$crate::__alias_methods!(combinators, [$($method),*], {
+				$crate::__alias_doc_alias!($left_variant, Left, "map_left", {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						#[doc = concat!("Maps the contained value with `f` if the value is [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), "), leaving `", stringify!($right_variant), "` untouched.")]
+						///
+						/// Behaves like [`Either::map_left`](https://docs.rs/either/latest/either/enum.Either.html#method.map_left) from the `either` crate
+						$vis fn [<map_ $left_variant:snake>]<T: $($bound)*, F: FnOnce(L) -> T>(self, f: F) -> $type_name<T, R> {
+							match self {
+								$type_name::$left_variant(v) => $type_name::$left_variant(f(v)),
+								$type_name::$right_variant(v) => $type_name::$right_variant(v),
+							}
+						}
+					});
+				});
+
+				$crate::__alias_doc_alias!($right_variant, Right, "map_right", {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						#[doc = concat!("Maps the contained value with `f` if the value is [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), "), leaving `", stringify!($left_variant), "` untouched.")]
+						///
+						/// Behaves like [`Either::map_right`](https://docs.rs/either/latest/either/enum.Either.html#method.map_right) from the `either` crate
+						$vis fn [<map_ $right_variant:snake>]<T: $($bound)*, F: FnOnce(R) -> T>(self, f: F) -> $type_name<L, T> {
+							match self {
+								$type_name::$left_variant(v) => $type_name::$left_variant(v),
+								$type_name::$right_variant(v) => $type_name::$right_variant(f(v)),
+							}
+						}
+					});
+				});
+
+				$crate::__alias_skip!(map_either, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						/// Maps the left value with `f` or the right value with `g`
+						$vis fn map_either<M: $($bound)*, N: $($bound)*, F: FnOnce(L) -> M, G: FnOnce(R) -> N>(self, f: F, g: G) -> $type_name<M, N> {
+							match self {
+								$type_name::$left_variant(v) => $type_name::$left_variant(f(v)),
+								$type_name::$right_variant(v) => $type_name::$right_variant(g(v)),
+							}
+						}
+					});
+				});
+
+				$crate::__alias_skip!(map_either_with, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						/// Like `map_either`, but passes a shared context value to whichever function is called
+						$vis fn map_either_with<Ctx, M: $($bound)*, N: $($bound)*, F: FnOnce(Ctx, L) -> M, G: FnOnce(Ctx, R) -> N>(self, ctx: Ctx, f: F, g: G) -> $type_name<M, N> {
+							match self {
+								$type_name::$left_variant(v) => $type_name::$left_variant(f(ctx, v)),
+								$type_name::$right_variant(v) => $type_name::$right_variant(g(ctx, v)),
+							}
+						}
+					});
+				});
+
+				$crate::__alias_doc_alias!($left_variant, Left, "left_and_then", {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						#[doc = concat!("Applies `f` to the contained value if the value is [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), "), leaving `", stringify!($right_variant), "` untouched. `f` may itself produce either variant.")]
+						///
+						/// Behaves like [`Either::left_and_then`](https://docs.rs/either/latest/either/enum.Either.html#method.left_and_then) from the `either` crate
+						$vis fn [<$left_variant:snake _and_then>]<T: $($bound)*, F: FnOnce(L) -> $type_name<T, R>>(self, f: F) -> $type_name<T, R> {
+							match self {
+								$type_name::$left_variant(v) => f(v),
+								$type_name::$right_variant(v) => $type_name::$right_variant(v),
+							}
+						}
+					});
+				});
+
+				$crate::__alias_doc_alias!($right_variant, Right, "right_and_then", {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						#[doc = concat!("Applies `f` to the contained value if the value is [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), "), leaving `", stringify!($left_variant), "` untouched. `f` may itself produce either variant.")]
+						///
+						/// Behaves like [`Either::right_and_then`](https://docs.rs/either/latest/either/enum.Either.html#method.right_and_then) from the `either` crate
+						$vis fn [<$right_variant:snake _and_then>]<T: $($bound)*, F: FnOnce(R) -> $type_name<L, T>>(self, f: F) -> $type_name<L, T> {
+							match self {
+								$type_name::$left_variant(v) => $type_name::$left_variant(v),
+								$type_name::$right_variant(v) => f(v),
+							}
+						}
+					});
+				});
+			});
+
+			$crate::__alias_skip!(no_panics, [$($skip),*], {
+				$crate::__alias_doc_alias!($left_variant, Left, "unwrap_left", {
+					#[doc = concat!("Returns the contained [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ") value, panicking if the value is `", stringify!($right_variant), "`.")]
+					///
+					/// Behaves like [`Either::unwrap_left`](https://docs.rs/either/latest/either/enum.Either.html#method.unwrap_left) from the `either` crate
+					$vis fn [<unwrap_ $left_variant:snake>](self) -> L {
+						match self {
+							$type_name::$left_variant(v) => v,
+							$type_name::$right_variant(_) => {
+								panic!("called `unwrap_{}()` on a `{}`", stringify!([<$left_variant:snake>]), stringify!($right_variant))
+							}
+						}
+					}
+				});
+			});
+
+			$crate::__alias_skip!(no_panics, [$($skip),*], {
+				$crate::__alias_doc_alias!($right_variant, Right, "unwrap_right", {
+					#[doc = concat!("Returns the contained [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ") value, panicking if the value is `", stringify!($left_variant), "`.")]
+					///
+					/// Behaves like [`Either::unwrap_right`](https://docs.rs/either/latest/either/enum.Either.html#method.unwrap_right) from the `either` crate
+					$vis fn [<unwrap_ $right_variant:snake>](self) -> R {
+						match self {
+							$type_name::$right_variant(v) => v,
+							$type_name::$left_variant(_) => {
+								panic!("called `unwrap_{}()` on a `{}`", stringify!([<$right_variant:snake>]), stringify!($left_variant))
+							}
+						}
+					}
+				});
+			});
+
+			$crate::__alias_doc_alias!($left_variant, Left, "left_or", {
+				#[doc = concat!("Returns the contained [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ") value, or `other` if the value is `", stringify!($right_variant), "`.")]
+				///
+				/// Behaves like [`Either::left_or`](https://docs.rs/either/latest/either/enum.Either.html#method.left_or) from the `either` crate
+				$vis fn [<$left_variant:snake _or>](self, other: L) -> L {
+					match self {
+						$type_name::$left_variant(v) => v,
+						$type_name::$right_variant(_) => other,
+					}
+				}
+			});
+
+			$crate::__alias_doc_alias!($left_variant, Left, "left_or_else", {
+				#[doc = concat!("Returns the contained [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ") value, or computes one from the `", stringify!($right_variant), "` value with `f`.")]
+				///
+				/// Behaves like [`Either::left_or_else`](https://docs.rs/either/latest/either/enum.Either.html#method.left_or_else) from the `either` crate
+				$vis fn [<$left_variant:snake _or_else>]<F: FnOnce(R) -> L>(self, f: F) -> L {
+					match self {
+						$type_name::$left_variant(v) => v,
+						$type_name::$right_variant(v) => f(v),
+					}
+				}
+			});
+
+			$crate::__alias_doc_alias!($left_variant, Left, "left_or_default", {
+				#[doc = concat!("Returns the contained [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ") value, or the default value if the value is `", stringify!($right_variant), "`.")]
+				///
+				/// Behaves like [`Either::left_or_default`](https://docs.rs/either/latest/either/enum.Either.html#method.left_or_default) from the `either` crate
+				$vis fn [<$left_variant:snake _or_default>](self) -> L
+				where
+					L: Default,
+				{
+					match self {
+						$type_name::$left_variant(v) => v,
+						$type_name::$right_variant(_) => L::default(),
+					}
+				}
+			});
+
+			$crate::__alias_doc_alias!($right_variant, Right, "right_or", {
+				#[doc = concat!("Returns the contained [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ") value, or `other` if the value is `", stringify!($left_variant), "`.")]
+				///
+				/// Behaves like [`Either::right_or`](https://docs.rs/either/latest/either/enum.Either.html#method.right_or) from the `either` crate
+				$vis fn [<$right_variant:snake _or>](self, other: R) -> R {
+					match self {
+						$type_name::$left_variant(_) => other,
+						$type_name::$right_variant(v) => v,
+					}
+				}
+			});
+
+			$crate::__alias_doc_alias!($right_variant, Right, "right_or_else", {
+				#[doc = concat!("Returns the contained [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ") value, or computes one from the `", stringify!($left_variant), "` value with `f`.")]
+				///
+				/// Behaves like [`Either::right_or_else`](https://docs.rs/either/latest/either/enum.Either.html#method.right_or_else) from the `either` crate
+				$vis fn [<$right_variant:snake _or_else>]<F: FnOnce(L) -> R>(self, f: F) -> R {
+					match self {
+						$type_name::$left_variant(v) => f(v),
+						$type_name::$right_variant(v) => v,
+					}
+				}
+			});
+
+			$crate::__alias_doc_alias!($right_variant, Right, "right_or_default", {
+				#[doc = concat!("Returns the contained [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ") value, or the default value if the value is `", stringify!($left_variant), "`.")]
+				///
+				/// Behaves like [`Either::right_or_default`](https://docs.rs/either/latest/either/enum.Either.html#method.right_or_default) from the `either` crate
+				$vis fn [<$right_variant:snake _or_default>](self) -> R
+				where
+					R: Default,
+				{
+					match self {
+						$type_name::$left_variant(_) => R::default(),
+						$type_name::$right_variant(v) => v,
+					}
+				}
+			});
+
+			$crate::__alias_methods!(combinators, [$($method),*], {
+				$crate::__alias_skip!(flip, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Swaps the two sides, turning the left variant into the right variant and vice versa
+						$vis fn flip(self) -> $type_name<R, L> {
+							match self {
+								$type_name::$left_variant(v) => $type_name::$right_variant(v),
+								$type_name::$right_variant(v) => $type_name::$left_variant(v),
+							}
+						}
+					});
+				});
+
+				$crate::__alias_skip!(either, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						/// Applies `f` to the left value or `g` to the right value, returning the common result
+						$vis fn either<U, F: FnOnce(L) -> U, G: FnOnce(R) -> U>(self, f: F, g: G) -> U {
+							match self {
+								$type_name::$left_variant(v) => f(v),
+								$type_name::$right_variant(v) => g(v),
+							}
+						}
+					});
+				});
+			});
+
+			$crate::__alias_methods!(conversions, [$($method),*], {
+				$crate::__alias_skip!(either_into, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Converts whichever value is present into a common type `T`
+						$vis fn either_into<T>(self) -> T
+						where
+							L: Into<T>,
+							R: Into<T>,
+						{
+							match self {
+								$type_name::$left_variant(v) => v.into(),
+								$type_name::$right_variant(v) => v.into(),
+							}
+						}
+					});
+				});
+			});
+
+			$crate::__alias_methods!(combinators, [$($method),*], {
+				$crate::__alias_skip!(either_with, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						/// Like `either`, but passes a shared context value to whichever function is called
+						$vis fn either_with<Ctx, U, F: FnOnce(Ctx, L) -> U, G: FnOnce(Ctx, R) -> U>(self, ctx: Ctx, f: F, g: G) -> U {
+							match self {
+								$type_name::$left_variant(v) => f(ctx, v),
+								$type_name::$right_variant(v) => g(ctx, v),
+							}
+						}
+					});
+				});
+			});
 		}
 
-		impl<L, R> $type_name<L, R> {
-			/// Returns true if this is the left variant
-			pub fn [<is_ $left_variant:lower>](&self) -> bool {
-				matches!(self, $type_name::$left_variant(_))
+		$crate::__alias_methods!(combinators, [$($method),*], {
+			#[allow(dead_code)]
+			impl<'a, L: Clone + $($bound)*, R: Clone + $($bound)*> $type_name<&'a L, &'a R>
+			where
+				&'a L: $($bound)*,
+				&'a R: $($bound)*,
+			{
+				$crate::__alias_skip!(cloned, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Clones the referenced value into an owned alias
+						$vis fn cloned(self) -> $type_name<L, R> {
+							match self {
+								$type_name::$left_variant(v) => $type_name::$left_variant(v.clone()),
+								$type_name::$right_variant(v) => $type_name::$right_variant(v.clone()),
+							}
+						}
+					});
+				});
 			}
 
-			/// Returns true if this is the right variant
-			pub fn [<is_ $right_variant:lower>](&self) -> bool {
-				matches!(self, $type_name::$right_variant(_))
+			#[allow(dead_code)]
+			impl<'a, L: Copy + $($bound)*, R: Copy + $($bound)*> $type_name<&'a L, &'a R>
+			where
+				&'a L: $($bound)*,
+				&'a R: $($bound)*,
+			{
+				$crate::__alias_skip!(copied, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Copies the referenced value into an owned alias
+						$vis fn copied(self) -> $type_name<L, R> {
+							match self {
+								$type_name::$left_variant(&v) => $type_name::$left_variant(v),
+								$type_name::$right_variant(&v) => $type_name::$right_variant(v),
+							}
+						}
+					});
+				});
 			}
+		});
 
-			/// Returns a reference to the left value if this is the left variant
-			pub fn [<as_ $left_variant:lower>](&self) -> Option<&L> {
-				match self {
-					$type_name::$left_variant(v) => Some(v),
-					_ => None,
+		$crate::__alias_methods!(conversions, [$($method),*], {
+			#[allow(dead_code)]
+			impl<L: $($bound)*, R: $($bound)*> $type_name<Option<L>, Option<R>>
+			where
+				Option<L>: $($bound)*,
+				Option<R>: $($bound)*,
+			{
+				$crate::__alias_skip!(factor_none, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Factors out `None` from both sides, producing `None` if the present side is `None`
+						$vis fn factor_none(self) -> Option<$type_name<L, R>> {
+							match self {
+								$type_name::$left_variant(v) => v.map($type_name::$left_variant),
+								$type_name::$right_variant(v) => v.map($type_name::$right_variant),
+							}
+						}
+					});
+				});
+			}
+
+			#[allow(dead_code)]
+			impl<L: $($bound)*, R: $($bound)*, E> $type_name<Result<L, E>, Result<R, E>>
+			where
+				Result<L, E>: $($bound)*,
+				Result<R, E>: $($bound)*,
+			{
+				$crate::__alias_skip!(factor_err, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Factors out a shared error type from both sides
+						$vis fn factor_err(self) -> Result<$type_name<L, R>, E> {
+							match self {
+								$type_name::$left_variant(v) => v.map($type_name::$left_variant),
+								$type_name::$right_variant(v) => v.map($type_name::$right_variant),
+							}
+						}
+					});
+				});
+			}
+
+			#[allow(dead_code)]
+			impl<T, L: $($bound)*, R: $($bound)*> $type_name<Result<T, L>, Result<T, R>>
+			where
+				Result<T, L>: $($bound)*,
+				Result<T, R>: $($bound)*,
+			{
+				$crate::__alias_skip!(factor_ok, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Factors out a shared ok type from both sides
+						$vis fn factor_ok(self) -> Result<T, $type_name<L, R>> {
+							match self {
+								$type_name::$left_variant(v) => v.map_err($type_name::$left_variant),
+								$type_name::$right_variant(v) => v.map_err($type_name::$right_variant),
+							}
+						}
+					});
+				});
+			}
+		});
+
+		#[allow(dead_code)]
+		impl<T: $($bound)*> $type_name<T, T> {
+			$crate::__alias_skip!(into_inner, [$($skip),*], {
+				$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+					/// Extracts the value regardless of the variant, when both sides have the same type
+					$vis fn into_inner(self) -> T {
+						match self {
+							$type_name::$left_variant(v) => v,
+							$type_name::$right_variant(v) => v,
+						}
+					}
+				});
+			});
+		}
+
+		#[allow(dead_code)]
+		impl<L: ::core::ops::Deref + $($bound)*, R: ::core::ops::Deref + $($bound)*> $type_name<L, R> {
+			$crate::__alias_skip!(as_deref, [$($skip),*], {
+				$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+					/// Converts from `&Self` to an alias holding a reference to the dereferenced value
+					$vis fn as_deref<'a>(&'a self) -> $type_name<&'a L::Target, &'a R::Target>
+					where
+						&'a L::Target: $($bound)*,
+						&'a R::Target: $($bound)*,
+					{
+						match self {
+							$type_name::$left_variant(v) => $type_name::$left_variant(v.deref()),
+							$type_name::$right_variant(v) => $type_name::$right_variant(v.deref()),
+						}
+					}
+				});
+			});
+		}
+
+		#[allow(dead_code)]
+		impl<L: ::core::ops::DerefMut + $($bound)*, R: ::core::ops::DerefMut + $($bound)*> $type_name<L, R> {
+			$crate::__alias_skip!(as_deref_mut, [$($skip),*], {
+				$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+					/// Converts from `&mut Self` to an alias holding a mutable reference to the dereferenced value
+					$vis fn as_deref_mut<'a>(&'a mut self) -> $type_name<&'a mut L::Target, &'a mut R::Target>
+					where
+						&'a mut L::Target: $($bound)*,
+						&'a mut R::Target: $($bound)*,
+					{
+						match self {
+							$type_name::$left_variant(v) => $type_name::$left_variant(v.deref_mut()),
+							$type_name::$right_variant(v) => $type_name::$right_variant(v.deref_mut()),
+						}
+					}
+				});
+			});
+		}
+
+		$crate::__alias_methods!(iterators, [$($method),*], {
+			impl<A, L: Extend<A> + $($bound)*, R: Extend<A> + $($bound)*> Extend<A> for $type_name<L, R> {
+				fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+					match self {
+						$type_name::$left_variant(inner) => inner.extend(iter),
+						$type_name::$right_variant(inner) => inner.extend(iter),
+					}
 				}
 			}
+		});
 
-			/// Returns a mutable reference to the left value if this is the left variant
-			pub fn [<as_ $left_variant:lower _mut>](&mut self) -> Option<&mut L> {
+		impl<Target: ?Sized, L: AsRef<Target> + $($bound)*, R: AsRef<Target> + $($bound)*> AsRef<Target> for $type_name<L, R> {
+			fn as_ref(&self) -> &Target {
 				match self {
-					$type_name::$left_variant(v) => Some(v),
-					_ => None,
+					$type_name::$left_variant(inner) => inner.as_ref(),
+					$type_name::$right_variant(inner) => inner.as_ref(),
 				}
 			}
+		}
 
-			/// Returns a reference to the right value if this is the right variant
-			pub fn [<as_ $right_variant:lower>](&self) -> Option<&R> {
+		impl<Target: ?Sized, L: AsMut<Target> + $($bound)*, R: AsMut<Target> + $($bound)*> AsMut<Target> for $type_name<L, R> {
+			fn as_mut(&mut self) -> &mut Target {
 				match self {
-					$type_name::$right_variant(v) => Some(v),
-					_ => None,
+					$type_name::$left_variant(inner) => inner.as_mut(),
+					$type_name::$right_variant(inner) => inner.as_mut(),
 				}
 			}
+		}
 
-			/// Returns a mutable reference to the right value if this is the right variant
-			pub fn [<as_ $right_variant:lower _mut>](&mut self) -> Option<&mut R> {
+		impl<L: ::core::fmt::Display + $($bound)*, R: ::core::fmt::Display + $($bound)*> ::core::fmt::Display for $type_name<L, R> {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
 				match self {
-					$type_name::$right_variant(v) => Some(v),
-					_ => None,
+					$type_name::$left_variant(inner) => inner.fmt(f),
+					$type_name::$right_variant(inner) => inner.fmt(f),
 				}
 			}
+		}
 
-			/// Maps the left value if this is the left variant
-			pub fn [<map_ $left_variant:lower>]<T, F: FnOnce(L) -> T>(self, f: F) -> $type_name<T, R> {
+		impl<L: $($bound)*, R: $($bound)*> ::core::error::Error for $type_name<L, R>
+		where
+			L: ::core::error::Error,
+			R: ::core::error::Error,
+			Self: ::core::fmt::Debug,
+		{
+			fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
 				match self {
-					$type_name::$left_variant(v) => $type_name::$left_variant(f(v)),
-					$type_name::$right_variant(v) => $type_name::$right_variant(v),
+					$type_name::$left_variant(inner) => inner.source(),
+					$type_name::$right_variant(inner) => inner.source(),
 				}
 			}
+		}
+
+		$crate::__alias_if_flag!(pin_project, [$($flag),*], {}, {
+			impl<L: $($bound)*, R: $($bound)*> ::core::future::Future for $type_name<L, R>
+			where
+				L: ::core::future::Future + ::core::marker::Unpin,
+				R: ::core::future::Future<Output = L::Output> + ::core::marker::Unpin,
+			{
+				type Output = L::Output;
 
-			/// Maps the right value if this is the right variant
-			pub fn [<map_ $right_variant:lower>]<T, F: FnOnce(R) -> T>(self, f: F) -> $type_name<L, T> {
+				fn poll(self: ::core::pin::Pin<&mut Self>, cx: &mut ::core::task::Context<'_>) -> ::core::task::Poll<Self::Output> {
+					match self.get_mut() {
+						$type_name::$left_variant(inner) => ::core::pin::Pin::new(inner).poll(cx),
+						$type_name::$right_variant(inner) => ::core::pin::Pin::new(inner).poll(cx),
+					}
+				}
+			}
+		});
+
+		impl<L: $($bound)*, R: $($bound)*> ::core::ops::Deref for $type_name<L, R>
+		where
+			L: ::core::ops::Deref,
+			R: ::core::ops::Deref<Target = L::Target>,
+		{
+			type Target = L::Target;
+
+			fn deref(&self) -> &Self::Target {
 				match self {
-					$type_name::$left_variant(v) => $type_name::$left_variant(v),
-					$type_name::$right_variant(v) => $type_name::$right_variant(f(v)),
+					$type_name::$left_variant(inner) => inner,
+					$type_name::$right_variant(inner) => inner,
 				}
 			}
+		}
 
-			/// Unwraps the left value, panicking if this is the right variant
-			pub fn [<unwrap_ $left_variant:lower>](self) -> L {
+		impl<L: $($bound)*, R: $($bound)*> ::core::ops::DerefMut for $type_name<L, R>
+		where
+			L: ::core::ops::DerefMut,
+			R: ::core::ops::DerefMut<Target = L::Target>,
+		{
+			fn deref_mut(&mut self) -> &mut Self::Target {
 				match self {
-					$type_name::$left_variant(v) => v,
-					$type_name::$right_variant(_) => {
-						panic!("called `unwrap_{}()` on a `{}`", stringify!([<$left_variant:lower>]), stringify!($right_variant))
+					$type_name::$left_variant(inner) => inner,
+					$type_name::$right_variant(inner) => inner,
+				}
+			}
+		}
+
+		$crate::__alias_methods!(iterators, [$($method),*], {
+			impl<L: $($bound)*, R: $($bound)*> Iterator for $type_name<L, R>
+			where
+				L: Iterator,
+				R: Iterator<Item = L::Item>,
+			{
+				type Item = L::Item;
+
+				fn next(&mut self) -> Option<Self::Item> {
+					match self {
+						$type_name::$left_variant(inner) => inner.next(),
+						$type_name::$right_variant(inner) => inner.next(),
+					}
+				}
+
+				fn size_hint(&self) -> (usize, Option<usize>) {
+					match self {
+						$type_name::$left_variant(inner) => inner.size_hint(),
+						$type_name::$right_variant(inner) => inner.size_hint(),
+					}
+				}
+
+				fn nth(&mut self, n: usize) -> Option<Self::Item> {
+					match self {
+						$type_name::$left_variant(inner) => inner.nth(n),
+						$type_name::$right_variant(inner) => inner.nth(n),
+					}
+				}
+
+				fn count(self) -> usize {
+					match self {
+						$type_name::$left_variant(inner) => inner.count(),
+						$type_name::$right_variant(inner) => inner.count(),
+					}
+				}
+
+				fn last(self) -> Option<Self::Item> {
+					match self {
+						$type_name::$left_variant(inner) => inner.last(),
+						$type_name::$right_variant(inner) => inner.last(),
+					}
+				}
+
+				fn fold<Acc, G: FnMut(Acc, Self::Item) -> Acc>(self, init: Acc, f: G) -> Acc {
+					match self {
+						$type_name::$left_variant(inner) => inner.fold(init, f),
+						$type_name::$right_variant(inner) => inner.fold(init, f),
 					}
 				}
 			}
 
-			/// Unwraps the right value, panicking if this is the left variant
-			pub fn [<unwrap_ $right_variant:lower>](self) -> R {
-				match self {
-					$type_name::$right_variant(v) => v,
-					$type_name::$left_variant(_) => {
-						panic!("called `unwrap_{}()` on a `{}`", stringify!([<$right_variant:lower>]), stringify!($left_variant))
+			impl<L: $($bound)*, R: $($bound)*> DoubleEndedIterator for $type_name<L, R>
+			where
+				L: DoubleEndedIterator,
+				R: DoubleEndedIterator<Item = L::Item>,
+			{
+				fn next_back(&mut self) -> Option<Self::Item> {
+					match self {
+						$type_name::$left_variant(inner) => inner.next_back(),
+						$type_name::$right_variant(inner) => inner.next_back(),
+					}
+				}
+
+				fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+					match self {
+						$type_name::$left_variant(inner) => inner.nth_back(n),
+						$type_name::$right_variant(inner) => inner.nth_back(n),
+					}
+				}
+
+				fn rfold<Acc, G: FnMut(Acc, Self::Item) -> Acc>(self, init: Acc, f: G) -> Acc {
+					match self {
+						$type_name::$left_variant(inner) => inner.rfold(init, f),
+						$type_name::$right_variant(inner) => inner.rfold(init, f),
 					}
 				}
 			}
-		}
+
+			impl<L: $($bound)*, R: $($bound)*> ExactSizeIterator for $type_name<L, R>
+			where
+				L: ExactSizeIterator,
+				R: ExactSizeIterator<Item = L::Item>,
+			{
+				fn len(&self) -> usize {
+					match self {
+						$type_name::$left_variant(inner) => inner.len(),
+						$type_name::$right_variant(inner) => inner.len(),
+					}
+				}
+			}
+
+			impl<L: $($bound)*, R: $($bound)*> ::core::iter::FusedIterator for $type_name<L, R>
+			where
+				L: ::core::iter::FusedIterator,
+				R: ::core::iter::FusedIterator<Item = L::Item>,
+			{
+			}
+		});
         }
+        });
+    };
+    (@enum [$(#[$left_attr:meta])*] [$(#[$right_attr:meta])*] [$vis:vis] [$type_name:ident, $left_variant:ident, $right_variant:ident], [$L:ident, $R:ident], [$($bound:tt)*], [$($trait:path),*], [$(#[$attr:meta])*]) => {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$L: $($bound)*, $R: $($bound)*> {
+            $(#[$left_attr])*
+            $left_variant($L),
+            $(#[$right_attr])*
+            $right_variant($R),
+        }
+    };
+    ($type_name:ident <$($lifetime:lifetime),+ $(,)?> $($rest:tt)*) => {
+        compile_error!(concat!(
+            "`alias_either!` does not take lifetime parameters, its payloads can borrow through the generic parameters, e.g. `", stringify!($type_name), "<&'a str, &'a [u8]>`. ",
+            "Use `borrowed: Name` for a `Name<'a, ..>` alias holding references",
+        ));
+    };
+    ($($input:tt)*) => {
+        compile_error!(concat!(
+            "expected `alias_either!(Name, LeftVariant, RightVariant, options...)`, got `alias_either!(", stringify!($($input)*), ")`",
+        ));
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_either_flag {
+    (@flags $ok_side:ident, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident, [$($flag:ident),*]) => {
+        $(
+            $crate::__alias_either_flag!($flag, $ok_side, $bounds, $methods, $skip, $type_name, $left_variant, $right_variant);
+        )*
     };
+    (implement_try, right, [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        impl<L: $($bound)*, R: $($bound)*> ::core::ops::Try for $type_name<L, R>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type Output = R;
+            type Residual = $type_name<L, ::core::convert::Infallible>;
+
+            fn from_output(output: Self::Output) -> Self {
+                $type_name::$right_variant(output)
+            }
+
+            fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, Self::Output> {
+                match self {
+                    $type_name::$left_variant(v) => {
+                        ::core::ops::ControlFlow::Break($type_name::$left_variant(v))
+                    }
+                    $type_name::$right_variant(v) => ::core::ops::ControlFlow::Continue(v),
+                }
+            }
+        }
+
+        impl<L: $($bound)*, R: $($bound)*> ::core::ops::FromResidual for $type_name<L, R>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            fn from_residual(residual: $type_name<L, ::core::convert::Infallible>) -> Self {
+                match residual {
+                    $type_name::$left_variant(v) => $type_name::$left_variant(v),
+                    $type_name::$right_variant(never) => match never {},
+                }
+            }
+        }
+
+        impl<L: $($bound)*, R: $($bound)*> ::core::ops::Residual<R> for $type_name<L, ::core::convert::Infallible>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type TryType = $type_name<L, R>;
+        }
+    };
+    (implement_try, left, [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        impl<L: $($bound)*, R: $($bound)*> ::core::ops::Try for $type_name<L, R>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type Output = L;
+            type Residual = $type_name<::core::convert::Infallible, R>;
+
+            fn from_output(output: Self::Output) -> Self {
+                $type_name::$left_variant(output)
+            }
+
+            fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, Self::Output> {
+                match self {
+                    $type_name::$left_variant(v) => ::core::ops::ControlFlow::Continue(v),
+                    $type_name::$right_variant(v) => {
+                        ::core::ops::ControlFlow::Break($type_name::$right_variant(v))
+                    }
+                }
+            }
+        }
+
+        impl<L: $($bound)*, R: $($bound)*> ::core::ops::FromResidual for $type_name<L, R>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            fn from_residual(residual: $type_name<::core::convert::Infallible, R>) -> Self {
+                match residual {
+                    $type_name::$left_variant(never) => match never {},
+                    $type_name::$right_variant(v) => $type_name::$right_variant(v),
+                }
+            }
+        }
+
+        impl<L: $($bound)*, R: $($bound)*> ::core::ops::Residual<L> for $type_name<::core::convert::Infallible, R>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type TryType = $type_name<L, R>;
+        }
+    };
+    (implement_io, $ok_side:ident, [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        impl<L: std::io::Read + $($bound)*, R: std::io::Read + $($bound)*> std::io::Read for $type_name<L, R> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.read(buf),
+                    $type_name::$right_variant(inner) => inner.read(buf),
+                }
+            }
+
+            fn read_vectored(
+                &mut self,
+                bufs: &mut [std::io::IoSliceMut<'_>],
+            ) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.read_vectored(bufs),
+                    $type_name::$right_variant(inner) => inner.read_vectored(bufs),
+                }
+            }
+
+            fn read_to_end(&mut self, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.read_to_end(buf),
+                    $type_name::$right_variant(inner) => inner.read_to_end(buf),
+                }
+            }
+
+            fn read_to_string(&mut self, buf: &mut String) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.read_to_string(buf),
+                    $type_name::$right_variant(inner) => inner.read_to_string(buf),
+                }
+            }
+
+            fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.read_exact(buf),
+                    $type_name::$right_variant(inner) => inner.read_exact(buf),
+                }
+            }
+        }
+
+        impl<L: std::io::BufRead + $($bound)*, R: std::io::BufRead + $($bound)*> std::io::BufRead for $type_name<L, R> {
+            fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.fill_buf(),
+                    $type_name::$right_variant(inner) => inner.fill_buf(),
+                }
+            }
+
+            fn consume(&mut self, amt: usize) {
+                match self {
+                    $type_name::$left_variant(inner) => inner.consume(amt),
+                    $type_name::$right_variant(inner) => inner.consume(amt),
+                }
+            }
+
+            fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.read_until(byte, buf),
+                    $type_name::$right_variant(inner) => inner.read_until(byte, buf),
+                }
+            }
+
+            fn read_line(&mut self, buf: &mut String) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.read_line(buf),
+                    $type_name::$right_variant(inner) => inner.read_line(buf),
+                }
+            }
+        }
+
+        impl<L: std::io::Write + $($bound)*, R: std::io::Write + $($bound)*> std::io::Write for $type_name<L, R> {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.write(buf),
+                    $type_name::$right_variant(inner) => inner.write(buf),
+                }
+            }
+
+            fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.write_vectored(bufs),
+                    $type_name::$right_variant(inner) => inner.write_vectored(bufs),
+                }
+            }
+
+            fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.write_all(buf),
+                    $type_name::$right_variant(inner) => inner.write_all(buf),
+                }
+            }
+
+            fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> std::io::Result<()> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.write_fmt(args),
+                    $type_name::$right_variant(inner) => inner.write_fmt(args),
+                }
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.flush(),
+                    $type_name::$right_variant(inner) => inner.flush(),
+                }
+            }
+        }
+
+        impl<L: std::io::Seek + $($bound)*, R: std::io::Seek + $($bound)*> std::io::Seek for $type_name<L, R> {
+            fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+                match self {
+                    $type_name::$left_variant(inner) => inner.seek(pos),
+                    $type_name::$right_variant(inner) => inner.seek(pos),
+                }
+            }
+        }
+    };
+    (default_left, $ok_side:ident, [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        impl<L: Default + $($bound)*, R: $($bound)*> Default for $type_name<L, R> {
+            #[doc = concat!("Returns [`", stringify!($left_variant), "`](Self::", stringify!($left_variant), ") holding the default value.")]
+            fn default() -> Self {
+                $type_name::$left_variant(L::default())
+            }
+        }
+    };
+    (default_right, $ok_side:ident, [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        impl<L: $($bound)*, R: Default + $($bound)*> Default for $type_name<L, R> {
+            #[doc = concat!("Returns [`", stringify!($right_variant), "`](Self::", stringify!($right_variant), ") holding the default value.")]
+            fn default() -> Self {
+                $type_name::$right_variant(R::default())
+            }
+        }
+    };
+    (pin_project, $ok_side:ident, [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        $crate::__alias_forbid_unsafe!(@unsafe pin_project, $skip, {
+            impl<L: $($bound)*, R: $($bound)*> ::core::future::Future for $type_name<L, R>
+            where
+                L: ::core::future::Future,
+                R: ::core::future::Future<Output = L::Output>,
+            {
+                type Output = L::Output;
+
+                fn poll(self: ::core::pin::Pin<&mut Self>, cx: &mut ::core::task::Context<'_>) -> ::core::task::Poll<Self::Output> {
+                    // SAFETY: the payload is structurally pinned; it is never moved out of a pinned alias.
+                    unsafe {
+                        match self.get_unchecked_mut() {
+                            $type_name::$left_variant(inner) => ::core::pin::Pin::new_unchecked(inner).poll(cx),
+                            $type_name::$right_variant(inner) => ::core::pin::Pin::new_unchecked(inner).poll(cx),
+                        }
+                    }
+                }
+            }
+
+            // The pin projections are only sound if the alias is `Unpin` exactly when both payloads
+            // are, and if it is never moved out of in a `Drop` impl. Implementing `Unpin` here makes
+            // a user `impl Unpin` conflict with it, and the blanket impl over `Drop` types makes a
+            // user `impl Drop` conflict with the impl for the alias.
+            impl<L: $($bound)*, R: $($bound)*> ::core::marker::Unpin for $type_name<L, R>
+            where
+                L: ::core::marker::Unpin,
+                R: ::core::marker::Unpin,
+            {
+            }
+
+            const _: () = {
+                #[allow(dead_code)]
+                trait MustNotImplDrop {}
+                #[allow(drop_bounds)]
+                impl<T: Drop> MustNotImplDrop for T {}
+                impl<L: $($bound)*, R: $($bound)*> MustNotImplDrop for $type_name<L, R> {}
+            };
+        });
+    };
+    (assert_same_size, $ok_side:ident, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        $crate::__alias_assert_same_size!($bounds, [$type_name<u8, u16> => Result<u8, u16>], [
+            $type_name<bool, ()> => Result<bool, ()>,
+            $type_name<::core::num::NonZeroU32, ()> => Result<::core::num::NonZeroU32, ()>,
+            $type_name<&'static u8, ()> => Result<&'static u8, ()>,
+            $type_name<&'static [u8], ::core::convert::Infallible> => Result<&'static [u8], ::core::convert::Infallible>
+        ]);
+    };
+    (generate_tests, $ok_side:ident, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        $crate::__alias_skip!(@tested __alias_either_flag [@generate_tests, $ok_side, $bounds, $methods], $skip, [$type_name, $left_variant, $right_variant]);
+    };
+    (@generate_tests, $ok_side:ident, [$($bound:tt)*], [$($method:ident),*], [$($skip:ident),*], $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        paste::paste! {
+            #[cfg(test)]
+            mod [<$type_name:snake _conformance>] {
+                use super::*;
+
+                // Each value is modelled as a `Result` holding the left value as `Ok`
+                const VALUES: [Result<u8, u16>; 2] = [Ok(1), Err(2)];
+
+                fn alias<L: $($bound)*, R: $($bound)*>(value: Result<L, R>) -> $type_name<L, R> {
+                    match value {
+                        Ok(l) => $type_name::$left_variant(l),
+                        Err(r) => $type_name::$right_variant(r),
+                    }
+                }
+
+                fn project<L: $($bound)*, R: $($bound)*>(value: $type_name<L, R>) -> Result<L, R> {
+                    match value {
+                        $type_name::$left_variant(l) => Ok(l),
+                        $type_name::$right_variant(r) => Err(r),
+                    }
+                }
+
+                fn flipped<L, R>(value: Result<L, R>) -> Result<R, L> {
+                    match value {
+                        Ok(l) => Err(l),
+                        Err(r) => Ok(r),
+                    }
+                }
+
+                #[test]
+                fn round_trip() {
+                    for value in VALUES {
+                        let result: Result<_, _> = alias(value).into();
+                        assert_eq!(project($type_name::from(result)), value);
+                    }
+                }
+
+                #[test]
+                fn size_equivalence() {
+                    assert_eq!(::core::mem::size_of::<$type_name<u8, u16>>(), ::core::mem::size_of::<Result<u8, u16>>());
+                    $crate::__alias_unbounded!([$($bound)*], {
+                        assert_eq!(::core::mem::size_of::<$type_name<&u8, ()>>(), ::core::mem::size_of::<Result<&u8, ()>>());
+                    });
+                }
+
+                #[test]
+                #[allow(unused_variables)]
+                fn methods() {
+                    for value in VALUES {
+                        assert_eq!(alias(value).[<is_ $left_variant:snake>](), value.is_ok());
+                        assert_eq!(alias(value).[<is_ $right_variant:snake>](), value.is_err());
+                        assert_eq!(alias(value).[<as_ $left_variant:snake>]().copied(), value.ok());
+                        assert_eq!(alias(value).[<as_ $right_variant:snake>]().copied(), value.err());
+
+                        let (mut aliased, mut expected) = (alias(value), value);
+                        assert_eq!(aliased.[<as_ $left_variant:snake _mut>]().map(|l| *l), expected.as_mut().ok().map(|l| *l));
+                        assert_eq!(aliased.[<as_ $right_variant:snake _mut>]().map(|r| *r), expected.as_mut().err().map(|r| *r));
+
+                        assert_eq!(alias(value).[<$left_variant:snake _or>](5), value.unwrap_or(5));
+                        assert_eq!(alias(value).[<$left_variant:snake _or_else>](|_| 5), value.unwrap_or_else(|_| 5));
+                        assert_eq!(alias(value).[<$left_variant:snake _or_default>](), value.unwrap_or_default());
+                        assert_eq!(alias(value).[<$right_variant:snake _or>](5), flipped(value).unwrap_or(5));
+                        assert_eq!(alias(value).[<$right_variant:snake _or_else>](|_| 5), flipped(value).unwrap_or_else(|_| 5));
+                        assert_eq!(alias(value).[<$right_variant:snake _or_default>](), flipped(value).unwrap_or_default());
+                        assert_eq!(alias(value).to_string(), value.map_or_else(|l| l.to_string(), |r| r.to_string()));
+
+                        $crate::__alias_methods!(conversions, [$($method),*], {
+                            $crate::__alias_keyword!(@if [<$left_variant:snake>], {}, {
+                                assert_eq!(alias(value).[<$left_variant:snake>](), value.ok());
+                            });
+                            $crate::__alias_keyword!(@if [<$right_variant:snake>], {}, {
+                                assert_eq!(alias(value).[<$right_variant:snake>](), value.err());
+                            });
+                            assert_eq!(alias(value).[<try_into_ $left_variant:snake>](), value);
+                            assert_eq!(alias(value).[<try_into_ $right_variant:snake>](), flipped(value));
+                            $crate::__alias_skip!(either_into, [$($skip),*], {
+                                assert_eq!(alias(value).either_into::<u32>(), value.map_or_else(u32::from, u32::from));
+                            });
+                            $crate::__alias_skip!(into_result, [$($skip),*], {
+                                let result: Result<_, _> = alias(value).into();
+                                assert_eq!(alias(value).into_result(), result);
+                            });
+                        });
+
+                        $crate::__alias_methods!(combinators, [$($method),*], {
+                            assert_eq!(project(alias(value).[<map_ $left_variant:snake>](u32::from)), value.map(u32::from));
+                            assert_eq!(project(alias(value).[<map_ $right_variant:snake>](u32::from)), value.map_err(u32::from));
+                            assert_eq!(
+                                project(alias(value).[<$left_variant:snake _and_then>](|l| alias(Ok(u32::from(l) + 1)))),
+                                value.and_then(|l| Ok(u32::from(l) + 1))
+                            );
+                            assert_eq!(
+                                project(alias(value).[<$right_variant:snake _and_then>](|r| alias(Err(u32::from(r) + 1)))),
+                                value.or_else(|r| Err(u32::from(r) + 1))
+                            );
+                            $crate::__alias_skip!(map_either, [$($skip),*], {
+                                assert_eq!(
+                                    project(alias(value).map_either(u32::from, u64::from)),
+                                    value.map(u32::from).map_err(u64::from)
+                                );
+                            });
+                            $crate::__alias_skip!(map_either_with, [$($skip),*], {
+                                assert_eq!(
+                                    project(alias(value).map_either_with(1, |c, l| u32::from(l) + c, |c, r| u32::from(r) + c)),
+                                    value.map(|l| u32::from(l) + 1).map_err(|r| u32::from(r) + 1)
+                                );
+                            });
+                            $crate::__alias_skip!(flip, [$($skip),*], {
+                                assert_eq!(project(alias(value).flip()), flipped(value));
+                            });
+                            $crate::__alias_skip!(either, [$($skip),*], {
+                                assert_eq!(alias(value).either(u32::from, u32::from), value.map_or_else(u32::from, u32::from));
+                            });
+                            $crate::__alias_skip!(either_with, [$($skip),*], {
+                                assert_eq!(
+                                    alias(value).either_with(1, |c, l| u32::from(l) + c, |c, r| u32::from(r) + c),
+                                    value.map_or_else(|r| u32::from(r) + 1, |l| u32::from(l) + 1)
+                                );
+                            });
+                        });
+
+                        $crate::__alias_skip!(no_panics, [$($skip),*], {
+                            match value {
+                                Ok(l) => assert_eq!(alias(value).[<unwrap_ $left_variant:snake>](), l),
+                                Err(r) => assert_eq!(alias(value).[<unwrap_ $right_variant:snake>](), r),
+                            }
+                        });
+                    }
+
+                    $crate::__alias_skip!(into_inner, [$($skip),*], {
+                        assert_eq!(alias::<u8, u8>(Err(2)).into_inner(), 2);
+                    });
+                    $crate::__alias_methods!(conversions, [$($method),*], {
+                        $crate::__alias_skip!(from_option_or, [$($skip),*], {
+                            assert_eq!(Result::from($type_name::<u8, u8>::from_option_or(Some(1), 2)), Ok(1));
+                            assert_eq!(Result::from($type_name::<u8, u8>::from_option_or(None, 2)), Err(2));
+                        });
+                        $crate::__alias_skip!(from_option_or_else, [$($skip),*], {
+                            assert_eq!(Result::from($type_name::<u8, u8>::from_option_or_else(Some(1), || 2)), Ok(1));
+                            assert_eq!(Result::from($type_name::<u8, u8>::from_option_or_else(None, || 2)), Err(2));
+                        });
+                    });
+
+                    $crate::__alias_unbounded!([$($bound)*], {
+                        for value in VALUES {
+                            $crate::__alias_skip!(as_ref, [$($skip),*], {
+                                assert_eq!(project(alias(value).as_ref()), value.as_ref());
+                            });
+                            $crate::__alias_skip!(as_mut, [$($skip),*], {
+                                let (mut aliased, mut expected) = (alias(value), value);
+                                assert_eq!(project(aliased.as_mut()), expected.as_mut());
+                            });
+                            $crate::__alias_skip!(as_pin_ref, [$($skip),*], {
+                                let aliased = alias(value);
+                                let pinned = project(::core::pin::Pin::new(&aliased).as_pin_ref());
+                                assert_eq!(pinned.map(|l| *l).map_err(|r| *r), value);
+                            });
+                            $crate::__alias_skip!(as_pin_mut, [$($skip),*], {
+                                let mut aliased = alias(value);
+                                let pinned = project(::core::pin::Pin::new(&mut aliased).as_pin_mut());
+                                assert_eq!(pinned.map(|l| *l).map_err(|r| *r), value);
+                            });
+                            $crate::__alias_methods!(combinators, [$($method),*], {
+                                $crate::__alias_skip!(cloned, [$($skip),*], {
+                                    assert_eq!(project(alias(value.as_ref()).cloned()), value);
+                                });
+                                $crate::__alias_skip!(copied, [$($skip),*], {
+                                    assert_eq!(project(alias(value.as_ref()).copied()), value);
+                                });
+                            });
+                        }
+
+                        $crate::__alias_skip!(as_deref, [$($skip),*], {
+                            let aliased = alias::<String, Vec<u8>>(Ok("deref".to_string()));
+                            assert_eq!(project(aliased.as_deref()), Ok("deref"));
+                        });
+                        $crate::__alias_skip!(as_deref_mut, [$($skip),*], {
+                            let mut aliased = alias::<String, Vec<u8>>(Err(vec![1]));
+                            assert_eq!(project(aliased.as_deref_mut()), Err(&mut [1][..]));
+                        });
+
+                        $crate::__alias_methods!(conversions, [$($method),*], {
+                            $crate::__alias_skip!(factor_none, [$($skip),*], {
+                                assert_eq!(alias::<_, Option<u16>>(Ok(Some(1u8))).factor_none().map(project), Some(Ok(1)));
+                                assert_eq!(alias::<Option<u8>, Option<u16>>(Err(None)).factor_none().map(project), None);
+                            });
+                            $crate::__alias_skip!(factor_err, [$($skip),*], {
+                                assert_eq!(alias::<Result<u8, u32>, Result<u16, u32>>(Ok(Ok(1))).factor_err().map(project), Ok(Ok(1)));
+                                assert_eq!(alias::<Result<u8, u32>, Result<u16, u32>>(Err(Err(3))).factor_err().map(project), Err(3));
+                            });
+                            $crate::__alias_skip!(factor_ok, [$($skip),*], {
+                                assert_eq!(alias::<Result<u32, u8>, Result<u32, u16>>(Ok(Ok(3))).factor_ok().map_err(project), Ok(3));
+                                assert_eq!(alias::<Result<u32, u8>, Result<u32, u16>>(Err(Err(2))).factor_ok().map_err(project), Err(Err(2)));
+                            });
+                        });
+
+                        let mut aliased = alias::<String, String>(Ok("as".to_string()));
+                        assert_eq!(AsRef::<str>::as_ref(&aliased), "as");
+                        assert_eq!(AsMut::<str>::as_mut(&mut aliased), "as");
+                        assert_eq!(&*alias::<Box<u8>, Box<u8>>(Err(Box::new(2))), &2);
+
+                        $crate::__alias_methods!(iterators, [$($method),*], {
+                            let mut aliased = alias::<Vec<u8>, Vec<u8>>(Ok(vec![1]));
+                            aliased.extend([2, 3]);
+                            assert_eq!(project(aliased), Ok(vec![1, 2, 3]));
+                            assert!(alias::<_, ::core::ops::Range<u8>>(Ok(0..3)).eq(0..3));
+                            assert!(alias::<::core::ops::Range<u8>, _>(Err(0..3)).rev().eq((0..3).rev()));
+                            assert_eq!(alias::<::core::ops::Range<u8>, ::core::ops::Range<u8>>(Err(0..3)).len(), 3);
+                        });
+                    });
+                }
+            }
+        }
+    };
+    ($flag:ident, $($args:tt)*) => {
+        compile_error!(concat!("`alias_either!` does not accept the `", stringify!($flag), "` option"));
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_either_result {
+    (right, [$vis:vis], $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        $crate::__alias_either_result!(@impl [$vis], $bounds, $methods, $skip, $type_name, $right_variant: R, $left_variant: L);
+    };
+    (left, [$vis:vis], $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        $crate::__alias_either_result!(@impl [$vis], $bounds, $methods, $skip, $type_name, $left_variant: L, $right_variant: R);
+    };
+    (@impl [$vis:vis], [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident: $ok:ident, $err_variant:ident: $err:ident) => {
+        impl<L: $($bound)*, R: $($bound)*> From<Result<$ok, $err>> for $type_name<L, R> {
+            fn from(result: Result<$ok, $err>) -> Self {
+                match result {
+                    Ok(v) => $type_name::$ok_variant(v),
+                    Err(e) => $type_name::$err_variant(e),
+                }
+            }
+        }
+
+        impl<L: $($bound)*, R: $($bound)*> From<$type_name<L, R>> for Result<$ok, $err> {
+            fn from(val: $type_name<L, R>) -> Self {
+                match val {
+                    $type_name::$ok_variant(v) => Ok(v),
+                    $type_name::$err_variant(e) => Err(e),
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<L: $($bound)*, R: $($bound)*> $type_name<L, R> {
+            $crate::__alias_methods!(conversions, $methods, {
+                $crate::__alias_skip!(into_result, $skip, {
+                    $crate::__alias_must_use!($skip, "this returns the converted value, and has no other effect", {
+                        #[doc = concat!("Converts into a `Result`, treating `", stringify!($ok_variant), "` as the ok variant")]
+                        $vis fn into_result(self) -> Result<$ok, $err> {
+                            self.into()
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(from_option_or, $skip, {
+                    #[doc = concat!("Behaves like [`Option::ok_or`](https://doc.rust-lang.org/std/option/enum.Option.html#method.ok_or), producing `", stringify!($ok_variant), "` from `Some`")]
+                    $vis fn from_option_or(option: Option<$ok>, err: $err) -> Self {
+                        match option {
+                            Some(v) => $type_name::$ok_variant(v),
+                            None => $type_name::$err_variant(err),
+                        }
+                    }
+                });
+
+                $crate::__alias_skip!(from_option_or_else, $skip, {
+                    #[doc = concat!("Behaves like [`Option::ok_or_else`](https://doc.rust-lang.org/std/option/enum.Option.html#method.ok_or_else), producing `", stringify!($ok_variant), "` from `Some`")]
+                    $vis fn from_option_or_else<F: FnOnce() -> $err>(option: Option<$ok>, err: F) -> Self {
+                        match option {
+                            Some(v) => $type_name::$ok_variant(v),
+                            None => $type_name::$err_variant(err()),
+                        }
+                    }
+                });
+            });
+        }
+    };
+}
+
+#[cfg(feature = "either")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_either_interop {
+    ([$($bound:tt)*], $type_name:ident, $left_variant:ident, $right_variant:ident) => {
+        impl<L: $($bound)*, R: $($bound)*> From<$crate::__private::either::Either<L, R>> for $type_name<L, R> {
+            fn from(val: $crate::__private::either::Either<L, R>) -> Self {
+                match val {
+                    $crate::__private::either::Either::Left(v) => $type_name::$left_variant(v),
+                    $crate::__private::either::Either::Right(v) => $type_name::$right_variant(v),
+                }
+            }
+        }
+
+        impl<L: $($bound)*, R: $($bound)*> From<$type_name<L, R>> for $crate::__private::either::Either<L, R> {
+            fn from(val: $type_name<L, R>) -> Self {
+                match val {
+                    $type_name::$left_variant(v) => $crate::__private::either::Either::Left(v),
+                    $type_name::$right_variant(v) => $crate::__private::either::Either::Right(v),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "either"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_either_interop {
+    ([$($bound:tt)*], $type_name:ident, $left_variant:ident, $right_variant:ident) => {};
 }
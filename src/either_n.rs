@@ -0,0 +1,312 @@
+/// Creates an Either-like enum with three variants and custom variant names.
+///
+/// See [`examples::Either3Example`](crate::examples::Either3Example) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_either3;
+///
+/// alias_either3!(Route, Fixed, Dynamic, Redirect);
+///
+/// let route: Route<&str, u32, String> = Route::Dynamic(7);
+///
+/// assert!(route.is_dynamic());
+/// assert!(!route.is_fixed());
+/// assert_eq!(route.as_dynamic(), Some(&7));
+/// assert_eq!(route.as_redirect(), None);
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_either3;
+/// alias_either3!(Route, Fixed, Dynamic, Redirect);
+///
+/// let route: Route<&str, u32, String> = Route::Redirect("/home".to_string());
+///
+/// // Mapping one variant changes only its type parameter.
+/// let mapped: Route<&str, u32, usize> = route.clone().map_redirect(|s| s.len());
+/// assert_eq!(mapped, Route::Redirect(5));
+/// assert_eq!(route.as_ref().map_fixed(|s| s.len()), Route::Redirect(&"/home".to_string()));
+///
+/// assert_eq!(route.clone().redirect(), Some("/home".to_string()));
+/// assert_eq!(route.clone().dynamic(), None);
+/// assert_eq!(route.as_ref().cloned(), route);
+///
+/// // Fold the three variants into a single value
+/// let description = route.either(
+///     |path| format!("fixed {path}"),
+///     |id| format!("dynamic {id}"),
+///     |target| format!("redirect to {target}"),
+/// );
+/// assert_eq!(description, "redirect to /home");
+///
+/// let same: Route<i32, i32, i32> = Route::Fixed(3);
+/// assert_eq!(same.into_inner(), 3);
+/// assert_eq!(same.unwrap_fixed(), 3);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_either3;
+/// alias_either3!(CustomChoice, One, Two, Three, traits: [Debug, Clone]);
+/// let val: CustomChoice<i32, (), ()> = CustomChoice::One(42);
+/// assert_eq!(format!("{:?}", val.clone()), "One(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_either3 {
+    ($type_name:ident, $first_variant:ident, $second_variant:ident, $third_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_either3 [$type_name, $first_variant, $second_variant, $third_variant] [A, B, C] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $first_variant:ident, $second_variant:ident, $third_variant:ident], [$A:ident, $B:ident, $C:ident], [], [], $skip:tt, $traits:tt, $attrs:tt, []) => {
+        $crate::__alias_either_n!($vis [$type_name [$first_variant($A), $second_variant($B), $third_variant($C)]], $skip, $traits, $attrs);
+    };
+}
+
+/// Creates an Either-like enum with four variants and custom variant names.
+///
+/// See [`examples::Either4Example`](crate::examples::Either4Example) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_either4;
+///
+/// alias_either4!(Token, Word, Number, Symbol, Space);
+///
+/// let token: Token<String, i64, char, ()> = Token::Symbol('+');
+///
+/// assert!(token.is_symbol());
+/// assert_eq!(token.as_symbol(), Some(&'+'));
+/// assert_eq!(token.as_word(), None);
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_either4;
+/// alias_either4!(Token, Word, Number, Symbol, Space);
+///
+/// let token: Token<String, i64, char, ()> = Token::Number(12);
+///
+/// let mapped: Token<String, f64, char, ()> = token.clone().map_number(|n| n as f64 / 2.0);
+/// assert_eq!(mapped.number(), Some(6.0));
+///
+/// let width = token.either(|w| w.len(), |n| n.to_string().len(), |_| 1, |()| 1);
+/// assert_eq!(width, 2);
+///
+/// let same: Token<i64, i64, i64, i64> = Token::Space(1);
+/// assert_eq!(same.either_into::<i128>(), 1);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_either4;
+/// alias_either4!(CustomChoice, One, Two, Three, Four, traits: [Debug, Clone]);
+/// let val: CustomChoice<(), (), (), i32> = CustomChoice::Four(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Four(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_either4 {
+    ($type_name:ident, $first_variant:ident, $second_variant:ident, $third_variant:ident, $fourth_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_either4 [$type_name, $first_variant, $second_variant, $third_variant, $fourth_variant] [A, B, C, D] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $first_variant:ident, $second_variant:ident, $third_variant:ident, $fourth_variant:ident], [$A:ident, $B:ident, $C:ident, $D:ident], [], [], $skip:tt, $traits:tt, $attrs:tt, []) => {
+        $crate::__alias_either_n!($vis [$type_name [$first_variant($A), $second_variant($B), $third_variant($C), $fourth_variant($D)]], $skip, $traits, $attrs);
+    };
+}
+
+/// Defines the enum of `alias_either3!` or `alias_either4!` and implements its methods, for any
+/// number of variants, each holding the value of its own generic parameter.
+///
+/// The `is_*`, `as_*`, `as_*_mut` and `unwrap_*` methods come from `__alias_oneof_accessors!`. The
+/// `map_*` methods change the parameter of one variant, so they are generated one variant at a
+/// time by `@map`, which keeps the variants before and after the mapped one apart, and collects one
+/// `T` per variant for the `into_inner` impl it emits at the end.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_either_n {
+    ($vis:vis [$type_name:ident [$($variant:ident($generic:ident)),+]], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*]) => {
+        $crate::__alias_impls!(variants [$($generic),+] [$type_name [$($variant($generic)),+]], [$($skip),*]);
+        paste::paste! {
+		#[derive($($trait),*)]
+		$(#[$attr])*
+		$vis enum $type_name<$($generic),+> {
+			$($variant($generic),)+
+		}
+
+		#[allow(dead_code)]
+		impl<$($generic),+> $type_name<$($generic),+> {
+			$(
+			/// Converts into an `Option` holding the
+			#[doc = concat!("`", stringify!($variant), "`")]
+			/// value, discarding any other value.
+			$vis fn [<$variant:snake>](self) -> Option<$generic> {
+				match self {
+					$type_name::$variant(v) => Some(v),
+					_ => None,
+				}
+			}
+			)+
+
+			/// Converts from `&Self` to an alias holding a reference to the value
+			$vis fn as_ref(&self) -> $type_name<$(&$generic),+> {
+				match self {
+					$($type_name::$variant(v) => $type_name::$variant(v),)+
+				}
+			}
+
+			/// Converts from `&mut Self` to an alias holding a mutable reference to the value
+			$vis fn as_mut(&mut self) -> $type_name<$(&mut $generic),+> {
+				match self {
+					$($type_name::$variant(v) => $type_name::$variant(v),)+
+				}
+			}
+
+			/// Applies the function matching the present variant, returning the common result
+			$vis fn either<U>(self, $([<on_ $variant:snake>]: impl FnOnce($generic) -> U),+) -> U {
+				match self {
+					$($type_name::$variant(v) => [<on_ $variant:snake>](v),)+
+				}
+			}
+
+			/// Converts whichever value is present into a common type `T`
+			$vis fn either_into<T>(self) -> T
+			where
+				$($generic: Into<T>,)+
+			{
+				match self {
+					$($type_name::$variant(v) => v.into(),)+
+				}
+			}
+		}
+
+		#[allow(dead_code)]
+		impl<$($generic: Clone),+> $type_name<$(&$generic),+> {
+			/// Clones the referenced value into an owned alias
+			$vis fn cloned(self) -> $type_name<$($generic),+> {
+				match self {
+					$($type_name::$variant(v) => $type_name::$variant(v.clone()),)+
+				}
+			}
+		}
+
+		#[allow(dead_code)]
+		impl<$($generic: Copy),+> $type_name<$(&$generic),+> {
+			/// Copies the referenced value into an owned alias
+			$vis fn copied(self) -> $type_name<$($generic),+> {
+				match self {
+					$($type_name::$variant(&v) => $type_name::$variant(v),)+
+				}
+			}
+		}
+        }
+
+        $crate::__alias_oneof_accessors!($vis $type_name [$($generic),+] [$($variant($generic)),+]);
+        $crate::__alias_either_n!(@map $vis $type_name [$($generic),+] [] [] $($variant($generic)),+);
+    };
+    (@map $vis:vis $type_name:ident [$($all:ident),+] [$($before_variant:ident($before:ident)),*] [$($same:ident)*] $variant:ident($generic:ident) $(, $after_variant:ident($after:ident))*) => {
+        paste::paste! {
+		#[allow(dead_code)]
+		impl<$($all),+> $type_name<$($all),+> {
+			/// Applies `f` to the
+			#[doc = concat!("`", stringify!($variant), "`")]
+			/// value, leaving any other value untouched.
+			$vis fn [<map_ $variant:snake>]<T, F: FnOnce($generic) -> T>(self, f: F) -> $type_name<$($before,)* T $(, $after)*> {
+				match self {
+					$($type_name::$before_variant(v) => $type_name::$before_variant(v),)*
+					$type_name::$variant(v) => $type_name::$variant(f(v)),
+					$($type_name::$after_variant(v) => $type_name::$after_variant(v),)*
+				}
+			}
+		}
+        }
+
+        $crate::__alias_either_n!(@map $vis $type_name [$($all),+] [$($before_variant($before),)* $variant($generic)] [$($same)* T] $($after_variant($after)),*);
+    };
+    (@map $vis:vis $type_name:ident [$($all:ident),+] [$($variant:ident($generic:ident)),*] [$($same:ident)*]) => {
+		#[allow(dead_code)]
+		impl<T> $type_name<$($same),*> {
+			/// Extracts the value regardless of the variant, when all variants have the same type
+			$vis fn into_inner(self) -> T {
+				match self {
+					$($type_name::$variant(v) => v,)*
+				}
+			}
+		}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    alias_either3!(Side, Left, LeftMid, Right);
+    alias_either4!(Token, Word, Number, Symbol, Space);
+
+    #[test]
+    fn map() {
+        let mid: Side<u8, u8, u8> = Side::LeftMid(2);
+        let mapped: Side<u8, String, u8> = mid.map_left_mid(|n| n.to_string());
+        assert_eq!(mapped, Side::LeftMid("2".to_string()));
+        assert_eq!(mid.map_left(|n| n.to_string()), Side::LeftMid(2));
+        assert_eq!(mid.map_right(u16::from), Side::LeftMid(2));
+        let left: Side<u8, u8, u8> = Side::Left(1);
+        assert_eq!(left.map_left(u16::from), Side::Left(1u16));
+
+        let space: Token<(), (), (), u8> = Token::Space(1);
+        assert_eq!(space.map_space(|n| n + 1), Token::Space(2));
+        assert_eq!(space.map_word(|()| 0), Token::Space(1));
+    }
+
+    #[test]
+    fn accessors() {
+        let mut mid: Side<u8, u8, u8> = Side::LeftMid(2);
+        assert!(mid.is_left_mid());
+        assert_eq!(mid.as_left_mid(), Some(&2));
+        assert_eq!(mid.as_right(), None);
+        *mid.as_left_mid_mut().unwrap() += 1;
+        assert_eq!(mid.left_mid(), Some(3));
+        assert_eq!(mid.left(), None);
+        assert_eq!(mid.unwrap_left_mid(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_left()` on a different `Side` variant")]
+    fn unwrap_other_variant() {
+        Side::<u8, u8, u8>::Right(1).unwrap_left();
+    }
+
+    #[test]
+    fn fold() {
+        let width = |token: Token<&str, i64, char, ()>| {
+            token.either(str::len, |n| n.to_string().len(), char::len_utf8, |()| 1)
+        };
+        assert_eq!(width(Token::Word("word")), 4);
+        assert_eq!(width(Token::Number(-12)), 3);
+        assert_eq!(width(Token::Symbol('é')), 2);
+        assert_eq!(width(Token::Space(())), 1);
+
+        let same: Token<u8, u8, u8, u8> = Token::Symbol(7);
+        assert_eq!(same.into_inner(), 7);
+        assert_eq!(same.either_into::<u32>(), 7);
+    }
+
+    #[test]
+    fn references() {
+        let word: Token<String, i64, char, ()> = Token::Word("word".to_string());
+        assert_eq!(word.as_ref().cloned(), word);
+        let mut number: Token<u8, i64, char, ()> = Token::Number(1);
+        if let Token::Number(n) = number.as_mut() {
+            *n += 1;
+        }
+        assert_eq!(number.as_ref().copied(), Token::Number(2));
+    }
+}
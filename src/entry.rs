@@ -0,0 +1,374 @@
+use std::collections::{btree_map, hash_map};
+
+/// The occupied side of a map entry, as used by types generated with [`alias_entry!`](crate::alias_entry).
+///
+/// Implemented for the occupied entries of `HashMap` and `BTreeMap`, and can be implemented for
+/// the entries of custom map types.
+pub trait OccupiedEntry<'a> {
+    type Key;
+    type Value: 'a;
+
+    /// Returns a reference to the entry's key.
+    fn key(&self) -> &Self::Key;
+
+    /// Returns a mutable reference to the entry's value.
+    fn get_mut(&mut self) -> &mut Self::Value;
+
+    /// Converts the entry into a mutable reference to its value, bound to the map's lifetime.
+    fn into_mut(self) -> &'a mut Self::Value;
+}
+
+/// The vacant side of a map entry, as used by types generated with [`alias_entry!`](crate::alias_entry).
+///
+/// Implemented for the vacant entries of `HashMap` and `BTreeMap`, and can be implemented for
+/// the entries of custom map types.
+pub trait VacantEntry<'a> {
+    type Key;
+    type Value: 'a;
+
+    /// Returns a reference to the key that would be used when inserting.
+    fn key(&self) -> &Self::Key;
+
+    /// Inserts the value into the map, and returns a mutable reference to it.
+    fn insert(self, value: Self::Value) -> &'a mut Self::Value;
+}
+
+impl<'a, K, V> OccupiedEntry<'a> for hash_map::OccupiedEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        hash_map::OccupiedEntry::key(self)
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        hash_map::OccupiedEntry::get_mut(self)
+    }
+
+    fn into_mut(self) -> &'a mut V {
+        hash_map::OccupiedEntry::into_mut(self)
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a> for hash_map::VacantEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        hash_map::VacantEntry::key(self)
+    }
+
+    fn insert(self, value: V) -> &'a mut V {
+        hash_map::VacantEntry::insert(self, value)
+    }
+}
+
+impl<'a, K: Ord, V> OccupiedEntry<'a> for btree_map::OccupiedEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        btree_map::OccupiedEntry::key(self)
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        btree_map::OccupiedEntry::get_mut(self)
+    }
+
+    fn into_mut(self) -> &'a mut V {
+        btree_map::OccupiedEntry::into_mut(self)
+    }
+}
+
+impl<'a, K: Ord, V> VacantEntry<'a> for btree_map::VacantEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        btree_map::VacantEntry::key(self)
+    }
+
+    fn insert(self, value: V) -> &'a mut V {
+        btree_map::VacantEntry::insert(self, value)
+    }
+}
+
+/// Creates an Entry-like enum with custom variant names.
+///
+/// See [`examples::EntryExample`](crate::examples::EntryExample) for a generated example.
+///
+/// The generated type is generic over its occupied and vacant payloads. When these implement
+/// [`OccupiedEntry`](crate::OccupiedEntry) and [`VacantEntry`](crate::VacantEntry), the type gets
+/// the familiar entry API.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_entry;
+/// use std::collections::HashMap;
+///
+/// alias_entry!(Slot, Filled, Empty);
+///
+/// let mut map: HashMap<&str, i32> = HashMap::new();
+///
+/// let slot: Slot<_, _> = map.entry("a").into();
+/// assert!(slot.is_empty());
+/// *slot.or_insert(1) += 10;
+///
+/// let slot: Slot<_, _> = map.entry("a").into();
+/// assert!(slot.is_filled());
+/// assert_eq!(map["a"], 11);
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_entry;
+/// use std::collections::BTreeMap;
+/// alias_entry!(Slot, Filled, Empty);
+///
+/// let mut map: BTreeMap<String, Vec<i32>> = BTreeMap::new();
+///
+/// Slot::from(map.entry("a".to_string())).or_default().push(1);
+/// Slot::from(map.entry("a".to_string()))
+///     .and_modify(|v| v.push(2))
+///     .or_insert_with(Vec::new);
+/// assert_eq!(map["a"], vec![1, 2]);
+///
+/// let slot = Slot::from(map.entry("bc".to_string()));
+/// assert_eq!(slot.key(), "bc");
+/// slot.or_insert_with_key(|key| vec![key.len() as i32]);
+/// assert_eq!(map["bc"], vec![2]);
+/// ```
+///
+/// # Custom Map Types
+///
+/// Implementing the entry traits for your own entries gives them the same API.
+///
+/// ```
+/// use enumizer::{alias_entry, OccupiedEntry, VacantEntry};
+/// alias_entry!(Slot, Filled, Empty);
+///
+/// struct Registry(Vec<Option<String>>);
+///
+/// struct Taken<'a>(usize, &'a mut String);
+/// struct Free<'a>(usize, &'a mut Option<String>);
+///
+/// impl<'a> OccupiedEntry<'a> for Taken<'a> {
+///     type Key = usize;
+///     type Value = String;
+///     fn key(&self) -> &usize { &self.0 }
+///     fn get_mut(&mut self) -> &mut String { self.1 }
+///     fn into_mut(self) -> &'a mut String { self.1 }
+/// }
+///
+/// impl<'a> VacantEntry<'a> for Free<'a> {
+///     type Key = usize;
+///     type Value = String;
+///     fn key(&self) -> &usize { &self.0 }
+///     fn insert(self, value: String) -> &'a mut String { self.1.insert(value) }
+/// }
+///
+/// impl Registry {
+///     fn slot(&mut self, index: usize) -> Slot<Taken<'_>, Free<'_>> {
+///         match &mut self.0[index] {
+///             Some(name) => Slot::Filled(Taken(index, name)),
+///             free => Slot::Empty(Free(index, free)),
+///         }
+///     }
+/// }
+///
+/// let mut registry = Registry(vec![None, Some("taken".to_string())]);
+/// assert_eq!(registry.slot(0).or_insert("new".to_string()), "new");
+/// assert_eq!(registry.slot(1).or_insert("new".to_string()), "taken");
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_entry;
+/// alias_entry!(CustomEntry, Present, Absent, traits: [Debug, Clone]);
+/// let val: CustomEntry<i32, ()> = CustomEntry::Present(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Present(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_entry {
+    ($type_name:ident, $occupied_variant:ident, $vacant_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_entry [$type_name, $occupied_variant, $vacant_variant] [O, V] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $occupied_variant:ident, $vacant_variant:ident], [$O:ident, $V:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$O, $V] [$type_name [$occupied_variant($O), $vacant_variant($V)]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$O, $V> {
+            $occupied_variant($O),
+            $vacant_variant($V),
+        }
+
+        #[allow(dead_code)]
+        impl<O, V> $type_name<O, V> {
+            /// Returns `true` if the entry is the occupied variant.
+            $vis fn [<is_ $occupied_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$occupied_variant(_))
+            }
+
+            /// Returns `true` if the entry is the vacant variant.
+            $vis fn [<is_ $vacant_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$vacant_variant(_))
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<'a, O, V> $type_name<O, V>
+        where
+            O: $crate::OccupiedEntry<'a>,
+            V: $crate::VacantEntry<'a, Key = O::Key, Value = O::Value>,
+        {
+            /// Behaves like [`Entry::key`](https://doc.rust-lang.org/std/collections/hash_map/enum.Entry.html#method.key)
+            $vis fn key(&self) -> &O::Key {
+                match self {
+                    $type_name::$occupied_variant(entry) => entry.key(),
+                    $type_name::$vacant_variant(entry) => entry.key(),
+                }
+            }
+
+            /// Behaves like [`Entry::or_insert`](https://doc.rust-lang.org/std/collections/hash_map/enum.Entry.html#method.or_insert)
+            $vis fn or_insert(self, default: O::Value) -> &'a mut O::Value {
+                match self {
+                    $type_name::$occupied_variant(entry) => entry.into_mut(),
+                    $type_name::$vacant_variant(entry) => entry.insert(default),
+                }
+            }
+
+            /// Behaves like [`Entry::or_insert_with`](https://doc.rust-lang.org/std/collections/hash_map/enum.Entry.html#method.or_insert_with)
+            $vis fn or_insert_with<F: FnOnce() -> O::Value>(self, default: F) -> &'a mut O::Value {
+                match self {
+                    $type_name::$occupied_variant(entry) => entry.into_mut(),
+                    $type_name::$vacant_variant(entry) => entry.insert(default()),
+                }
+            }
+
+            /// Behaves like [`Entry::or_insert_with_key`](https://doc.rust-lang.org/std/collections/hash_map/enum.Entry.html#method.or_insert_with_key)
+            $vis fn or_insert_with_key<F: FnOnce(&O::Key) -> O::Value>(self, default: F) -> &'a mut O::Value {
+                match self {
+                    $type_name::$occupied_variant(entry) => entry.into_mut(),
+                    $type_name::$vacant_variant(entry) => {
+                        let value = default(entry.key());
+                        entry.insert(value)
+                    }
+                }
+            }
+
+            /// Behaves like [`Entry::or_default`](https://doc.rust-lang.org/std/collections/hash_map/enum.Entry.html#method.or_default)
+            $vis fn or_default(self) -> &'a mut O::Value
+            where
+                O::Value: Default,
+            {
+                self.or_insert_with(O::Value::default)
+            }
+
+            /// Behaves like [`Entry::and_modify`](https://doc.rust-lang.org/std/collections/hash_map/enum.Entry.html#method.and_modify)
+            $vis fn and_modify<F: FnOnce(&mut O::Value)>(self, f: F) -> Self {
+                match self {
+                    $type_name::$occupied_variant(mut entry) => {
+                        f(entry.get_mut());
+                        $type_name::$occupied_variant(entry)
+                    }
+                    vacant => vacant,
+                }
+            }
+        }
+
+        impl<'a, K, T> From<std::collections::hash_map::Entry<'a, K, T>>
+            for $type_name<std::collections::hash_map::OccupiedEntry<'a, K, T>, std::collections::hash_map::VacantEntry<'a, K, T>>
+        {
+            fn from(entry: std::collections::hash_map::Entry<'a, K, T>) -> Self {
+                match entry {
+                    std::collections::hash_map::Entry::Occupied(entry) => $type_name::$occupied_variant(entry),
+                    std::collections::hash_map::Entry::Vacant(entry) => $type_name::$vacant_variant(entry),
+                }
+            }
+        }
+
+        impl<'a, K, T> From<$type_name<std::collections::hash_map::OccupiedEntry<'a, K, T>, std::collections::hash_map::VacantEntry<'a, K, T>>>
+            for std::collections::hash_map::Entry<'a, K, T>
+        {
+            fn from(val: $type_name<std::collections::hash_map::OccupiedEntry<'a, K, T>, std::collections::hash_map::VacantEntry<'a, K, T>>) -> Self {
+                match val {
+                    $type_name::$occupied_variant(entry) => std::collections::hash_map::Entry::Occupied(entry),
+                    $type_name::$vacant_variant(entry) => std::collections::hash_map::Entry::Vacant(entry),
+                }
+            }
+        }
+
+        impl<'a, K: Ord, T> From<std::collections::btree_map::Entry<'a, K, T>>
+            for $type_name<std::collections::btree_map::OccupiedEntry<'a, K, T>, std::collections::btree_map::VacantEntry<'a, K, T>>
+        {
+            fn from(entry: std::collections::btree_map::Entry<'a, K, T>) -> Self {
+                match entry {
+                    std::collections::btree_map::Entry::Occupied(entry) => $type_name::$occupied_variant(entry),
+                    std::collections::btree_map::Entry::Vacant(entry) => $type_name::$vacant_variant(entry),
+                }
+            }
+        }
+
+        impl<'a, K: Ord, T> From<$type_name<std::collections::btree_map::OccupiedEntry<'a, K, T>, std::collections::btree_map::VacantEntry<'a, K, T>>>
+            for std::collections::btree_map::Entry<'a, K, T>
+        {
+            fn from(val: $type_name<std::collections::btree_map::OccupiedEntry<'a, K, T>, std::collections::btree_map::VacantEntry<'a, K, T>>) -> Self {
+                match val {
+                    $type_name::$occupied_variant(entry) => std::collections::btree_map::Entry::Occupied(entry),
+                    $type_name::$vacant_variant(entry) => std::collections::btree_map::Entry::Vacant(entry),
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap, btree_map, hash_map};
+
+    alias_entry!(Slot, Filled, Empty);
+
+    type HashSlot<'a> = Slot<
+        hash_map::OccupiedEntry<'a, &'static str, u32>,
+        hash_map::VacantEntry<'a, &'static str, u32>,
+    >;
+
+    #[test]
+    fn hash_map() {
+        let mut counts = HashMap::new();
+        for word in ["a", "b", "a"] {
+            let slot = HashSlot::from(counts.entry(word));
+            assert_eq!(*slot.key(), word);
+            *slot.and_modify(|count| *count += 10).or_insert(1) += 1;
+        }
+        assert_eq!(counts["a"], 13);
+        assert_eq!(counts["b"], 2);
+        assert!(HashSlot::from(counts.entry("a")).is_filled());
+        assert!(HashSlot::from(counts.entry("c")).is_empty());
+        assert!(matches!(
+            hash_map::Entry::from(HashSlot::from(counts.entry("c"))),
+            hash_map::Entry::Vacant(_)
+        ));
+    }
+
+    #[test]
+    fn btree_map() {
+        let mut lengths = BTreeMap::new();
+        let slot: Slot<
+            btree_map::OccupiedEntry<'_, String, usize>,
+            btree_map::VacantEntry<'_, String, usize>,
+        > = lengths.entry("four".to_string()).into();
+        assert_eq!(*slot.or_insert_with_key(|key| key.len()), 4);
+        *Slot::from(lengths.entry("none".to_string())).or_default() += 1;
+        assert_eq!(lengths["four"], 4);
+        assert_eq!(lengths["none"], 1);
+    }
+}
@@ -0,0 +1,319 @@
+/// Generates per-variant accessors for an arbitrary enum definition.
+///
+/// See [`examples::EnumizeExample`](crate::examples::EnumizeExample) for a generated example.
+///
+/// The enum is emitted as written, with its attributes and visibility, and each variant gets the
+/// same accessor families the fixed-shape aliases have. Unit variants get an `is_*` predicate, and
+/// tuple variants additionally get `as_*`, `as_*_mut`, `map_*`, `unwrap_*` and `into_*`. Variants
+/// with several fields expose their payload as a tuple, with up to 12 fields per variant.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::enumize;
+///
+/// enumize! {
+///     #[derive(Debug, Clone, PartialEq)]
+///     pub enum Shape {
+///         Circle(f64),
+///         Rect(f64, f64),
+///         Empty,
+///     }
+/// }
+///
+/// let circle = Shape::Circle(1.0);
+/// assert!(circle.is_circle());
+/// assert!(!circle.is_rect());
+/// assert!(Shape::Empty.is_empty());
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::enumize;
+///
+/// enumize! {
+///     #[derive(Debug, Clone, PartialEq)]
+///     enum Shape {
+///         Circle(f64),
+///         Rect(f64, f64),
+///         Empty,
+///     }
+/// }
+///
+/// let mut circle = Shape::Circle(1.0);
+/// assert_eq!(circle.as_circle(), Some(&1.0));
+/// assert_eq!(circle.as_rect(), None);
+/// if let Some(radius) = circle.as_circle_mut() {
+///     *radius = 2.0;
+/// }
+/// assert_eq!(circle.clone().map_circle(|r| r * 2.0), Shape::Circle(4.0));
+/// assert_eq!(circle.clone().into_circle(), Some(2.0));
+/// assert_eq!(circle.unwrap_circle(), 2.0);
+///
+/// // Variants with several fields expose them as a tuple
+/// let rect = Shape::Rect(2.0, 3.0);
+/// assert_eq!(rect.as_rect(), Some((&2.0, &3.0)));
+/// assert_eq!(rect.clone().map_rect(|(w, h)| (h, w)), Shape::Rect(3.0, 2.0));
+/// assert_eq!(rect.clone().into_rect(), Some((2.0, 3.0)));
+/// assert_eq!(rect.unwrap_rect(), (2.0, 3.0));
+/// ```
+///
+/// # Generic Enums
+///
+/// ```
+/// use enumizer::enumize;
+///
+/// enumize! {
+///     #[derive(Debug, PartialEq)]
+///     enum Message<T> {
+///         Data(T),
+///         Error(String),
+///         Close,
+///     }
+/// }
+///
+/// let message: Message<u8> = Message::Data(7);
+/// assert_eq!(message.map_data(|x| x + 1), Message::Data(8));
+/// assert_eq!(Message::<u8>::Error("oops".to_string()).into_error(), Some("oops".to_string()));
+/// ```
+#[macro_export]
+macro_rules! enumize {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $type_name:ident $(<$($generic:ident),* $(,)?>)? {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident $(($($field:ty),+ $(,)?))?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $type_name $(<$($generic),*>)? {
+            $(
+                $(#[$variant_meta])*
+                $variant $(($($field),+))?,
+            )*
+        }
+
+        $crate::__enumize_variants!($type_name [$($($generic),*)?] $($variant $(($($field),+))?)*);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enumize_variants {
+    ($type_name:ident $generics:tt $($variant:ident $(($($field:ty),+))?)*) => {
+        $(
+            $crate::__enumize_variant!($type_name $generics $variant $(($($field),+))?);
+        )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enumize_variant {
+    ($type_name:ident [$($generic:ident),*] $variant:ident) => {
+        paste::paste! {
+        #[allow(dead_code)]
+        impl<$($generic),*> $type_name<$($generic),*> {
+            /// Returns true if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<is_ $variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$variant)
+            }
+        }
+        }
+    };
+    ($type_name:ident [$($generic:ident),*] $variant:ident ($field:ty)) => {
+        paste::paste! {
+        #[allow(dead_code)]
+        impl<$($generic),*> $type_name<$($generic),*> {
+            /// Returns true if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<is_ $variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$variant(..))
+            }
+
+            /// Returns a reference to the payload if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<as_ $variant:lower>](&self) -> Option<&$field> {
+                match self {
+                    $type_name::$variant(v) => Some(v),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Returns a mutable reference to the payload if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<as_ $variant:lower _mut>](&mut self) -> Option<&mut $field> {
+                match self {
+                    $type_name::$variant(v) => Some(v),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Applies `f` to the payload if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant, leaving other variants untouched
+            pub fn [<map_ $variant:lower>]<F: FnOnce($field) -> $field>(self, f: F) -> Self {
+                match self {
+                    $type_name::$variant(v) => $type_name::$variant(f(v)),
+                    #[allow(unreachable_patterns)]
+                    other => other,
+                }
+            }
+
+            /// Unwraps the payload, panicking if this is not the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<unwrap_ $variant:lower>](self) -> $field {
+                match self {
+                    $type_name::$variant(v) => v,
+                    #[allow(unreachable_patterns)]
+                    _ => panic!(concat!("called `unwrap_", stringify!([<$variant:lower>]), "()` on a different `", stringify!($type_name), "` variant")),
+                }
+            }
+
+            /// Converts into an `Option` holding the payload if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<into_ $variant:lower>](self) -> Option<$field> {
+                match self {
+                    $type_name::$variant(v) => Some(v),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+        }
+        }
+    };
+    ($type_name:ident $generics:tt $variant:ident ($($field:ty),+)) => {
+        $crate::__enumize_variant!(@bind $type_name $generics $variant [] [f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 f10 f11] $($field),+);
+    };
+    (@bind $type_name:ident $generics:tt $variant:ident [$($bound:tt)*] [$next:ident $($pool:ident)*] $field:ty $(, $rest:ty)*) => {
+        $crate::__enumize_variant!(@bind $type_name $generics $variant [$($bound)* ($next: $field)] [$($pool)*] $($rest),*);
+    };
+    (@bind $type_name:ident [$($generic:ident),*] $variant:ident [$(($binding:ident: $field:ty))+] [$($pool:ident)*]) => {
+        paste::paste! {
+        #[allow(dead_code)]
+        impl<$($generic),*> $type_name<$($generic),*> {
+            /// Returns true if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<is_ $variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$variant(..))
+            }
+
+            /// Returns references to the payload fields if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<as_ $variant:lower>](&self) -> Option<($(&$field,)+)> {
+                match self {
+                    $type_name::$variant($($binding),+) => Some(($($binding,)+)),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Returns mutable references to the payload fields if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<as_ $variant:lower _mut>](&mut self) -> Option<($(&mut $field,)+)> {
+                match self {
+                    $type_name::$variant($($binding),+) => Some(($($binding,)+)),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Applies `f` to the payload fields if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant, leaving other variants untouched
+            pub fn [<map_ $variant:lower>]<F: FnOnce(($($field,)+)) -> ($($field,)+)>(self, f: F) -> Self {
+                match self {
+                    $type_name::$variant($($binding),+) => {
+                        let ($($binding,)+) = f(($($binding,)+));
+                        $type_name::$variant($($binding),+)
+                    }
+                    #[allow(unreachable_patterns)]
+                    other => other,
+                }
+            }
+
+            /// Unwraps the payload fields, panicking if this is not the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<unwrap_ $variant:lower>](self) -> ($($field,)+) {
+                match self {
+                    $type_name::$variant($($binding),+) => ($($binding,)+),
+                    #[allow(unreachable_patterns)]
+                    _ => panic!(concat!("called `unwrap_", stringify!([<$variant:lower>]), "()` on a different `", stringify!($type_name), "` variant")),
+                }
+            }
+
+            /// Converts into an `Option` holding the payload fields if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            pub fn [<into_ $variant:lower>](self) -> Option<($($field,)+)> {
+                match self {
+                    $type_name::$variant($($binding),+) => Some(($($binding,)+)),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    enumize! {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Frame<T> {
+            Data(T),
+            Span(usize, usize),
+            End,
+        }
+    }
+
+    #[test]
+    fn single_field() {
+        let mut data: Frame<u8> = Frame::Data(1);
+        assert!(data.is_data());
+        assert!(!data.is_end());
+        *data.as_data_mut().unwrap() += 1;
+        assert_eq!(data.as_data(), Some(&2));
+        assert_eq!(data.clone().map_data(|x| x * 10), Frame::Data(20));
+        assert_eq!(data.clone().into_data(), Some(2));
+        assert_eq!(data.unwrap_data(), 2);
+        assert_eq!(Frame::<u8>::End.map_data(|x| x * 10), Frame::End);
+        assert_eq!(Frame::<u8>::End.into_data(), None);
+    }
+
+    #[test]
+    fn several_fields() {
+        let mut span: Frame<u8> = Frame::Span(1, 4);
+        assert_eq!(span.as_span(), Some((&1, &4)));
+        if let Some((start, _)) = span.as_span_mut() {
+            *start = 2;
+        }
+        assert_eq!(span.clone().map_span(|(s, e)| (e, s)), Frame::Span(4, 2));
+        assert_eq!(span.clone().into_span(), Some((2, 4)));
+        assert_eq!(span.unwrap_span(), (2, 4));
+        assert_eq!(Frame::Data(1).as_span(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_span()` on a different `Frame` variant")]
+    fn unwrap_other_variant() {
+        Frame::<u8>::End.unwrap_span();
+    }
+}
@@ -0,0 +1,217 @@
+/// Creates an error enum with custom variant names, each wrapping a different error type.
+///
+/// See [`examples::ErrorExample`](crate::examples::ErrorExample) for a generated example.
+///
+/// Every payload gets a `From` impl, so the `?` operator converts the wrapped errors into the
+/// generated type. The generated type is transparent: `Display` and `Error::source` forward to the
+/// wrapped error. Because of the `From` impls, each payload type may only appear in one variant.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_error;
+/// use std::num::ParseIntError;
+///
+/// alias_error!(AppError, Io(std::io::Error), Parse(ParseIntError));
+///
+/// fn parse(input: &str) -> Result<i32, AppError> {
+///     Ok(input.trim().parse::<i32>()?)
+/// }
+///
+/// assert_eq!(parse(" 42 ").unwrap(), 42);
+/// let err = parse("forty-two").unwrap_err();
+/// assert!(err.is_parse());
+/// assert!(!err.is_io());
+/// assert_eq!(err.to_string(), "invalid digit found in string");
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_error;
+/// use std::error::Error;
+/// use std::num::ParseIntError;
+/// alias_error!(AppError, Io(std::io::Error), Parse(ParseIntError));
+///
+/// let err = AppError::io(std::io::Error::other("disk full"));
+/// assert_eq!(err.as_io().map(|e| e.kind()), Some(std::io::ErrorKind::Other));
+/// assert!(err.as_parse().is_none());
+/// assert_eq!(err.to_string(), "disk full");
+/// assert!(err.source().is_none());
+///
+/// let parse_err = "x".parse::<u8>().unwrap_err();
+/// assert_eq!(AppError::parse(parse_err.clone()).into_parse(), Some(parse_err));
+/// ```
+///
+/// # Custom Traits
+///
+/// Only `Debug` is derived by default, since many error types implement nothing else.
+/// You can specify custom traits to derive instead, using the `traits:` keyword followed by a list
+/// of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_error;
+/// use std::num::{ParseFloatError, ParseIntError};
+/// alias_error!(NumberError, Int(ParseIntError), Float(ParseFloatError), traits: [Debug, Clone, PartialEq]);
+/// let err: NumberError = "1.5".parse::<i32>().unwrap_err().into();
+/// assert_eq!(err.clone(), err);
+/// ```
+#[macro_export]
+macro_rules! alias_error {
+    (@impl $vis:vis [$type_name:ident, [$($variant:ident($payload:ty)),+]], [], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [] [$type_name [$($variant($payload)),+]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name {
+            $($variant($payload),)+
+        }
+
+        #[allow(dead_code)]
+        impl $type_name {
+            $(
+            /// Creates the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant from anything convertible to its payload
+            $vis fn [<$variant:lower>](error: impl Into<$payload>) -> Self {
+                $type_name::$variant(error.into())
+            }
+
+            /// Returns true if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            $vis fn [<is_ $variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$variant(_))
+            }
+
+            /// Returns a reference to the wrapped error if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            $vis fn [<as_ $variant:lower>](&self) -> Option<&$payload> {
+                match self {
+                    $type_name::$variant(error) => Some(error),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Converts into an `Option` holding the wrapped error if this is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant
+            $vis fn [<into_ $variant:lower>](self) -> Option<$payload> {
+                match self {
+                    $type_name::$variant(error) => Some(error),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+            )+
+        }
+
+        $(
+        impl From<$payload> for $type_name {
+            fn from(error: $payload) -> Self {
+                $type_name::$variant(error)
+            }
+        }
+        )+
+
+        impl ::core::fmt::Display for $type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $($type_name::$variant(error) => ::core::fmt::Display::fmt(error, f),)+
+                }
+            }
+        }
+
+        impl ::core::error::Error for $type_name {
+            fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                match self {
+                    $($type_name::$variant(error) => ::core::error::Error::source(error),)+
+                }
+            }
+        }
+        }
+    };
+    ($type_name:ident, $($rest:tt)+) => {
+        $crate::__alias_error_variants!($type_name, [], $($rest)+);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_error_variants {
+    ($type_name:ident, [$($variants:tt)*], $variant:ident($payload:ty) $(, $($rest:tt)*)?) => {
+        $crate::__alias_error_variants!($type_name, [$($variants)* $variant($payload),], $($($rest)*)?);
+    };
+    ($type_name:ident, [$($variant:ident($payload:ty),)+], $($option:tt)*) => {
+        $crate::__alias_options!(alias_error [$type_name, [$($variant($payload)),+]] [] [Debug], $($option)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::fmt;
+    use std::num::ParseIntError;
+
+    /// An error with a source, to check that `source` forwards to the wrapped error.
+    #[derive(Debug)]
+    struct Config(ParseIntError);
+
+    impl fmt::Display for Config {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("invalid config")
+        }
+    }
+
+    impl Error for Config {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    alias_error!(AppError, Parse(ParseIntError), Load(Config), vis: private);
+
+    fn parse_error() -> ParseIntError {
+        "x".parse::<u8>().unwrap_err()
+    }
+
+    #[test]
+    fn question_mark() {
+        fn load(input: &str) -> Result<u8, AppError> {
+            let value = input.parse::<u8>()?;
+            if value == 0 {
+                Err(Config(parse_error()))?;
+            }
+            Ok(value)
+        }
+
+        assert_eq!(load("1").unwrap(), 1);
+        assert!(load("x").unwrap_err().is_parse());
+        assert!(load("0").unwrap_err().is_load());
+    }
+
+    #[test]
+    fn forwarding() {
+        let parse = AppError::parse(parse_error());
+        assert_eq!(parse.to_string(), parse_error().to_string());
+        assert!(parse.source().is_none());
+
+        let load = AppError::load(Config(parse_error()));
+        assert_eq!(load.to_string(), "invalid config");
+        assert_eq!(
+            load.source().unwrap().to_string(),
+            parse_error().to_string()
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let parse = AppError::from(parse_error());
+        assert_eq!(parse.as_parse(), Some(&parse_error()));
+        assert!(parse.as_load().is_none());
+        assert_eq!(parse.into_parse(), Some(parse_error()));
+        assert!(AppError::load(Config(parse_error())).into_parse().is_none());
+    }
+}
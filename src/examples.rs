@@ -5,3 +5,33 @@ use super::*;
 alias_option!(OptionExample, Found, Missing);
 alias_either!(EitherExample, Good, Bad);
 alias_result!(ResultExample, Success, Failure);
+alias_poll!(PollExample, Ready, Waiting);
+alias_control_flow!(ControlFlowExample, Proceed, Halt);
+alias_ordering!(OrderingExample, Before, Same, After);
+alias_bound!(BoundExample, Closed, Open, Unlimited);
+alias_cow!(CowExample, Borrowed, Owned);
+alias_entry!(EntryExample, Occupied, Vacant);
+alias_oneof!(OneOfExample<A, B, C>, First(A), Second(B), Third(C));
+alias_either3!(Either3Example, First, Second, Third);
+alias_either4!(Either4Example, First, Second, Third, Fourth);
+alias_validation!(ValidationExample, Valid, Invalid);
+alias_these!(TheseExample, OnlyLeft, OnlyRight, Both);
+alias_remote_data!(RemoteDataExample, Idle, Loading, Loaded, Errored);
+alias_maybe_done!(MaybeDoneExample, Future, Done, Gone);
+alias_tristate!(TristateExample, Yes, No, Unknown);
+enumize! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum EnumizeExample<T> {
+        Single(T),
+        Pair(T, T),
+        Nothing,
+    }
+}
+alias_error!(ErrorExample, Io(std::io::Error), Format(std::fmt::Error));
+alias_nested_result!(NestedResultExample, Success, InnerFailure, OuterFailure);
+alias_fieldless!(FieldlessExample, std::net::Shutdown { Read => Inbound, Write => Outbound, Both => Full });
+alias_ipaddr!(IpAddrExample, V4, V6);
+alias_try_recv_error!(TryRecvErrorExample, Empty, Disconnected);
+alias_recv_timeout_error!(RecvTimeoutErrorExample, Timeout, Disconnected);
+alias_try_send_error!(TrySendErrorExample, Full, Disconnected);
+alias_try_lock_error!(TryLockErrorExample, Poisoned, WouldBlock);
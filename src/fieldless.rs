@@ -0,0 +1,131 @@
+/// Creates a renamed copy of a fieldless enum, such as [`std::net::Shutdown`], with custom variant names.
+///
+/// See [`examples::FieldlessExample`](crate::examples::FieldlessExample) for a generated example.
+///
+/// The target enum is followed by a mapping from each of its variants to the new variant name.
+/// The conversions match exhaustively, so every variant of the target must be mapped, and enums
+/// marked `#[non_exhaustive]` are not supported.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_fieldless;
+///
+/// alias_fieldless!(HalfClose, std::net::Shutdown {
+///     Read => Inbound,
+///     Write => Outbound,
+///     Both => Full,
+/// });
+///
+/// let close = HalfClose::Inbound;
+/// assert!(close.is_inbound());
+/// assert!(!close.is_full());
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be losslessly converted to and from the target enum.
+///
+/// ```
+/// use enumizer::alias_fieldless;
+/// use std::net::Shutdown;
+/// alias_fieldless!(HalfClose, Shutdown { Read => Inbound, Write => Outbound, Both => Full });
+///
+/// let from_std: HalfClose = Shutdown::Write.into();
+/// assert_eq!(from_std, HalfClose::Outbound);
+///
+/// let to_std: Shutdown = HalfClose::Full.into();
+/// assert_eq!(to_std, Shutdown::Both);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_fieldless;
+/// use std::cmp::Ordering;
+/// alias_fieldless!(Rank, Ordering { Less => Lower, Equal => Tied, Greater => Higher }, traits: [Debug]);
+/// assert_eq!(format!("{:?}", Rank::from(Ordering::Less)), "Lower");
+/// ```
+#[macro_export]
+macro_rules! alias_fieldless {
+    ($type_name:ident, $target:path { $($target_variant:ident => $variant:ident),+ $(,)? } $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_fieldless [$type_name, $target { $($target_variant => $variant),+ }] [] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $target:path { $($target_variant:ident => $variant:ident),+ }], [], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [] [$type_name [$($variant),+]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name {
+            $($variant,)+
+        }
+
+        #[allow(dead_code)]
+        impl $type_name {
+            $(
+            /// Returns true if this is the
+            #[doc = concat!("`", stringify!($target_variant), "`")]
+            /// equivalent variant
+            $vis fn [<is_ $variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$variant)
+            }
+            )+
+        }
+
+        impl From<$target> for $type_name {
+            fn from(val: $target) -> Self {
+                use $target as Target;
+                match val {
+                    $(Target::$target_variant => $type_name::$variant,)+
+                }
+            }
+        }
+
+        impl From<$type_name> for $target {
+            fn from(val: $type_name) -> Self {
+                use $target as Target;
+                match val {
+                    $($type_name::$variant => Target::$target_variant,)+
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Shutdown;
+
+    alias_fieldless!(HalfClose, Shutdown { Read => Inbound, Write => Outbound, Both => Full });
+
+    mod level {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum Level {
+            Low,
+            High,
+        }
+    }
+
+    alias_fieldless!(Signal, level::Level { Low => Off, High => On });
+
+    #[test]
+    fn round_trip() {
+        for shutdown in [Shutdown::Read, Shutdown::Write, Shutdown::Both] {
+            assert_eq!(Shutdown::from(HalfClose::from(shutdown)), shutdown);
+        }
+        assert!(HalfClose::from(Shutdown::Read).is_inbound());
+        assert!(HalfClose::from(Shutdown::Write).is_outbound());
+        assert!(HalfClose::from(Shutdown::Both).is_full());
+    }
+
+    #[test]
+    fn nested_path() {
+        assert_eq!(Signal::from(level::Level::High), Signal::On);
+        assert_eq!(level::Level::from(Signal::Off), level::Level::Low);
+        assert!(Signal::Off < Signal::On);
+    }
+}
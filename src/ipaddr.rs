@@ -0,0 +1,253 @@
+/// Creates an IpAddr-like enum with custom variant names.
+///
+/// See [`examples::IpAddrExample`](crate::examples::IpAddrExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_ipaddr;
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+///
+/// alias_ipaddr!(Address, Legacy, Modern);
+///
+/// let legacy = Address::Legacy(Ipv4Addr::LOCALHOST);
+/// let modern = Address::Modern(Ipv6Addr::UNSPECIFIED);
+///
+/// assert!(legacy.is_legacy());
+/// assert!(!legacy.is_modern());
+/// assert!(modern.is_modern());
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_ipaddr;
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+/// alias_ipaddr!(Address, Legacy, Modern);
+///
+/// let legacy = Address::Legacy(Ipv4Addr::LOCALHOST);
+/// assert_eq!(legacy.as_legacy(), Some(&Ipv4Addr::LOCALHOST));
+/// assert_eq!(legacy.legacy(), Some(Ipv4Addr::LOCALHOST));
+/// assert_eq!(legacy.modern(), None);
+/// assert!(legacy.is_loopback());
+///
+/// let modern = Address::Modern(Ipv6Addr::UNSPECIFIED);
+/// assert!(modern.is_unspecified());
+/// assert!(!modern.is_multicast());
+/// ```
+///
+/// # Parsing and Formatting
+///
+/// `Display` and `FromStr` delegate to [`std::net::IpAddr`].
+///
+/// ```
+/// use enumizer::alias_ipaddr;
+/// alias_ipaddr!(Address, Legacy, Modern);
+///
+/// let parsed: Address = "::1".parse().unwrap();
+/// assert!(parsed.is_modern());
+/// assert_eq!(parsed.to_string(), "::1");
+/// assert!("not an address".parse::<Address>().is_err());
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `IpAddr`, and created from
+/// `Ipv4Addr` and `Ipv6Addr`.
+///
+/// ```
+/// use enumizer::alias_ipaddr;
+/// use std::net::{IpAddr, Ipv4Addr};
+/// alias_ipaddr!(Address, Legacy, Modern);
+///
+/// let from_v4: Address = Ipv4Addr::new(10, 0, 0, 1).into();
+/// assert_eq!(from_v4, Address::Legacy(Ipv4Addr::new(10, 0, 0, 1)));
+///
+/// let to_std: IpAddr = from_v4.into();
+/// assert_eq!(to_std, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+///
+/// let back: Address = to_std.into();
+/// assert_eq!(back, from_v4);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_ipaddr;
+/// use std::net::Ipv4Addr;
+/// alias_ipaddr!(CustomAddress, Four, Six, traits: [Debug, Clone]);
+/// let val = CustomAddress::Four(Ipv4Addr::BROADCAST);
+/// assert_eq!(format!("{:?}", val.clone()), "Four(255.255.255.255)");
+/// ```
+#[macro_export]
+macro_rules! alias_ipaddr {
+    ($type_name:ident, $v4_variant:ident, $v6_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_ipaddr [$type_name, $v4_variant, $v6_variant] [] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $v4_variant:ident, $v6_variant:ident], [], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [] [$type_name [$v4_variant(::core::net::Ipv4Addr), $v6_variant(::core::net::Ipv6Addr)]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name {
+            $v4_variant(::core::net::Ipv4Addr),
+            $v6_variant(::core::net::Ipv6Addr),
+        }
+
+        #[allow(dead_code)]
+        impl $type_name {
+            /// Behaves like [`IpAddr::is_ipv4`](https://doc.rust-lang.org/std/net/enum.IpAddr.html#method.is_ipv4)
+            $vis fn [<is_ $v4_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$v4_variant(_))
+            }
+
+            /// Behaves like [`IpAddr::is_ipv6`](https://doc.rust-lang.org/std/net/enum.IpAddr.html#method.is_ipv6)
+            $vis fn [<is_ $v6_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$v6_variant(_))
+            }
+
+            /// Returns a reference to the IPv4 address, if present
+            $vis fn [<as_ $v4_variant:lower>](&self) -> Option<&::core::net::Ipv4Addr> {
+                match self {
+                    $type_name::$v4_variant(addr) => Some(addr),
+                    _ => None,
+                }
+            }
+
+            /// Returns a reference to the IPv6 address, if present
+            $vis fn [<as_ $v6_variant:lower>](&self) -> Option<&::core::net::Ipv6Addr> {
+                match self {
+                    $type_name::$v6_variant(addr) => Some(addr),
+                    _ => None,
+                }
+            }
+
+            /// Returns the IPv4 address, if present
+            $vis fn [<$v4_variant:lower>](&self) -> Option<::core::net::Ipv4Addr> {
+                self.[<as_ $v4_variant:lower>]().copied()
+            }
+
+            /// Returns the IPv6 address, if present
+            $vis fn [<$v6_variant:lower>](&self) -> Option<::core::net::Ipv6Addr> {
+                self.[<as_ $v6_variant:lower>]().copied()
+            }
+
+            /// Behaves like [`IpAddr::is_unspecified`](https://doc.rust-lang.org/std/net/enum.IpAddr.html#method.is_unspecified)
+            $vis fn is_unspecified(&self) -> bool {
+                match self {
+                    $type_name::$v4_variant(addr) => addr.is_unspecified(),
+                    $type_name::$v6_variant(addr) => addr.is_unspecified(),
+                }
+            }
+
+            /// Behaves like [`IpAddr::is_loopback`](https://doc.rust-lang.org/std/net/enum.IpAddr.html#method.is_loopback)
+            $vis fn is_loopback(&self) -> bool {
+                match self {
+                    $type_name::$v4_variant(addr) => addr.is_loopback(),
+                    $type_name::$v6_variant(addr) => addr.is_loopback(),
+                }
+            }
+
+            /// Behaves like [`IpAddr::is_multicast`](https://doc.rust-lang.org/std/net/enum.IpAddr.html#method.is_multicast)
+            $vis fn is_multicast(&self) -> bool {
+                match self {
+                    $type_name::$v4_variant(addr) => addr.is_multicast(),
+                    $type_name::$v6_variant(addr) => addr.is_multicast(),
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for $type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $type_name::$v4_variant(addr) => addr.fmt(f),
+                    $type_name::$v6_variant(addr) => addr.fmt(f),
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $type_name {
+            type Err = ::core::net::AddrParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse::<::core::net::IpAddr>().map(Self::from)
+            }
+        }
+
+        impl From<::core::net::Ipv4Addr> for $type_name {
+            fn from(addr: ::core::net::Ipv4Addr) -> Self {
+                $type_name::$v4_variant(addr)
+            }
+        }
+
+        impl From<::core::net::Ipv6Addr> for $type_name {
+            fn from(addr: ::core::net::Ipv6Addr) -> Self {
+                $type_name::$v6_variant(addr)
+            }
+        }
+
+        impl From<::core::net::IpAddr> for $type_name {
+            fn from(addr: ::core::net::IpAddr) -> Self {
+                match addr {
+                    ::core::net::IpAddr::V4(addr) => $type_name::$v4_variant(addr),
+                    ::core::net::IpAddr::V6(addr) => $type_name::$v6_variant(addr),
+                }
+            }
+        }
+
+        impl From<$type_name> for ::core::net::IpAddr {
+            fn from(val: $type_name) -> Self {
+                match val {
+                    $type_name::$v4_variant(addr) => ::core::net::IpAddr::V4(addr),
+                    $type_name::$v6_variant(addr) => ::core::net::IpAddr::V6(addr),
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    alias_ipaddr!(Address, Legacy, Modern);
+
+    #[test]
+    fn matches_ip_addr() {
+        for text in [
+            "127.0.0.1",
+            "0.0.0.0",
+            "224.0.0.1",
+            "10.1.2.3",
+            "::1",
+            "::",
+            "ff02::1",
+            "2001:db8::1",
+        ] {
+            let address: Address = text.parse().unwrap();
+            let ip: IpAddr = text.parse().unwrap();
+            assert_eq!(address.to_string(), ip.to_string());
+            assert_eq!(address.is_loopback(), ip.is_loopback());
+            assert_eq!(address.is_unspecified(), ip.is_unspecified());
+            assert_eq!(address.is_multicast(), ip.is_multicast());
+            assert_eq!(address.is_legacy(), ip.is_ipv4());
+            assert_eq!(IpAddr::from(address), ip);
+        }
+        assert!("localhost".parse::<Address>().is_err());
+    }
+
+    #[test]
+    fn accessors() {
+        let legacy = Address::from(Ipv4Addr::LOCALHOST);
+        assert_eq!(legacy.legacy(), Some(Ipv4Addr::LOCALHOST));
+        assert_eq!(legacy.as_modern(), None);
+        let modern = Address::from(Ipv6Addr::LOCALHOST);
+        assert!(modern.is_modern());
+        assert_eq!(modern.as_modern(), Some(&Ipv6Addr::LOCALHOST));
+        assert_eq!(modern.legacy(), None);
+    }
+}
@@ -1,7 +1,402 @@
 //! This crate provides easy to use macros, that allow the user to create enum types that are equivalent and convertible to standard library enum types, with custom variant names.
 //!
 //! See the `examples` module for examples of the generated types.
+//!
+//! # Common Options
+//!
+//! Every `alias_*` macro accepts the following options after its variant names. Options are
+//! separated by commas and can be given in any order, mixed with flags like `implement_try` and
+//! macro specific options like `left_is_ok` or `error = ...`.
+//!
+//! - `traits: [...]` replaces the default set of derived traits. `alias_cow!` implements its traits
+//!   manually, and does not accept it.
+//! - `traits_add: [...]` derives additional traits on top of the default set, or on top of the
+//!   set given with `traits:`.
+//! - `vis: ...` sets the visibility of the generated type and its methods, e.g. `vis: pub(crate)`.
+//!   Use `vis: private` for a type that is private to the enclosing module. Defaults to `pub`.
+//! - `attrs: [...]` adds attributes to the generated type, e.g. `attrs: [#[repr(u8)]]`. They are
+//!   emitted after the derives, so derive helper attributes like `#[serde(...)]` work. They only
+//!   apply to the type itself, so put `#[cfg(...)]` on the macro invocation instead.
+//! - `generics: [...]` renames the generic parameters of the generated type, e.g.
+//!   `generics: [Value, Error]` for `alias_result!`. Only the type definition uses the new names,
+//!   so they show up in rustdoc and IDE hints. Macros that generate non-generic types, and
+//!   `alias_oneof!` which takes its parameters from the invocation, do not accept it.
+//! - `serde: ...` picks the serde tagging of a type that derives `Serialize` or `Deserialize`:
+//!   `serde: untagged`, `serde: [tag = "type"]` for internal tagging, or
+//!   `serde: [tag = "type", content = "value"]` for adjacent tagging. Without it, the derived impls
+//!   use serde's default external tagging, e.g. `{"Present":42}`. Internal tagging only works for
+//!   payloads that serialize as maps, like structs.
+//! - `module: name` generates the type inside a module called `name` and re-exports everything in
+//!   it, including companion items like the `boxed:` and `borrowed:` aliases, so the generated items
+//!   stay grouped. List the re-exports explicitly with `module: name [...]`, or use
+//!   `module: name []` to re-export nothing. The module and the re-exports take the visibility
+//!   given with `vis:`, and the module imports everything from the enclosing module.
+//!
+//! `alias_option!`, `alias_result!` and `alias_either!` also accept `bounds: [...]`, which requires
+//! every generic parameter to implement the given bounds, e.g. `bounds: [Send + 'static]`. The
+//! bounds are carried by the type and all of its impls, so methods that change a parameter, like
+//! `map`, require the new parameter to satisfy them too.
+//!
+//! The same macros accept `methods: [...]` to generate only some of their method families, which
+//! keeps the expansion small in crates with many aliases. The groups are `core` (predicates,
+//! accessors, unwrapping, and conversion to and from the std type), `combinators` (`map`,
+//! `and_then`, `or_else` and friends), `conversions` (other conversions, like `transpose` or
+//! `into_result`) and `iterators` (`iter` and the iterator trait impls). `core` is always
+//! generated. Without `methods:`, all groups are generated, so that adding the option did not take
+//! methods away from existing invocations. For the smallest expansion, ask for the core group only
+//! with `methods: [core]`.
+//!
+//! They also accept `skip: [...]` to leave out single methods, e.g. `skip: [map, unwrap]` when
+//! the generated name would collide with an extension trait. Methods whose names are derived from
+//! a variant, like `is_found`, can not be skipped, and naming them, or any other unknown method, is
+//! a compile error. The `no_panics` flag leaves out every method that can panic, including variant
+//! named ones like `unwrap_failed` or `expect_failed`, for code bases that forbid panicking APIs.
+//!
+//! The methods that `skip:` can name can also be renamed, to follow in-house naming conventions or
+//! to make room for an extension trait. `rename: [unwrap => into_value, map => map_payload]` gives
+//! single methods a new name, and `method_prefix: name_` puts a prefix in front of all of them, e.g.
+//! `name_map`. An explicit rename takes precedence over the prefix. The `generate_tests` flag does
+//! not check the renamed methods.
+//!
+//! The same macros accept `serde_attrs: [...]`, which attaches attributes to single variants, e.g.
+//! `serde_attrs: [Success => #[serde(rename = "ok")], Failure => #[serde(rename = "error")]]`, so
+//! the serialized form can be changed without writing the enum by hand. Each variant can be given
+//! several attributes, and naming something that is not a variant is an error.
+//!
+//! A derived `Serialize` uses the variant names of the generated type, e.g. `{"Present":42}`, so
+//! replacing an `Option` or `Result` field with an alias changes the serialized form. With the
+//! `serde` feature, `serde: std` implements `Serialize` and `Deserialize` by hand instead, with the
+//! exact representation of the std type, so `alias_option!` types serialize as the value or `null`,
+//! and missing fields deserialize to the empty variant. Leave `serde::Serialize` and
+//! `serde::Deserialize` out of the derived traits when using it.
+//!
+//! ```
+//! use enumizer::alias_either;
+//!
+//! alias_either!(Event, Click, Key, serde: [tag = "kind", content = "data"], traits_add: [serde::Serialize]);
+//!
+//! let event: Event<(u8, u8), char> = Event::Key('a');
+//! assert_eq!(serde_json::to_string(&event).unwrap(), r#"{"kind":"Key","data":"a"}"#);
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! use enumizer::{alias_option, alias_result};
+//!
+//! alias_option!(Nickname, Given, Unset, serde: std);
+//! alias_result!(Reply, Delivered, Bounced, serde: std);
+//!
+//! assert_eq!(serde_json::to_string(&Nickname::Given(1)).unwrap(), "1");
+//! assert_eq!(serde_json::from_str::<Nickname<u8>>("null").unwrap(), Nickname::Unset);
+//! let reply: Reply<u8, String> = serde_json::from_str(r#"{"Ok":1}"#).unwrap();
+//! assert_eq!(reply, Reply::Delivered(1));
+//! # }
+//! ```
+//!
+//! To switch single fields of a frozen schema, the `serde_as_std` flag emits a module named after
+//! the type, e.g. `reply_as_std` for `Reply`, for use with `#[serde(with = "reply_as_std")]`. It
+//! serializes like `serde: std`, while the type itself keeps its derived impls. Like
+//! `generate_tests`, the module refers to the type through its parent, so the macro has to be
+//! invoked at module level.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # mod example {
+//! use enumizer::alias_result;
+//!
+//! alias_result!(Reply, Delivered, Bounced, serde_as_std, traits_add: [serde::Serialize]);
+//!
+//! #[derive(serde::Serialize)]
+//! struct Message {
+//!     #[serde(with = "reply_as_std")]
+//!     reply: Reply<u8, String>,
+//! }
+//!
+//! # pub fn main() {
+//! let message = Message { reply: Reply::Delivered(1) };
+//! assert_eq!(serde_json::to_string(&message).unwrap(), r#"{"reply":{"Ok":1}}"#);
+//! assert_eq!(serde_json::to_string(&message.reply).unwrap(), r#"{"Delivered":1}"#);
+//! # }
+//! # }
+//! # fn main() {
+//! #     #[cfg(feature = "serde")]
+//! #     example::main();
+//! # }
+//! ```
+//!
+//! With the `schemars` feature, the `json_schema` flag implements `schemars::JsonSchema` for
+//! `alias_option!`, `alias_result!` and `alias_either!`, so the types can appear in schemas
+//! generated from Rust types without a wrapper. With the `utoipa` feature, the `to_schema` flag
+//! implements `utoipa::ToSchema` the same way, for OpenAPI documents, and with the `ts-rs` feature,
+//! the `ts` flag implements `ts_rs::TS`, for TypeScript bindings. The schema follows serde's
+//! default external tagging, with the custom variant names, or the std counterpart's schema with
+//! `serde: std`. It does not follow the other `serde:` modes or `serde_attrs:`. Generic aliases get
+//! a schema name per payload, e.g. `Nickname_String` for `Nickname<String>` under utoipa.
+//!
+//! ```
+//! # #[cfg(feature = "schemars")] {
+//! use enumizer::alias_option;
+//!
+//! alias_option!(Nickname, Given, Unset, json_schema);
+//!
+//! let schema = schemars::schema_for!(Nickname<String>);
+//! assert_eq!(schema.get("title").unwrap(), "Nickname_for_string");
+//! assert_eq!(schema.get("oneOf").unwrap()[1], serde_json::json!({"type": "string", "const": "Unset"}));
+//! # }
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "utoipa")] {
+//! use enumizer::alias_result;
+//! use utoipa::ToSchema;
+//!
+//! alias_result!(Reply, Delivered, Bounced, to_schema);
+//!
+//! assert_eq!(Reply::<u8, String>::name(), "Reply_u8_String");
+//! # }
+//! ```
+//!
+//! The TypeScript declaration keeps the generic parameter names of the alias:
+//!
+//! ```
+//! # #[cfg(feature = "ts-rs")] {
+//! use enumizer::alias_option;
+//! use ts_rs::TS;
+//!
+//! alias_option!(Nickname, Given, Unset, generics: [Name], ts);
+//!
+//! assert_eq!(Nickname::<String>::decl(), r#"type Nickname<Name> = { "Given": Name } | "Unset";"#);
+//! assert_eq!(Nickname::<String>::inline(), r#"{ "Given": string } | "Unset""#);
+//! # }
+//! ```
+//!
+//! For firmware that logs with defmt, the `defmt` flag implements `defmt::Format` with the `defmt`
+//! feature, printing the variant name and the payload, e.g. `Given(1)`, so the types don't need
+//! to derive `Debug`. Unlike the other integrations, the crate using the flag has to depend on
+//! defmt too.
+//!
+//! ```no_run
+//! # #[cfg(feature = "defmt")] {
+//! use enumizer::alias_option;
+//!
+//! alias_option!(Nickname, Given, Unset, defmt, traits: [Clone, Copy, PartialEq]);
+//!
+//! fn log(nickname: Nickname<u8>) {
+//!     defmt::info!("nickname: {}", nickname);
+//! }
+//! # }
+//! ```
+//!
+//! With the `arbitrary` feature, the `arbitrary` flag implements `arbitrary::Arbitrary`, so fuzz
+//! targets can take the types as structured input. The impl picks a variant from the input, then
+//! builds its payloads with their own impls. Unlike the schema and defmt integrations, it is
+//! supported by the other alias macros too, as are `proptest` and `quickcheck` below, except for
+//! `alias_cow!`, `alias_maybe_done!` and `alias_try_lock_error!`, whose payloads can't be
+//! generated that way.
+//!
+//! ```
+//! # #[cfg(feature = "arbitrary")] {
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use enumizer::alias_poll;
+//!
+//! alias_poll!(Fetch, Fetched, Fetching, arbitrary);
+//!
+//! let fetch = Fetch::<u8>::arbitrary(&mut Unstructured::new(&[0, 7])).unwrap();
+//! assert_eq!(fetch, Fetch::Fetched(7));
+//! # }
+//! ```
+//!
+//! The `proptest` flag does the same for `proptest::arbitrary::Arbitrary` with the `proptest`
+//! feature, so alias types can be parameters of `proptest!` tests. The strategy picks variants
+//! with payloads three times as often as the ones without, and generates the payloads with their
+//! own strategies.
+//!
+//! ```
+//! # #[cfg(feature = "proptest")] {
+//! use enumizer::alias_result;
+//! use proptest::prelude::*;
+//!
+//! alias_result!(Reply, Delivered, Bounced, proptest);
+//!
+//! proptest!(|(reply: Reply<u8, String>)| {
+//!     prop_assert_eq!(reply.is_delivered(), Result::from(reply).is_ok());
+//! });
+//! # }
+//! ```
+//!
+//! For codebases on quickcheck, the `quickcheck` flag implements `quickcheck::Arbitrary` with the
+//! `quickcheck` feature. Failing values shrink toward the variants without payloads, e.g. `Some(2)`
+//! to `None` before `Some(0)`, and otherwise shrink their payloads in place. Two variants that
+//! both hold payloads, like those of `alias_result!`, keep their variant while shrinking.
+//!
+//! ```
+//! # #[cfg(feature = "quickcheck")] {
+//! use enumizer::alias_option;
+//! use quickcheck::Arbitrary;
+//!
+//! alias_option!(Nickname, Given, Unset, quickcheck);
+//!
+//! let shrunk: Vec<_> = Nickname::Given(2u8).shrink().collect();
+//! assert_eq!(shrunk, [Nickname::Unset, Nickname::Given(0), Nickname::Given(1)]);
+//! # }
+//! ```
+//!
+//! Enum payloads have to be sized, so unsized values like trait objects or `str` are held behind a
+//! `Box` or a reference, e.g. `Handler<Box<dyn Fn()>>`. `boxed: Name` and `borrowed: Name` generate
+//! a type alias that fills every generic parameter that way, so the payload can be named directly
+//! as `Name<dyn Fn()>`, or as `Name<'a, str>` for `borrowed:`. Like their std counterparts,
+//! `as_deref` and `as_deref_mut` reach through the `Box`, and the `*_eq` methods compare against
+//! unsized values, e.g. `found_eq("name")` on a `String` payload.
+//!
+//! ```
+//! use enumizer::alias_option;
+//!
+//! alias_option!(Handler, Set, Unset, boxed: BoxedHandler);
+//!
+//! let handler: BoxedHandler<dyn Fn(u8) -> u8> = Handler::Set(Box::new(|v| v * 2));
+//! assert_eq!(handler.as_deref().map(|f| f(2)).unwrap_or(0), 4);
+//! ```
+//!
+//! The generated types do not take lifetime parameters of their own. Payloads borrow through the
+//! generic parameters instead, e.g. `Lookup<&'a str>`, and the methods that take references apart,
+//! like `copied`, `cloned` and `as_deref`, work for any lifetime.
+//!
+//! ```
+//! use enumizer::alias_option;
+//!
+//! alias_option!(Lookup, Hit, Miss);
+//!
+//! fn find<'a>(names: &'a [String], prefix: &str) -> Lookup<&'a str> {
+//!     Lookup::from(names.iter().find(|name| name.starts_with(prefix))).map(String::as_str)
+//! }
+//!
+//! let names = vec!["ferris".to_string()];
+//! assert_eq!(find(&names, "fe"), Lookup::Hit("ferris"));
+//! ```
+//!
+//! ```compile_fail
+//! use enumizer::alias_option;
+//!
+//! alias_option!(Lookup<'a>, Hit, Miss);
+//! ```
+//!
+//! The derived `PartialOrd` and `Ord` follow the std types, ordering `None` before `Some` and `Ok`
+//! before `Err`. The `none_last` flag of `alias_option!` and the `err_first` flag of
+//! `alias_result!` implement them by hand instead, so the empty variant sorts after the values, or
+//! the failures before the successes. The variants are still declared in the order of the std
+//! type, so the flags do not change the variant index used by serde. With either flag, `PartialOrd`
+//! and `Ord` in the derive set, given by their bare names, are taken out and implemented by hand.
+//!
+//! The generated code contains no `unsafe` by default. The pin projections, like the `Future`
+//! delegation and `as_pin_ref` of `alias_either!`, require the payloads to be `Unpin`, unless the
+//! `pin_project` flag of `alias_either!` and `alias_maybe_done!` is given, which projects them with
+//! `unsafe` code instead. The extractors of the `implement_unchecked` flag of `alias_result!` are
+//! `unsafe` too. The `forbid_unsafe` flag of `alias_option!`, `alias_result!` and `alias_either!`
+//! emits the impls under `#[forbid(unsafe_code)]`, so the compiler checks that they contain no
+//! `unsafe` code. Combining it with `pin_project` or `implement_unchecked` is an error.
+//!
+//! ```compile_fail
+//! use enumizer::alias_result;
+//!
+//! alias_result!(Reply, Received, Lost, forbid_unsafe, implement_unchecked);
+//! ```
+//!
+//! ```compile_fail
+//! use enumizer::alias_either;
+//!
+//! alias_either!(Fetch, Cached, Remote, forbid_unsafe, pin_project);
+//! ```
+//!
+//! Like their std counterparts, the methods of these macros that only compute a value, like the
+//! predicates, the `as_*` accessors and the combinators, are `#[must_use]`, and so is the type
+//! generated by `alias_result!`. The `no_must_use` flag leaves the attributes out.
+//!
+//! `alias_option!`, `alias_result!`, `alias_either!`, `alias_poll!` and `alias_control_flow!`
+//! accept the `generate_tests` flag, which emits a `#[cfg(test)]` module named after the type,
+//! e.g. `reply_conformance` for `Reply`. Its tests check the round trip through the std type, the
+//! size equality with it, and every generated method against the std behavior. They instantiate
+//! the type with integers, and leave out the checks that need other parameters, like references
+//! or collections, when `bounds:` is given. The module imports the type from its parent, so the
+//! macro has to be invoked at module level rather than inside a function.
+//!
+//! The same macros accept the `assert_same_size` flag, which emits const assertions that the
+//! generated type has the size of its std counterpart for a few probe types, like `u8`, `&u8` and
+//! `&[u8]`. A layout change, e.g. from a `#[repr]` attribute that disables the niche
+//! optimization, then fails the build of the crate that invokes the macro. The probes other than
+//! `u8` are left out when `bounds:` is given.
+//!
+//! The methods of `alias_option!`, `alias_result!` and `alias_either!` are documented with the
+//! variant names of the generated type, and methods named after a variant carry a `doc(alias)` for
+//! the std name, so searching the docs for `is_ok` finds `is_success`. These methods convert the
+//! variant name to snake case, so a `FoundItem` variant gets `is_found_item` and `as_found_item`.
+//! Variant names can be raw identifiers, like `r#Type`. Methods named after a variant alone, like
+//! the `ok` and `err` of `alias_result!`, get a trailing underscore when the name is a keyword, so a
+//! `Loop` variant gets `loop_`.
+//!
+//! `alias_option!`, `alias_result!` and `alias_either!` check their invocation, and report a
+//! `compile_error!` that names the problem for variant names that are the same or only differ in
+//! case, for options they do not accept, and for a missing type or variant name. Variant names
+//! where one is the other with a method suffix appended, like `Found` and `FoundMut`, are reported
+//! as well, since `as_found_mut` would be generated for both.
+//!
+//! As options accumulate, the argument list can be hard to read. `define!` takes the same options
+//! in a block that reads like the enum, with one option per statement, and routes to the matching
+//! `alias_*` macro. The visibility and attributes of the declaration become `vis:` and `attrs:`,
+//! `derive(...)` stands for `traits: [...]`, and `try` for `implement_try`.
+mod bound;
+mod control_flow;
+mod cow;
+mod define;
 mod either;
+mod either_n;
+mod entry;
+mod enumize;
+mod error;
 pub mod examples;
+mod fieldless;
+mod ipaddr;
+mod maybe_done;
+mod nested_result;
+mod oneof;
 mod option;
+mod options;
+mod ordering;
+mod poll;
+mod remote_data;
 mod result;
+mod sync_error;
+mod these;
+mod tristate;
+mod validation;
+
+pub use entry::{OccupiedEntry, VacantEntry};
+
+/// Re-exports of the optional dependencies, for the code generated by the alias macros.
+///
+/// The generated impls name these crates as `$crate::__private::serde` and the like, so a crate
+/// enabling an integration feature does not need its own dependency on the integrated crate, and
+/// gets the version this crate was built against. The `defmt` flag is the exception: defmt's
+/// macros refer to `defmt` by name, so crates using the flag depend on defmt directly.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "arbitrary")]
+    pub use ::arbitrary;
+    #[cfg(feature = "defmt")]
+    pub use ::defmt;
+    #[cfg(feature = "either")]
+    pub use ::either;
+    #[cfg(feature = "itertools")]
+    pub use ::itertools;
+    #[cfg(feature = "proptest")]
+    pub use ::proptest;
+    #[cfg(feature = "quickcheck")]
+    pub use ::quickcheck;
+    #[cfg(feature = "schemars")]
+    pub use ::schemars;
+    #[cfg(feature = "serde")]
+    pub use ::serde;
+    #[cfg(feature = "ts-rs")]
+    pub use ::ts_rs;
+    #[cfg(feature = "utoipa")]
+    pub use ::utoipa;
+}
@@ -0,0 +1,384 @@
+/// Creates a MaybeDone-like enum with custom variant names, wrapping a future and its output.
+///
+/// See [`examples::MaybeDoneExample`](crate::examples::MaybeDoneExample) for a generated example.
+///
+/// Like [`futures::future::MaybeDone`](https://docs.rs/futures/latest/futures/future/enum.MaybeDone.html),
+/// the generated type holds a pending future, its output once it completed, or nothing once the
+/// output was taken. Polling it drives the inner future and stores its output, which makes it a
+/// building block for join-style combinators.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_maybe_done;
+/// use std::future::{ready, Future};
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+///
+/// alias_maybe_done!(Job, Running, Finished, Collected);
+///
+/// let mut job = pin!(Job::Running(ready(42)));
+/// assert!(job.is_running());
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(job.as_mut().poll(&mut cx), Poll::Ready(()));
+/// assert!(job.is_finished());
+///
+/// assert_eq!(job.as_mut().output_mut(), Some(&mut 42));
+/// assert_eq!(job.as_mut().take_output(), Some(42));
+/// assert!(job.is_collected());
+/// assert_eq!(job.as_mut().take_output(), None);
+/// ```
+///
+/// # Joining Futures
+///
+/// ```
+/// use enumizer::alias_maybe_done;
+/// use std::future::{poll_fn, ready, Future};
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// alias_maybe_done!(Job, Running, Finished, Collected);
+///
+/// let mut first = pin!(Job::from(ready(1)));
+/// let mut second = pin!(Job::from(Box::pin(async { "two" })));
+///
+/// let joined = poll_fn(|cx| {
+///     let first_ready = first.as_mut().poll(cx).is_ready();
+///     let second_ready = second.as_mut().poll(cx).is_ready();
+///     if first_ready && second_ready {
+///         Poll::Ready((first.as_mut().take_output().unwrap(), second.as_mut().take_output().unwrap()))
+///     } else {
+///         Poll::Pending
+///     }
+/// });
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(pin!(joined).poll(&mut cx), Poll::Ready((1, "two")));
+/// ```
+///
+/// # Pinning
+///
+/// By default the future has to be `Unpin`, like the `ready` and boxed futures above, so the
+/// generated code needs no `unsafe`. Add `pin_project` to drop that requirement, e.g. for `async`
+/// blocks. The projections then use `unsafe` code, which is sound because the future is pinned
+/// whenever the generated type is: the type is only `Unpin` if the future is, and it can not
+/// implement `Drop`. Implementing either of them by hand is an error.
+///
+/// ```
+/// use enumizer::alias_maybe_done;
+/// use std::future::Future;
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+/// alias_maybe_done!(Job, Running, Finished, Collected, pin_project);
+///
+/// let mut job = pin!(Job::from(async { 1 }));
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(job.as_mut().poll(&mut cx), Poll::Ready(()));
+/// assert_eq!(job.as_mut().take_output(), Some(1));
+/// ```
+///
+/// ```compile_fail
+/// use enumizer::alias_maybe_done;
+/// alias_maybe_done!(Job, Running, Finished, Collected, pin_project);
+///
+/// impl<Fut: std::future::Future> Unpin for Job<Fut> {}
+/// ```
+///
+/// # Custom Traits
+///
+/// Only `Debug` is derived by default, since futures rarely implement other traits.
+/// You can specify custom traits to derive instead, using the `traits:` keyword followed by a list
+/// of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_maybe_done;
+/// use std::future::Ready;
+/// alias_maybe_done!(CustomJob, Waiting, Done, Gone, traits: [Debug, Clone]);
+/// let val: CustomJob<Ready<i32>> = CustomJob::Done(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Done(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_maybe_done {
+    ($type_name:ident, $future_variant:ident, $done_variant:ident, $gone_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_maybe_done [$type_name, $future_variant, $done_variant, $gone_variant] [Fut] [Debug] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $future_variant:ident, $done_variant:ident, $gone_variant:ident], [$Fut:ident], [], [], [], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::alias_maybe_done!(@body $vis [$type_name, $future_variant, $done_variant, $gone_variant], [$Fut], [$($trait),*], [$(#[$attr])*], []);
+    };
+    (@impl $vis:vis [$type_name:ident, $future_variant:ident, $done_variant:ident, $gone_variant:ident], [$Fut:ident], [], [], [], [$($trait:path),*], [$(#[$attr:meta])*], [pin_project]) => {
+        $crate::alias_maybe_done!(@body $vis [$type_name, $future_variant, $done_variant, $gone_variant], [$Fut], [$($trait),*], [$(#[$attr])*], [pin_project]);
+    };
+    (@body $vis:vis [$type_name:ident, $future_variant:ident, $done_variant:ident, $gone_variant:ident], [$Fut:ident], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$Fut: ::core::future::Future> {
+            $future_variant($Fut),
+            $done_variant($Fut::Output),
+            $gone_variant,
+        }
+
+        #[allow(dead_code)]
+        impl<Fut: ::core::future::Future> $type_name<Fut> {
+            /// Returns true if the future has not completed yet
+            $vis fn [<is_ $future_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$future_variant(_))
+            }
+
+            /// Returns true if the future completed and its output is available
+            $vis fn [<is_ $done_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$done_variant(_))
+            }
+
+            /// Returns true if the output was already taken
+            $vis fn [<is_ $gone_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$gone_variant)
+            }
+        }
+
+        impl<Fut: ::core::future::Future> From<Fut> for $type_name<Fut> {
+            fn from(future: Fut) -> Self {
+                $type_name::$future_variant(future)
+            }
+        }
+
+        $crate::__alias_if_flag!(pin_project, [$($flag),*], {
+            #[allow(dead_code)]
+            impl<Fut: ::core::future::Future> $type_name<Fut> {
+                /// Projects a pinned reference to the inner future, if it has not completed yet
+                $vis fn as_pin_mut(self: ::core::pin::Pin<&mut Self>) -> Option<::core::pin::Pin<&mut Fut>> {
+                    // SAFETY: the future is structurally pinned. It is never moved out of the pinned
+                    // value, and is only dropped in place when the variant is replaced.
+                    unsafe {
+                        match self.get_unchecked_mut() {
+                            $type_name::$future_variant(inner) => Some(::core::pin::Pin::new_unchecked(inner)),
+                            _ => None,
+                        }
+                    }
+                }
+
+                /// Returns a mutable reference to the output, if the future completed and it was not taken yet
+                $vis fn output_mut(self: ::core::pin::Pin<&mut Self>) -> Option<&mut Fut::Output> {
+                    // SAFETY: the output is not structurally pinned, and the future is not touched.
+                    unsafe {
+                        match self.get_unchecked_mut() {
+                            $type_name::$done_variant(output) => Some(output),
+                            _ => None,
+                        }
+                    }
+                }
+
+                /// Takes the output out of the value, if the future completed and it was not taken yet
+                $vis fn take_output(self: ::core::pin::Pin<&mut Self>) -> Option<Fut::Output> {
+                    if !self.[<is_ $done_variant:lower>]() {
+                        return None;
+                    }
+                    // SAFETY: the value holds the output rather than the pinned future, so it can be replaced.
+                    let this = unsafe { self.get_unchecked_mut() };
+                    match ::core::mem::replace(this, $type_name::$gone_variant) {
+                        $type_name::$done_variant(output) => Some(output),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+
+            impl<Fut: ::core::future::Future> ::core::future::Future for $type_name<Fut> {
+                type Output = ();
+
+                fn poll(mut self: ::core::pin::Pin<&mut Self>, cx: &mut ::core::task::Context<'_>) -> ::core::task::Poll<()> {
+                    if self.[<is_ $done_variant:lower>]() {
+                        return ::core::task::Poll::Ready(());
+                    }
+                    let output = match self.as_mut().as_pin_mut() {
+                        Some(future) => ::core::task::ready!(future.poll(cx)),
+                        None => panic!(concat!(stringify!($type_name), " polled after its output was taken")),
+                    };
+                    // SAFETY: the future completed, so dropping it in place and replacing it with its output is sound.
+                    unsafe { *self.get_unchecked_mut() = $type_name::$done_variant(output) };
+                    ::core::task::Poll::Ready(())
+                }
+            }
+
+            // The pin projection above is only sound if the value is `Unpin` exactly when the future
+            // is, and if the future is never moved out of in a `Drop` impl. Implementing `Unpin` here
+            // makes a user `impl Unpin` conflict with it, and the blanket impl over `Drop` types makes
+            // a user `impl Drop` conflict with the impl for the generated type.
+            impl<Fut: ::core::future::Future> ::core::marker::Unpin for $type_name<Fut> where Fut: ::core::marker::Unpin {}
+
+            const _: () = {
+                #[allow(dead_code)]
+                trait MustNotImplDrop {}
+                #[allow(drop_bounds)]
+                impl<T: Drop> MustNotImplDrop for T {}
+                impl<Fut: ::core::future::Future> MustNotImplDrop for $type_name<Fut> {}
+            };
+        }, {
+            #[allow(dead_code)]
+            impl<Fut: ::core::future::Future + ::core::marker::Unpin> $type_name<Fut> {
+                /// Returns a pinned reference to the inner future, if it has not completed yet
+                $vis fn as_pin_mut(self: ::core::pin::Pin<&mut Self>) -> Option<::core::pin::Pin<&mut Fut>> {
+                    match self.get_mut() {
+                        $type_name::$future_variant(inner) => Some(::core::pin::Pin::new(inner)),
+                        _ => None,
+                    }
+                }
+
+                /// Returns a mutable reference to the output, if the future completed and it was not taken yet
+                $vis fn output_mut(self: ::core::pin::Pin<&mut Self>) -> Option<&mut Fut::Output> {
+                    match self.get_mut() {
+                        $type_name::$done_variant(output) => Some(output),
+                        _ => None,
+                    }
+                }
+
+                /// Takes the output out of the value, if the future completed and it was not taken yet
+                $vis fn take_output(self: ::core::pin::Pin<&mut Self>) -> Option<Fut::Output> {
+                    let this = self.get_mut();
+                    if !this.[<is_ $done_variant:lower>]() {
+                        return None;
+                    }
+                    match ::core::mem::replace(this, $type_name::$gone_variant) {
+                        $type_name::$done_variant(output) => Some(output),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+
+            impl<Fut: ::core::future::Future + ::core::marker::Unpin> ::core::future::Future for $type_name<Fut> {
+                type Output = ();
+
+                fn poll(self: ::core::pin::Pin<&mut Self>, cx: &mut ::core::task::Context<'_>) -> ::core::task::Poll<()> {
+                    let this = self.get_mut();
+                    let output = match this {
+                        $type_name::$future_variant(future) => ::core::task::ready!(::core::pin::Pin::new(future).poll(cx)),
+                        $type_name::$done_variant(_) => return ::core::task::Poll::Ready(()),
+                        $type_name::$gone_variant => panic!(concat!(stringify!($type_name), " polled after its output was taken")),
+                    };
+                    *this = $type_name::$done_variant(output);
+                    ::core::task::Poll::Ready(())
+                }
+            }
+
+            // The output is never pinned, so the value only needs the future to be `Unpin`.
+            impl<Fut: ::core::future::Future> ::core::marker::Unpin for $type_name<Fut> where Fut: ::core::marker::Unpin {}
+        });
+        }
+    };
+    (@impl $vis:vis [$($arg:tt)*], $generics:tt, [$($bound:tt)+], $($rest:tt)*) => {
+        compile_error!("`alias_maybe_done!` does not accept the `bounds:` option");
+    };
+    (@impl $vis:vis [$($arg:tt)*], $generics:tt, $bounds:tt, [$($method:ident),+], $($rest:tt)*) => {
+        compile_error!("`alias_maybe_done!` does not accept the `methods:` option");
+    };
+    (@impl $vis:vis [$($arg:tt)*], $generics:tt, $bounds:tt, $methods:tt, [$first:tt $(, $skip:tt)*], $($rest:tt)*) => {
+        compile_error!(concat!("`alias_maybe_done!` does not accept `", stringify!($first), "`"));
+    };
+    (@impl $vis:vis [$($arg:tt)*], $generics:tt, $bounds:tt, $methods:tt, $skip:tt, $traits:tt, $attrs:tt, [$flag:ident $(, $rest:ident)*]) => {
+        compile_error!(concat!("`alias_maybe_done!` does not accept the `", stringify!($flag), "` option"));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::{Pin, pin};
+    use std::task::{Context, Poll, Waker};
+
+    alias_maybe_done!(Job, Running, Finished, Collected);
+    alias_maybe_done!(PinnedJob, Running, Finished, Collected, pin_project);
+
+    /// A future that is pending on its first poll, and ready with `value` on the second one.
+    struct YieldOnce {
+        yielded: bool,
+        value: u8,
+    }
+
+    impl Future for YieldOnce {
+        type Output = u8;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
+            if self.yielded {
+                Poll::Ready(self.value)
+            } else {
+                self.yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn poll_to_completion() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut job = pin!(Job::from(YieldOnce {
+            yielded: false,
+            value: 7
+        }));
+
+        assert_eq!(job.as_mut().poll(&mut cx), Poll::Pending);
+        assert!(job.is_running());
+        assert!(job.as_mut().as_pin_mut().is_some());
+        assert_eq!(job.as_mut().output_mut(), None);
+
+        assert_eq!(job.as_mut().poll(&mut cx), Poll::Ready(()));
+        assert!(job.is_finished());
+        assert!(job.as_mut().as_pin_mut().is_none());
+        *job.as_mut().output_mut().unwrap() += 1;
+        assert_eq!(job.as_mut().poll(&mut cx), Poll::Ready(()));
+        assert_eq!(job.as_mut().take_output(), Some(8));
+    }
+
+    #[test]
+    fn pin_project() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut job = pin!(PinnedJob::from(async {
+            YieldOnce {
+                yielded: false,
+                value: 7,
+            }
+            .await
+                + 1
+        }));
+
+        assert_eq!(job.as_mut().poll(&mut cx), Poll::Pending);
+        assert!(job.is_running());
+        assert!(job.as_mut().as_pin_mut().is_some());
+        assert_eq!(job.as_mut().output_mut(), None);
+
+        assert_eq!(job.as_mut().poll(&mut cx), Poll::Ready(()));
+        assert!(job.is_finished());
+        assert!(job.as_mut().as_pin_mut().is_none());
+        *job.as_mut().output_mut().unwrap() += 1;
+        assert_eq!(job.as_mut().poll(&mut cx), Poll::Ready(()));
+        assert_eq!(job.as_mut().take_output(), Some(9));
+    }
+
+    #[test]
+    fn take_output_twice() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut job = pin!(Job::from(YieldOnce {
+            yielded: true,
+            value: 3
+        }));
+
+        assert_eq!(job.as_mut().take_output(), None);
+        assert_eq!(job.as_mut().poll(&mut cx), Poll::Ready(()));
+        assert_eq!(job.as_mut().take_output(), Some(3));
+        assert!(job.is_collected());
+        assert_eq!(job.as_mut().take_output(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Job polled after its output was taken")]
+    fn poll_after_take() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut job = pin!(Job::from(YieldOnce {
+            yielded: true,
+            value: 3
+        }));
+
+        let _ = job.as_mut().poll(&mut cx);
+        job.as_mut().take_output();
+        let _ = job.as_mut().poll(&mut cx);
+    }
+}
@@ -0,0 +1,234 @@
+/// Creates an enum equivalent to `Result<Result<T, I>, O>`, with custom variant names.
+///
+/// See [`examples::NestedResultExample`](crate::examples::NestedResultExample) for a generated example.
+///
+/// This shape is common in layered APIs, such as RPC clients, where the outer error describes a
+/// transport failure and the inner error describes a failure reported by the remote side. The
+/// generated type flattens the three outcomes into one enum with a success variant holding a `T`,
+/// an inner error variant holding an `I` and an outer error variant holding an `O`.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_nested_result;
+///
+/// alias_nested_result!(Call, Replied, Refused, Unreachable);
+///
+/// let replied: Call<i32, String, std::io::ErrorKind> = Call::Replied(42);
+/// let refused: Call<i32, String, std::io::ErrorKind> = Call::Refused("no such user".to_string());
+///
+/// assert!(replied.is_replied());
+/// assert!(refused.is_refused());
+/// assert!(!refused.is_unreachable());
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_nested_result;
+/// alias_nested_result!(Call, Replied, Refused, Unreachable);
+///
+/// let replied: Call<i32, &str, u16> = Call::Replied(2);
+/// assert_eq!(replied.map(|x| x * 10), Call::Replied(20));
+///
+/// let refused: Call<i32, &str, u16> = Call::Refused("denied");
+/// assert_eq!(refused.map_inner(str::len), Call::Refused(6));
+///
+/// let unreachable: Call<i32, &str, u16> = Call::Unreachable(503);
+/// assert_eq!(unreachable.map_outer(|code| code / 100), Call::Unreachable(5));
+///
+/// // Merges the inner error into the outer error type
+/// let refused: Call<i32, u8, u16> = Call::Refused(4);
+/// assert_eq!(refused.flatten_outer(), Err(4u16));
+/// let replied: Call<i32, u8, u16> = Call::Replied(2);
+/// assert_eq!(replied.flatten_outer(), Ok(2));
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `Result<Result<T, I>, O>`.
+///
+/// ```
+/// use enumizer::alias_nested_result;
+/// alias_nested_result!(Call, Replied, Refused, Unreachable);
+///
+/// let nested: Result<Result<i32, &str>, u16> = Ok(Err("denied"));
+/// let call: Call<i32, &str, u16> = nested.into();
+/// assert_eq!(call, Call::Refused("denied"));
+///
+/// let back: Result<Result<i32, &str>, u16> = Call::Unreachable(503).into();
+/// assert_eq!(back, Err(503));
+///
+/// let inner: Call<i32, &str, u16> = Call::from_inner(Ok(1));
+/// assert_eq!(inner, Call::Replied(1));
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_nested_result;
+/// alias_nested_result!(CustomCall, Done, Failed, Lost, traits: [Debug, Clone]);
+/// let val: CustomCall<i32, (), ()> = CustomCall::Done(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Done(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_nested_result {
+    ($type_name:ident, $ok_variant:ident, $inner_variant:ident, $outer_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_nested_result [$type_name, $ok_variant, $inner_variant, $outer_variant] [T, I, O] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $ok_variant:ident, $inner_variant:ident, $outer_variant:ident], [$T:ident, $I:ident, $O:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$T, $I, $O] [$type_name [$ok_variant($T), $inner_variant($I), $outer_variant($O)]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T, $I, $O> {
+            $ok_variant($T),
+            $inner_variant($I),
+            $outer_variant($O),
+        }
+
+        #[allow(dead_code)]
+        impl<T, I, O> $type_name<T, I, O> {
+            /// Returns true if this is the `Ok(Ok(_))` equivalent variant
+            $vis fn [<is_ $ok_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$ok_variant(_))
+            }
+
+            /// Returns true if this is the `Ok(Err(_))` equivalent variant
+            $vis fn [<is_ $inner_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$inner_variant(_))
+            }
+
+            /// Returns true if this is the `Err(_)` equivalent variant
+            $vis fn [<is_ $outer_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$outer_variant(_))
+            }
+
+            /// Creates a value from the inner result, for a call that did not fail in the outer layer
+            $vis fn from_inner(inner: Result<T, I>) -> Self {
+                match inner {
+                    Ok(v) => $type_name::$ok_variant(v),
+                    Err(e) => $type_name::$inner_variant(e),
+                }
+            }
+
+            /// Applies `f` to the success value, leaving the errors untouched
+            $vis fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U, I, O> {
+                match self {
+                    $type_name::$ok_variant(v) => $type_name::$ok_variant(f(v)),
+                    $type_name::$inner_variant(e) => $type_name::$inner_variant(e),
+                    $type_name::$outer_variant(e) => $type_name::$outer_variant(e),
+                }
+            }
+
+            /// Applies `f` to the inner error, leaving the other variants untouched
+            $vis fn map_inner<J, F: FnOnce(I) -> J>(self, f: F) -> $type_name<T, J, O> {
+                match self {
+                    $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
+                    $type_name::$inner_variant(e) => $type_name::$inner_variant(f(e)),
+                    $type_name::$outer_variant(e) => $type_name::$outer_variant(e),
+                }
+            }
+
+            /// Applies `f` to the outer error, leaving the other variants untouched
+            $vis fn map_outer<P, F: FnOnce(O) -> P>(self, f: F) -> $type_name<T, I, P> {
+                match self {
+                    $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
+                    $type_name::$inner_variant(e) => $type_name::$inner_variant(e),
+                    $type_name::$outer_variant(e) => $type_name::$outer_variant(f(e)),
+                }
+            }
+
+            /// Flattens into a single `Result`, converting the inner error into the outer error type
+            $vis fn flatten_outer(self) -> Result<T, O>
+            where
+                I: Into<O>,
+            {
+                match self {
+                    $type_name::$ok_variant(v) => Ok(v),
+                    $type_name::$inner_variant(e) => Err(e.into()),
+                    $type_name::$outer_variant(e) => Err(e),
+                }
+            }
+        }
+
+        impl<T, I, O> From<Result<Result<T, I>, O>> for $type_name<T, I, O> {
+            fn from(result: Result<Result<T, I>, O>) -> Self {
+                match result {
+                    Ok(inner) => Self::from_inner(inner),
+                    Err(e) => $type_name::$outer_variant(e),
+                }
+            }
+        }
+
+        impl<T, I, O> From<$type_name<T, I, O>> for Result<Result<T, I>, O> {
+            fn from(val: $type_name<T, I, O>) -> Self {
+                match val {
+                    $type_name::$ok_variant(v) => Ok(Ok(v)),
+                    $type_name::$inner_variant(e) => Ok(Err(e)),
+                    $type_name::$outer_variant(e) => Err(e),
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    alias_nested_result!(Call, Replied, Refused, Unreachable);
+
+    #[test]
+    fn conversions() {
+        let cases: [Result<Result<u8, &str>, String>; 3] = [
+            Ok(Ok(1)),
+            Ok(Err("refused")),
+            Err("unreachable".to_string()),
+        ];
+        for case in cases {
+            assert_eq!(Result::from(Call::from(case.clone())), case);
+        }
+        assert!(Call::<u8, &str, String>::from_inner(Ok(1)).is_replied());
+        assert!(Call::<u8, &str, String>::from_inner(Err("refused")).is_refused());
+        assert!(Call::<u8, &str, String>::from(Err("down".to_string())).is_unreachable());
+    }
+
+    #[test]
+    fn map() {
+        let replied: Call<u8, u8, u8> = Call::Replied(1);
+        assert_eq!(
+            replied
+                .map(|v| v + 1)
+                .map_inner(|e| e * 10)
+                .map_outer(|e| e * 100),
+            Call::Replied(2)
+        );
+        let refused: Call<u8, u8, u8> = Call::Refused(1);
+        assert_eq!(
+            refused
+                .map(|v| v + 1)
+                .map_inner(|e| e * 10)
+                .map_outer(|e| e * 100),
+            Call::Refused(10)
+        );
+        let unreachable: Call<u8, u8, u8> = Call::Unreachable(1);
+        assert_eq!(
+            unreachable
+                .map(|v| v + 1)
+                .map_inner(|e| e * 10)
+                .map_outer(|e| e * 100),
+            Call::Unreachable(100)
+        );
+    }
+
+    #[test]
+    fn flatten_outer() {
+        let refused: Call<u8, &str, String> = Call::Refused("refused");
+        assert_eq!(refused.flatten_outer(), Err("refused".to_string()));
+        let replied: Call<u8, &str, String> = Call::Replied(1);
+        assert_eq!(replied.flatten_outer(), Ok(1));
+    }
+}
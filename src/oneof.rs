@@ -0,0 +1,227 @@
+/// Creates a sum enum with any number of variants, each holding a single payload.
+///
+/// See [`examples::OneOfExample`](crate::examples::OneOfExample) for a generated example.
+///
+/// Generic parameters are declared after the type name, and each variant names its payload type,
+/// which can be one of the generic parameters or any concrete type.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_oneof;
+///
+/// alias_oneof!(Payload<T>, Text(String), Number(i64), Custom(T));
+///
+/// let text: Payload<()> = Payload::Text("hello".to_string());
+/// let number: Payload<()> = Payload::Number(42);
+///
+/// assert!(text.is_text());
+/// assert!(!text.is_number());
+/// assert!(number.is_number());
+/// assert!(!number.is_custom());
+/// ```
+///
+/// # Generated Methods
+///
+/// Every variant gets its own `is_*`, `as_*`, `as_*_mut`, `map_*` and `unwrap_*` methods,
+/// and `fold` consumes the value with one closure per variant.
+///
+/// ```
+/// use enumizer::alias_oneof;
+/// alias_oneof!(Shape<A, B, C>, Circle(A), Square(B), Line(C));
+///
+/// let mut circle: Shape<f64, u32, ()> = Shape::Circle(1.5);
+/// assert_eq!(circle.as_circle(), Some(&1.5));
+/// assert_eq!(circle.as_square(), None);
+///
+/// if let Some(radius) = circle.as_circle_mut() {
+///     *radius *= 2.0;
+/// }
+/// assert_eq!(circle.map_circle(|r| r + 1.0), Shape::Circle(4.0));
+///
+/// let square: Shape<f64, u32, ()> = Shape::Square(3);
+/// assert_eq!(square.clone().map_circle(|r| r + 1.0), Shape::Square(3));
+/// assert_eq!(square.unwrap_square(), 3);
+///
+/// let description = Shape::<f64, u32, ()>::Line(()).fold(
+///     |r| format!("circle of radius {r}"),
+///     |side| format!("square of side {side}"),
+///     |()| "line".to_string(),
+/// );
+/// assert_eq!(description, "line");
+/// ```
+///
+/// # Custom Traits
+///
+/// Since payloads can be concrete types, only `Debug`, `Clone` and `PartialEq` are derived by default.
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets, after the variants.
+///
+/// ```
+/// use enumizer::alias_oneof;
+/// alias_oneof!(Id<T>, Numeric(u64), Named(T), traits: [Debug, Clone, Copy, PartialEq, Eq, Hash]);
+/// let val: Id<&str> = Id::Named("root");
+/// let copy = val;
+/// assert_eq!(format!("{:?}", copy), "Named(\"root\")");
+/// assert_eq!(val, copy);
+/// ```
+#[macro_export]
+macro_rules! alias_oneof {
+    (@impl $vis:vis [$type_name:ident [$($generic:ident),*], [$($variant:ident($payload:ty)),+]], [], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$($generic),*] [$type_name [$($variant($payload)),+]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$($generic),*> {
+            $($variant($payload),)+
+        }
+
+        #[allow(dead_code)]
+        impl<$($generic),*> $type_name<$($generic),*> {
+            $(
+            /// Applies `f` to the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// payload, leaving other variants untouched.
+            $vis fn [<map_ $variant:snake>]<F: FnOnce($payload) -> $payload>(self, f: F) -> Self {
+                match self {
+                    $type_name::$variant(val) => $type_name::$variant(f(val)),
+                    #[allow(unreachable_patterns)]
+                    other => other,
+                }
+            }
+            )+
+
+            /// Consumes the value, applying the closure matching its variant.
+            #[allow(clippy::too_many_arguments)]
+            $vis fn fold<R>(self, $([<on_ $variant:snake>]: impl FnOnce($payload) -> R),+) -> R {
+                match self {
+                    $($type_name::$variant(val) => [<on_ $variant:snake>](val),)+
+                }
+            }
+        }
+        }
+
+        $crate::__alias_oneof_accessors!($vis $type_name [$($generic),*] [$($variant($payload)),+]);
+    };
+    ($type_name:ident $(<$($generic:ident),* $(,)?>)?, $($rest:tt)+) => {
+        $crate::__alias_oneof_variants!($type_name [$($($generic),*)?], [], $($rest)+);
+    };
+}
+
+/// Implements the `is_*`, `as_*`, `as_*_mut` and `unwrap_*` methods of every variant of a sum enum
+/// whose variants each hold a single payload.
+///
+/// Shared by `alias_oneof!` and the fixed-arity `alias_either3!` and `alias_either4!`, which pass
+/// their generic parameters as the payload types.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_oneof_accessors {
+    ($vis:vis $type_name:ident [$($generic:ident),*] [$($variant:ident($payload:ty)),+]) => {
+        paste::paste! {
+        #[allow(dead_code)]
+        impl<$($generic),*> $type_name<$($generic),*> {
+            $(
+            /// Returns `true` if the value is the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// variant.
+            $vis fn [<is_ $variant:snake>](&self) -> bool {
+                matches!(self, $type_name::$variant(_))
+            }
+
+            /// Returns a reference to the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// payload, or `None` if the value is a different variant.
+            $vis fn [<as_ $variant:snake>](&self) -> Option<&$payload> {
+                match self {
+                    $type_name::$variant(val) => Some(val),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Returns a mutable reference to the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// payload, or `None` if the value is a different variant.
+            $vis fn [<as_ $variant:snake _mut>](&mut self) -> Option<&mut $payload> {
+                match self {
+                    $type_name::$variant(val) => Some(val),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Returns the
+            #[doc = concat!("`", stringify!($variant), "`")]
+            /// payload.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value is a different variant.
+            $vis fn [<unwrap_ $variant:snake>](self) -> $payload {
+                match self {
+                    $type_name::$variant(val) => val,
+                    #[allow(unreachable_patterns)]
+                    _ => panic!(
+                        concat!("called `unwrap_", stringify!([<$variant:snake>]), "()` on a different `", stringify!($type_name), "` variant")
+                    ),
+                }
+            }
+            )+
+        }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_oneof_variants {
+    ($type_name:ident [$($generic:ident),*], [$($variants:tt)*], $variant:ident($payload:ty) $(, $($rest:tt)*)?) => {
+        $crate::__alias_oneof_variants!($type_name [$($generic),*], [$($variants)* $variant($payload),], $($($rest)*)?);
+    };
+    ($type_name:ident [$($generic:ident),*], [$($variant:ident($payload:ty),)+], $($option:tt)*) => {
+        $crate::__alias_options!(alias_oneof [$type_name [$($generic),*], [$($variant($payload)),+]] [] [Debug, Clone, PartialEq], $($option)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    alias_oneof!(Token<T>, Word(String), Number(i64), Other(T));
+
+    #[test]
+    fn map() {
+        let number: Token<()> = Token::Number(2);
+        assert_eq!(number.clone().map_number(|n| n * 10), Token::Number(20));
+        assert_eq!(number.map_word(|w| w + "!"), Token::Number(2));
+        assert_eq!(
+            Token::Other('a').map_other(|c| c.to_ascii_uppercase()),
+            Token::Other('A')
+        );
+    }
+
+    #[test]
+    fn unwrap() {
+        assert_eq!(Token::<()>::Word("hi".to_string()).unwrap_word(), "hi");
+        assert_eq!(Token::<()>::Number(3).unwrap_number(), 3);
+        assert_eq!(Token::Other(4u8).unwrap_other(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_word()` on a different `Token` variant")]
+    fn unwrap_other_variant() {
+        Token::<()>::Number(3).unwrap_word();
+    }
+
+    #[test]
+    fn fold() {
+        let describe = |token: Token<char>| {
+            token.fold(
+                |w| format!("word {w}"),
+                |n| format!("number {n}"),
+                |c| format!("other {c}"),
+            )
+        };
+        assert_eq!(describe(Token::Word("hi".to_string())), "word hi");
+        assert_eq!(describe(Token::Number(3)), "number 3");
+        assert_eq!(describe(Token::Other('?')), "other ?");
+    }
+}
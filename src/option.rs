@@ -39,6 +39,13 @@
 /// // Unwrap variants
 /// assert_eq!(Value::Found(5).unwrap_or(0), 5);
 /// assert_eq!(Value::<i32>::Searching.unwrap_or(5), 5);
+///
+/// // Transpose a wrapped `Result`
+/// let parsed: Value<Result<i32, String>> = Value::Found(Ok(5));
+/// assert_eq!(parsed.transpose(), Ok(Value::Found(5)));
+/// let failed: Value<Result<i32, String>> = Value::Found(Err("bad".to_string()));
+/// assert_eq!(failed.transpose(), Err("bad".to_string()));
+/// assert_eq!(Value::<Result<i32, String>>::Searching.transpose(), Ok(Value::Searching));
 /// ```
 ///
 /// # Conversions
@@ -56,6 +63,10 @@
 ///
 /// let to_option: Option<i32> = Value::Found(42).into();
 /// assert_eq!(to_option, Some(42));
+///
+/// // Like `Option<T>`, a bare value converts into the some variant.
+/// let from_value: Value<i32> = 42.into();
+/// assert_eq!(from_value, Value::Found(42));
 /// ```
 ///
 /// # Conditional Checks
@@ -71,6 +82,59 @@
 /// assert!(Value::<i32>::Searching.is_searching_or(|&x| x > 40));
 /// assert!(val.is_searching_or(|&x| x > 40));
 /// assert!(!val.is_searching_or(|&x| x < 40));
+///
+/// assert!(val.found_eq(&42));
+/// assert!(!val.found_eq(&41));
+/// assert!(!Value::<i32>::Searching.found_eq(&42));
+/// ```
+///
+/// # Bounds
+///
+/// Use `bounds:` to require the payload type to implement some traits, e.g. to keep a queue of
+/// jobs sendable between threads. The methods that map the payload require the new payload type
+/// to satisfy the bounds too.
+///
+/// ```
+/// use enumizer::alias_option;
+/// alias_option!(Job, Queued, Idle, bounds: [Send + 'static]);
+/// let job: Job<String> = Job::Queued("build".to_string());
+/// assert_eq!(job.map(|name| name.len()), Job::Queued(5));
+/// ```
+///
+/// # Skipping Methods
+///
+/// Use `skip:` to leave out single methods, e.g. when an extension trait has a method of the same
+/// name, which the generated method would otherwise shadow.
+///
+/// ```
+/// use enumizer::alias_option;
+///
+/// trait Label {
+///     fn map(self) -> String;
+/// }
+///
+/// alias_option!(Tag, Named, Anonymous, skip: [map]);
+/// impl Label for Tag<&str> {
+///     fn map(self) -> String {
+///         self.unwrap_or("anonymous").to_uppercase()
+///     }
+/// }
+///
+/// assert_eq!(Tag::Named("release").map(), "RELEASE");
+/// ```
+///
+/// # Visibility
+///
+/// The generated type is `pub` by default. Use `vis:` to give it another visibility, or
+/// `vis: private` to leave the visibility out.
+///
+/// ```
+/// mod storage {
+///     enumizer::alias_option!(Slot, Filled, Empty, vis: pub(crate));
+/// }
+///
+/// let slot: storage::Slot<u8> = storage::Slot::Filled(1);
+/// assert!(slot.is_filled());
 /// ```
 ///
 /// # Custom Traits
@@ -91,10 +155,10 @@
 /// # Try Trait Support (Nightly Only)
 ///
 /// Add `implement_try` to enable the `?` operator for early returns.
-/// Requires nightly Rust with `#![feature(try_trait_v2)]`.
+/// Requires nightly Rust with `#![feature(try_trait_v2, try_trait_v2_residual)]`.
 ///
 /// ```ignore
-/// #![feature(try_trait_v2)]
+/// #![feature(try_trait_v2, try_trait_v2_residual)]
 /// use enumizer::alias_option;
 ///
 /// alias_option!(Value, Found, Searching, implement_try);
@@ -108,111 +172,262 @@
 /// assert_eq!(try_example(Value::Found(10), Value::Found(20)), Value::Found(30));
 /// assert_eq!(try_example(Value::Searching, Value::Found(20)), Value::Searching);
 /// ```
-
 #[macro_export]
 macro_rules! alias_option {
-    ($type_name:ident, $some_variant:ident, $none_variant:ident) => {
-        $crate::alias_option!($type_name, $some_variant, $none_variant, [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash]);
-    };
-    ($type_name:ident, $some_variant:ident, $none_variant:ident, traits: [$($trait:path),*]) => {
-        $crate::alias_option!($type_name, $some_variant, $none_variant, [$($trait),*]);
-    };
-    ($type_name:ident, $some_variant:ident, $none_variant:ident, implement_try) => {
-        $crate::alias_option!($type_name, $some_variant, $none_variant, [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash], implement_try);
-    };
-    ($type_name:ident, $some_variant:ident, $none_variant:ident, traits: [$($trait:path),*], implement_try) => {
-        $crate::alias_option!($type_name, $some_variant, $none_variant, [$($trait),*], implement_try);
-    };
-    ($type_name:ident, $some_variant:ident, $none_variant:ident, [$($trait:path),*]) => {
-        $crate::alias_option!($type_name, $some_variant, $none_variant, [$($trait),*], );
+    ($type_name:ident, $some_variant:ident, $none_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_validate!(alias_option, $some_variant, $none_variant, [mut, and, or, eq]);
+        $crate::__alias_options!(alias_option [$type_name, $some_variant, $none_variant] [T] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
     };
-    ($type_name:ident, $some_variant:ident, $none_variant:ident, [$($trait:path),*], $($implement_try:ident)?) => {
+    (@impl $vis:vis [$type_name:ident, $some_variant:ident, $none_variant:ident], [$T:ident], [$($bound:tt)*], [$($method:ident),*], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+      $crate::__alias_variant_attrs!($type_name, [$some_variant, $none_variant], [$($skip),*], alias_option ([$vis] [$type_name, $some_variant, $none_variant], [$T], [$($bound)*], [$($trait),*], [$(#[$attr])*], [$($flag),*]));
+      $crate::__alias_unsized!($vis $type_name, [$T], [$($skip),*]);
+      $crate::__alias_serde!(option [$vis] [[$($bound)*], $type_name, $some_variant, $none_variant], [$($skip),*]);
+      $crate::__alias_impls!(option [$T] [[$($bound)*], $type_name, $some_variant, $none_variant], [$($skip),*]);
+
+      $crate::__alias_forbid_unsafe!([$($skip),*], {
       paste::paste! {
-		#[derive($($trait),*)]
-		pub enum $type_name<T> {
-			$none_variant,
-			$some_variant(T),
-		}
 
-		impl<T> $type_name<T> {
-			/// Behaves like [`Option::is_none`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_none)
-			pub fn [<is_ $none_variant:lower>](&self) -> bool {
-				matches!(self, $type_name::$none_variant)
-			}
+		#[allow(dead_code)]
+		impl<T: $($bound)*> $type_name<T> {
+			$crate::__alias_doc_alias!($none_variant, None, "is_none", {
+				$crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($none_variant), "`, consider wrapping this in an `assert!()` instead"), {
+					#[doc = concat!("Returns `true` if the value is [`", stringify!($none_variant), "`](Self::", stringify!($none_variant), ").")]
+					///
+					#[doc = concat!("For example, `", stringify!($type_name), "::<u8>::", stringify!($none_variant), ".", stringify!([<is_ $none_variant:snake>]), "()` returns `true`.")]
+					///
+					/// Behaves like [`Option::is_none`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_none)
+					$vis fn [<is_ $none_variant:snake>](&self) -> bool {
+						matches!(self, $type_name::$none_variant)
+					}
+				});
+			});
 
-			/// Behaves like [`Option::is_some`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_some)
-			pub fn [<is_ $some_variant:lower>](&self) -> bool {
-				matches!(self, $type_name::$some_variant(_))
-			}
+			$crate::__alias_doc_alias!($some_variant, Some, "is_some", {
+				$crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($some_variant), "`, consider wrapping this in an `assert!()` instead"), {
+					#[doc = concat!("Returns `true` if the value is [`", stringify!($some_variant), "`](Self::", stringify!($some_variant), ").")]
+					///
+					#[doc = concat!("For example, `", stringify!($type_name), "::", stringify!($some_variant), "(1).", stringify!([<is_ $some_variant:snake>]), "()` returns `true`.")]
+					///
+					/// Behaves like [`Option::is_some`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_some)
+					$vis fn [<is_ $some_variant:snake>](&self) -> bool {
+						matches!(self, $type_name::$some_variant(_))
+					}
+				});
+			});
 
-			/// Behaves like [`Option::is_some_and`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_some_and)
-			pub fn [<is_ $some_variant:lower _and>]<F: FnOnce(&T) -> bool>(&self, f: F) -> bool {
-				match self {
-					$type_name::$some_variant(v) => f(v),
-					_ => false,
-				}
-			}
+			$crate::__alias_doc_alias!($some_variant, Some, "is_some_and", {
+				$crate::__alias_must_use!([$($skip),*], {
+					#[doc = concat!("Returns `true` if the value is [`", stringify!($some_variant), "`](Self::", stringify!($some_variant), ") and the contained value matches the predicate.")]
+					///
+					/// Behaves like [`Option::is_some_and`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_some_and)
+					$vis fn [<is_ $some_variant:snake _and>]<F: FnOnce(&T) -> bool>(&self, f: F) -> bool {
+						match self {
+							$type_name::$some_variant(v) => f(v),
+							_ => false,
+						}
+					}
+				});
+			});
 
-			/// Behaves like [`Option::is_none_or`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_none_or)
-			pub fn [<is_ $none_variant:lower _or>]<F: FnOnce(&T) -> bool>(&self, f: F) -> bool {
-				match self {
-					$type_name::$none_variant => true,
-					$type_name::$some_variant(v) => f(v),
-				}
-			}
+			$crate::__alias_doc_alias!($none_variant, None, "is_none_or", {
+				$crate::__alias_must_use!([$($skip),*], {
+					#[doc = concat!("Returns `true` if the value is [`", stringify!($none_variant), "`](Self::", stringify!($none_variant), "), or if the contained value matches the predicate.")]
+					///
+					/// Behaves like [`Option::is_none_or`](https://doc.rust-lang.org/std/option/enum.Option.html#method.is_none_or)
+					$vis fn [<is_ $none_variant:snake _or>]<F: FnOnce(&T) -> bool>(&self, f: F) -> bool {
+						match self {
+							$type_name::$none_variant => true,
+							$type_name::$some_variant(v) => f(v),
+						}
+					}
+				});
+			});
 
-			/// Behaves like [`Option::as_ref`](https://doc.rust-lang.org/std/option/enum.Option.html#method.as_ref)
-			pub fn [<as_ $some_variant:lower>](&self) -> Option<&T> {
-				match self {
-					$type_name::$some_variant(v) => Some(v),
-					_ => None,
+			$crate::__alias_must_use!([$($skip),*], {
+				#[doc = concat!("Returns `true` if the value is [`", stringify!($some_variant), "`](Self::", stringify!($some_variant), ") and the contained value equals `other`.")]
+				///
+				/// Behaves like the removed `Option::contains`
+				#[doc(alias = "contains")]
+				$vis fn [<$some_variant:snake _eq>]<U: ?Sized>(&self, other: &U) -> bool
+				where
+					T: PartialEq<U>,
+				{
+					match self {
+						$type_name::$some_variant(v) => v == other,
+						$type_name::$none_variant => false,
+					}
 				}
-			}
+			});
 
-			/// Behaves like [`Option::as_mut`](https://doc.rust-lang.org/std/option/enum.Option.html#method.as_mut)
-			pub fn [<as_ $some_variant:lower _mut>](&mut self) -> Option<&mut T> {
-				match self {
-					$type_name::$some_variant(v) => Some(v),
-					_ => None,
+			$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+				#[doc = concat!("Returns a reference to the contained value if the value is [`", stringify!($some_variant), "`](Self::", stringify!($some_variant), ").")]
+				///
+				/// Behaves like [`Option::as_ref`](https://doc.rust-lang.org/std/option/enum.Option.html#method.as_ref)
+				$vis fn [<as_ $some_variant:snake>](&self) -> Option<&T> {
+					match self {
+						$type_name::$some_variant(v) => Some(v),
+						_ => None,
+					}
 				}
-			}
+			});
 
-			/// Behaves like [`Option::map`](https://doc.rust-lang.org/std/option/enum.Option.html#method.map)
-			pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U> {
-				match self {
-					$type_name::$some_variant(v) => $type_name::$some_variant(f(v)),
-					$type_name::$none_variant => $type_name::$none_variant,
+			$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+				#[doc = concat!("Returns a mutable reference to the contained value if the value is [`", stringify!($some_variant), "`](Self::", stringify!($some_variant), ").")]
+				///
+				/// Behaves like [`Option::as_mut`](https://doc.rust-lang.org/std/option/enum.Option.html#method.as_mut)
+				$vis fn [<as_ $some_variant:snake _mut>](&mut self) -> Option<&mut T> {
+					match self {
+						$type_name::$some_variant(v) => Some(v),
+						_ => None,
+					}
 				}
-			}
+			});
 
-			/// Behaves like [`Option::unwrap`](https://doc.rust-lang.org/std/option/enum.Option.html#method.unwrap)
-			pub fn unwrap(self) -> T {
-				match self {
-					$type_name::$some_variant(v) => v,
-					$type_name::$none_variant => {
-						panic!("called `unwrap()` on a `{}`", stringify!($none_variant))
+			$crate::__alias_methods!(combinators, [$($method),*], {
+				$crate::__alias_skip!(map, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+						/// Behaves like [`Option::map`](https://doc.rust-lang.org/std/option/enum.Option.html#method.map)
+						$vis fn map<U: $($bound)*, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U> {
+							match self {
+								$type_name::$some_variant(v) => $type_name::$some_variant(f(v)),
+								$type_name::$none_variant => $type_name::$none_variant,
+							}
+						}
+					});
+				});
+			});
+
+			$crate::__alias_skip!(no_panics, [$($skip),*], {
+				$crate::__alias_skip!(unwrap, [$($skip),*], {
+					/// Behaves like [`Option::unwrap`](https://doc.rust-lang.org/std/option/enum.Option.html#method.unwrap)
+					$vis fn unwrap(self) -> T {
+						match self {
+							$type_name::$some_variant(v) => v,
+							$type_name::$none_variant => {
+								panic!("called `unwrap()` on a `{}`", stringify!($none_variant))
+							}
+						}
+					}
+				});
+			});
+
+			$crate::__alias_skip!(unwrap_or, [$($skip),*], {
+				/// Behaves like [`Option::unwrap_or`](https://doc.rust-lang.org/std/option/enum.Option.html#method.unwrap_or)
+				$vis fn unwrap_or(self, default: T) -> T {
+					match self {
+						$type_name::$some_variant(v) => v,
+						$type_name::$none_variant => default,
 					}
 				}
-			}
+			});
 
-			/// Behaves like [`Option::unwrap_or`](https://doc.rust-lang.org/std/option/enum.Option.html#method.unwrap_or)
-			pub fn unwrap_or(self, default: T) -> T {
-				match self {
-					$type_name::$some_variant(v) => v,
-					$type_name::$none_variant => default,
+			$crate::__alias_skip!(unwrap_or_else, [$($skip),*], {
+				/// Behaves like [`Option::unwrap_or_else`](https://doc.rust-lang.org/std/option/enum.Option.html#method.unwrap_or_else)
+				$vis fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+					match self {
+						$type_name::$some_variant(v) => v,
+						$type_name::$none_variant => f(),
+					}
 				}
+			});
+		}
+
+		$crate::__alias_methods!(combinators, [$($method),*], {
+			#[allow(dead_code)]
+			impl<'a, T: Clone + $($bound)*> $type_name<&'a T>
+			where
+				&'a T: $($bound)*,
+			{
+				$crate::__alias_skip!(cloned, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Behaves like [`Option::cloned`](https://doc.rust-lang.org/std/option/enum.Option.html#method.cloned)
+						$vis fn cloned(self) -> $type_name<T> {
+							match self {
+								$type_name::$some_variant(v) => $type_name::$some_variant(v.clone()),
+								$type_name::$none_variant => $type_name::$none_variant,
+							}
+						}
+					});
+				});
 			}
 
-			/// Behaves like [`Option::unwrap_or_else`](https://doc.rust-lang.org/std/option/enum.Option.html#method.unwrap_or_else)
-			pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
-				match self {
-					$type_name::$some_variant(v) => v,
-					$type_name::$none_variant => f(),
-				}
+			#[allow(dead_code)]
+			impl<'a, T: Copy + $($bound)*> $type_name<&'a T>
+			where
+				&'a T: $($bound)*,
+			{
+				$crate::__alias_skip!(copied, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Behaves like [`Option::copied`](https://doc.rust-lang.org/std/option/enum.Option.html#method.copied)
+						$vis fn copied(self) -> $type_name<T> {
+							match self {
+								$type_name::$some_variant(&v) => $type_name::$some_variant(v),
+								$type_name::$none_variant => $type_name::$none_variant,
+							}
+						}
+					});
+				});
 			}
+		});
+
+		#[allow(dead_code)]
+		impl<T: ::core::ops::Deref + $($bound)*> $type_name<T> {
+			$crate::__alias_skip!(as_deref, [$($skip),*], {
+				$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+					/// Behaves like [`Option::as_deref`](https://doc.rust-lang.org/std/option/enum.Option.html#method.as_deref)
+					$vis fn as_deref<'a>(&'a self) -> $type_name<&'a T::Target>
+					where
+						&'a T::Target: $($bound)*,
+					{
+						match self {
+							$type_name::$some_variant(v) => $type_name::$some_variant(v.deref()),
+							$type_name::$none_variant => $type_name::$none_variant,
+						}
+					}
+				});
+			});
+		}
+
+		#[allow(dead_code)]
+		impl<T: ::core::ops::DerefMut + $($bound)*> $type_name<T> {
+			$crate::__alias_skip!(as_deref_mut, [$($skip),*], {
+				$crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+					/// Behaves like [`Option::as_deref_mut`](https://doc.rust-lang.org/std/option/enum.Option.html#method.as_deref_mut)
+					$vis fn as_deref_mut<'a>(&'a mut self) -> $type_name<&'a mut T::Target>
+					where
+						&'a mut T::Target: $($bound)*,
+					{
+						match self {
+							$type_name::$some_variant(v) => $type_name::$some_variant(v.deref_mut()),
+							$type_name::$none_variant => $type_name::$none_variant,
+						}
+					}
+				});
+			});
 		}
 
-		impl<T> From<Option<T>> for $type_name<T> {
+		$crate::__alias_methods!(conversions, [$($method),*], {
+			#[allow(dead_code)]
+			impl<T: $($bound)*, E> $type_name<Result<T, E>>
+			where
+				Result<T, E>: $($bound)*,
+			{
+				$crate::__alias_skip!(transpose, [$($skip),*], {
+					$crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+						/// Behaves like [`Option::transpose`](https://doc.rust-lang.org/std/option/enum.Option.html#method.transpose)
+						$vis fn transpose(self) -> Result<$type_name<T>, E> {
+							match self {
+								$type_name::$some_variant(Ok(v)) => Ok($type_name::$some_variant(v)),
+								$type_name::$some_variant(Err(e)) => Err(e),
+								$type_name::$none_variant => Ok($type_name::$none_variant),
+							}
+						}
+					});
+				});
+			}
+		});
+
+		impl<T: $($bound)*> From<Option<T>> for $type_name<T> {
 			fn from(opt: Option<T>) -> Self {
 				match opt {
 					Some(v) => $type_name::$some_variant(v),
@@ -221,7 +436,7 @@ macro_rules! alias_option {
 			}
 		}
 
-		impl<T> From<$type_name<T>> for Option<T> {
+		impl<T: $($bound)*> From<$type_name<T>> for Option<T> {
 			fn from(val: $type_name<T>) -> Self {
 				match val {
 					$type_name::$some_variant(v) => Some(v),
@@ -229,36 +444,200 @@ macro_rules! alias_option {
 				}
 			}
 		}
+
+		$crate::__alias_methods!(conversions, [$($method),*], {
+			impl<T: $($bound)*> From<T> for $type_name<T> {
+				fn from(val: T) -> Self {
+					$type_name::$some_variant(val)
+				}
+			}
+		});
+        }
+      });
+
+        $crate::__alias_option_flag!(@flags [$($bound)*], [$($method),*], [$($skip),*], $type_name, $some_variant, $none_variant, [$($flag),*]);
+    };
+    (@enum [$(#[$some_attr:meta])*] [$(#[$none_attr:meta])*] [$vis:vis] [$type_name:ident, $some_variant:ident, $none_variant:ident], [$T:ident], [$($bound:tt)*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T: $($bound)*> {
+            $(#[$none_attr])*
+            $none_variant,
+            $(#[$some_attr])*
+            $some_variant($T),
         }
+    };
+    ($type_name:ident <$($lifetime:lifetime),+ $(,)?> $($rest:tt)*) => {
+        compile_error!(concat!(
+            "`alias_option!` does not take lifetime parameters, its payloads can borrow through the generic parameters, e.g. `", stringify!($type_name), "<&'a str>`. ",
+            "Use `borrowed: Name` for a `Name<'a, ..>` alias holding references",
+        ));
+    };
+    ($($input:tt)*) => {
+        compile_error!(concat!(
+            "expected `alias_option!(Name, SomeVariant, NoneVariant, options...)`, got `alias_option!(", stringify!($($input)*), ")`",
+        ));
+    };
+}
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_option_flag {
+    (@flags $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $some_variant:ident, $none_variant:ident, [$($flag:ident),*]) => {
         $(
-            let _ = stringify!($implement_try);
-            paste::paste! {
-                impl<T> std::ops::Try for $type_name<T> {
-                    type Output = T;
-                    type Residual = $type_name<std::convert::Infallible>;
-
-                    fn from_output(output: Self::Output) -> Self {
-                        $type_name::$some_variant(output)
+            $crate::__alias_option_flag!($flag, $bounds, $methods, $skip, $type_name, $some_variant, $none_variant);
+        )*
+    };
+    (implement_try, [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $some_variant:ident, $none_variant:ident) => {
+        impl<T: $($bound)*> ::core::ops::Try for $type_name<T>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type Output = T;
+            type Residual = $type_name<::core::convert::Infallible>;
+
+            fn from_output(output: Self::Output) -> Self {
+                $type_name::$some_variant(output)
+            }
+
+            fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, Self::Output> {
+                match self {
+                    $type_name::$some_variant(v) => ::core::ops::ControlFlow::Continue(v),
+                    $type_name::$none_variant => {
+                        ::core::ops::ControlFlow::Break($type_name::$none_variant)
                     }
+                }
+            }
+        }
+
+        impl<T: $($bound)*> ::core::ops::FromResidual for $type_name<T>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            fn from_residual(_: $type_name<::core::convert::Infallible>) -> Self {
+                $type_name::$none_variant
+            }
+        }
+
+        impl<T: $($bound)*> ::core::ops::Residual<T> for $type_name<::core::convert::Infallible>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type TryType = $type_name<T>;
+        }
+    };
+    (none_last, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $some_variant:ident, $none_variant:ident) => {};
+    (assert_same_size, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $some_variant:ident, $none_variant:ident) => {
+        $crate::__alias_assert_same_size!($bounds, [$type_name<u8> => Option<u8>], [
+            $type_name<bool> => Option<bool>,
+            $type_name<::core::num::NonZeroU32> => Option<::core::num::NonZeroU32>,
+            $type_name<&'static u8> => Option<&'static u8>,
+            $type_name<&'static [u8]> => Option<&'static [u8]>
+        ]);
+    };
+    (generate_tests, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $some_variant:ident, $none_variant:ident) => {
+        $crate::__alias_skip!(@tested __alias_option_flag [@generate_tests, $bounds, $methods], $skip, [$type_name, $some_variant, $none_variant]);
+    };
+    (@generate_tests, [$($bound:tt)*], [$($method:ident),*], [$($skip:ident),*], $type_name:ident, $some_variant:ident, $none_variant:ident) => {
+        paste::paste! {
+            #[cfg(test)]
+            mod [<$type_name:snake _conformance>] {
+                use super::*;
+
+                const VALUES: [Option<u8>; 2] = [Some(1), None];
+
+                fn alias(value: Option<u8>) -> $type_name<u8> {
+                    value.into()
+                }
 
-                    fn branch(self) -> std::ops::ControlFlow<Self::Residual, Self::Output> {
-                        match self {
-                            $type_name::$some_variant(v) => std::ops::ControlFlow::Continue(v),
-                            $type_name::$none_variant => std::ops::ControlFlow::Break($type_name::$none_variant),
-                        }
+                #[test]
+                fn round_trip() {
+                    for value in VALUES {
+                        assert_eq!(Option::from(alias(value)), value);
                     }
                 }
 
-                impl<T> std::ops::FromResidual for $type_name<T> {
-                    fn from_residual(_: $type_name<std::convert::Infallible>) -> Self {
-                        $type_name::$none_variant
+                #[test]
+                fn size_equivalence() {
+                    assert_eq!(::core::mem::size_of::<$type_name<u8>>(), ::core::mem::size_of::<Option<u8>>());
+                    $crate::__alias_unbounded!([$($bound)*], {
+                        assert_eq!(::core::mem::size_of::<$type_name<&u8>>(), ::core::mem::size_of::<Option<&u8>>());
+                    });
+                }
+
+                #[test]
+                fn methods() {
+                    for value in VALUES {
+                        assert_eq!(alias(value).[<is_ $some_variant:snake>](), value.is_some());
+                        assert_eq!(alias(value).[<is_ $none_variant:snake>](), value.is_none());
+                        assert_eq!(alias(value).[<is_ $some_variant:snake _and>](|v| *v > 0), value.is_some_and(|v| v > 0));
+                        assert_eq!(alias(value).[<is_ $none_variant:snake _or>](|v| *v > 1), value.is_none_or(|v| v > 1));
+                        assert_eq!(alias(value).[<$some_variant:snake _eq>](&1), value == Some(1));
+                        assert_eq!(alias(value).[<as_ $some_variant:snake>]().copied(), value.as_ref().copied());
+
+                        let (mut aliased, mut expected) = (alias(value), value);
+                        assert_eq!(aliased.[<as_ $some_variant:snake _mut>]().map(|v| *v), expected.as_mut().map(|v| *v));
+
+                        $crate::__alias_methods!(combinators, [$($method),*], {
+                            $crate::__alias_skip!(map, [$($skip),*], {
+                                assert_eq!(Option::from(alias(value).map(u16::from)), value.map(u16::from));
+                            });
+                        });
+                        $crate::__alias_skip!(no_panics, [$($skip),*], {
+                            $crate::__alias_skip!(unwrap, [$($skip),*], {
+                                if let Some(v) = value {
+                                    assert_eq!(alias(value).unwrap(), v);
+                                }
+                            });
+                        });
+                        $crate::__alias_skip!(unwrap_or, [$($skip),*], {
+                            assert_eq!(alias(value).unwrap_or(5), value.unwrap_or(5));
+                        });
+                        $crate::__alias_skip!(unwrap_or_else, [$($skip),*], {
+                            assert_eq!(alias(value).unwrap_or_else(|| 5), value.unwrap_or_else(|| 5));
+                        });
                     }
+
+                    $crate::__alias_unbounded!([$($bound)*], {
+                        $crate::__alias_methods!(combinators, [$($method),*], {
+                            for value in VALUES {
+                                $crate::__alias_skip!(cloned, [$($skip),*], {
+                                    assert_eq!(Option::from($type_name::from(value.as_ref()).cloned()), value);
+                                });
+                                $crate::__alias_skip!(copied, [$($skip),*], {
+                                    assert_eq!(Option::from($type_name::from(value.as_ref()).copied()), value);
+                                });
+                            }
+                        });
+                        $crate::__alias_skip!(as_deref, [$($skip),*], {
+                            let value = Some("deref".to_string());
+                            assert_eq!(Option::from($type_name::<String>::from(value.clone()).as_deref()), value.as_deref());
+                        });
+                        $crate::__alias_skip!(as_deref_mut, [$($skip),*], {
+                            let mut expected = Some("deref".to_string());
+                            let mut aliased = $type_name::<String>::from(expected.clone());
+                            assert_eq!(Option::from(aliased.as_deref_mut()), expected.as_deref_mut());
+                        });
+                    });
+
+                    $crate::__alias_methods!(conversions, [$($method),*], {
+                        assert_eq!(Option::from($type_name::from(1u8)), Some(1));
+                        $crate::__alias_unbounded!([$($bound)*], {
+                            $crate::__alias_skip!(transpose, [$($skip),*], {
+                                for value in [Some(Ok(1u8)), Some(Err(2u16)), None] {
+                                    assert_eq!($type_name::from(value).transpose().map(Option::from), value.transpose());
+                                }
+                            });
+                        });
+                    });
                 }
             }
-        )?
-    }
-  }
+        }
+    };
+    ($flag:ident, $($args:tt)*) => {
+        compile_error!(concat!("`alias_option!` does not accept the `", stringify!($flag), "` option"));
+    };
+}
 
 #[cfg(test)]
 mod tests {
@@ -275,4 +654,55 @@ mod tests {
             std::mem::size_of::<Option<NonZeroU32>>()
         );
     }
+
+    #[test]
+    fn multi_word_variants() {
+        alias_option!(Status, FoundItem, NotFound);
+
+        let mut status = Status::FoundItem(1);
+        assert!(status.is_found_item());
+        assert!(!status.is_not_found());
+        assert_eq!(status.as_found_item_mut(), Some(&mut 1));
+        assert!(status.found_item_eq(&1));
+    }
+
+    #[test]
+    fn none_last() {
+        alias_option!(Deadline, Due, Unscheduled, none_last);
+
+        let mut deadlines = vec![Deadline::Unscheduled, Deadline::Due(3), Deadline::Due(1)];
+        deadlines.sort();
+        assert_eq!(
+            deadlines,
+            [Deadline::Due(1), Deadline::Due(3), Deadline::Unscheduled]
+        );
+
+        alias_option!(Weight, Measured, Unmeasured, none_last, traits: [Debug, PartialEq, PartialOrd]);
+        assert!(Weight::Measured(0.5) < Weight::Measured(1.5));
+        assert!(Weight::Measured(f64::MAX) < Weight::Unmeasured);
+        assert_eq!(
+            Weight::Measured(f64::NAN).partial_cmp(&Weight::Measured(1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn borrowed_payloads() {
+        alias_option!(Lookup, Hit, Miss);
+
+        fn find<'a>(names: &'a [String], prefix: &str) -> Lookup<&'a str> {
+            Lookup::from(names.iter().find(|name| name.starts_with(prefix))).map(String::as_str)
+        }
+
+        let names = vec!["ferris".to_string(), "corro".to_string()];
+        assert_eq!(find(&names, "co"), Lookup::Hit("corro"));
+        assert!(find(&names, "x").is_miss());
+        assert!(find(&names, "fe").hit_eq(&"ferris"));
+        assert_eq!(
+            Lookup::from(names.first()).cloned(),
+            Lookup::Hit("ferris".to_string())
+        );
+        let lengths = [1u8, 2];
+        assert_eq!(Lookup::from(lengths.last()).copied(), Lookup::Hit(2));
+    }
 }
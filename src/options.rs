@@ -0,0 +1,2393 @@
+/// Parses the options trailing an alias macro invocation, and calls the alias macro back with them
+/// normalized.
+///
+/// The alias macro passes its own name, its positional arguments, its default generic parameter
+/// names and its default derive set, and is called back as
+/// `$callback!(@impl vis [args], [generics], [bounds], [methods], [skip], [traits], [attrs],
+/// [flags])`.
+/// Options that are not given fall back to `pub`, the default generic names, no bounds, all method
+/// groups, no skipped methods, the default derive set and no extra attributes. Skipped methods,
+/// renamed methods and the `no_panics`, `no_must_use` and `forbid_unsafe` flags share the `[skip]`
+/// list, with renames as `(old => new)`, the method prefix as `(prefix name)`, the attributes given
+/// with `serde_attrs:` as `(attrs Variant [#[...]])`, the type aliases given with `boxed:` and
+/// `borrowed:` as `(boxed Name)` and `(borrowed Name)`, and `serde: std` and the `serde_as_std`
+/// flag as `(serde std)` and `(serde as_std)`, and the `json_schema`, `to_schema`, `ts`, `defmt`,
+/// `arbitrary`, `proptest` and `quickcheck` flags as `(json_schema)`, `(to_schema)`, `(ts)`,
+/// `(defmt)`, `(arbitrary)`, `(proptest)` and `(quickcheck)`. With the `none_last` or `err_first`
+/// flag, `PartialOrd` and `Ord` are taken out of the derive set and added to the list as
+/// `(partial_ord)` and `(ord)`, to be implemented by hand. The other serde modes are added to the
+/// attributes as `#[serde(...)]`. Traits given with `traits_add:` are appended to the derive
+/// set, whether it is the default or given with `traits:`. With `module:`, the callback is expanded
+/// inside the module with `pub` visibility, and the module and its re-exports get the requested
+/// visibility instead. Without a list of re-exports, everything in the module is re-exported with a
+/// glob. The names given with `skip:` and the old names given with `rename:` must be
+/// in the `__alias_same_method!` table, and an unknown name is reported with `compile_error!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_options {
+    ($callback:ident $args:tt $generics:tt $default_traits:tt $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics [] [] [] []] [pub] $default_traits [] [] [] $($($option)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt vis: private $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args [] $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt vis: $new_vis:vis $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args [$new_vis] $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt $skip:tt $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt generics: [$($generic:ident),*] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args [$($generic),*] $bounds $methods $skip $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt $skip:tt $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt bounds: [$($bound:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics [$($bound)*] $methods $skip $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt $skip:tt $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt methods: [$($method:ident),*] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds [$($method),*] $skip $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt skip: [$($new_skipped:ident),+ $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::__alias_same_method!($new_skipped, $new_skipped, {}, {
+            compile_error!(concat!("unknown method `", stringify!($new_skipped), "` in skip"));
+        });)+
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* $($new_skipped),+] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt rename: [$($old:ident => $new:ident),+ $(,)?] $(, $($rest:tt)*)?) => {
+        $($crate::__alias_same_method!($old, $old, {}, {
+            compile_error!(concat!("unknown method `", stringify!($old), "` in rename"));
+        });)+
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* $(($old => $new)),+] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt method_prefix: $prefix:ident $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (prefix $prefix)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt serde_attrs: [$($variant:ident => $(#[$variant_attr:meta])+),+ $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* $((attrs $variant [$(#[$variant_attr])+])),+] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt boxed: $name:ident $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (boxed $name)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt borrowed: $name:ident $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (borrowed $name)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt [$(#[$attr:meta])*] $flags:tt serde: untagged $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis $traits $added [$(#[$attr])* #[serde(untagged)]] $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt [$(#[$attr:meta])*] $flags:tt serde: [tag = $tag:literal $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis $traits $added [$(#[$attr])* #[serde(tag = $tag)]] $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt [$(#[$attr:meta])*] $flags:tt serde: [tag = $tag:literal, content = $content:literal $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis $traits $added [$(#[$attr])* #[serde(tag = $tag, content = $content)]] $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt serde: $mode:ident $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (serde $mode)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt skip: [] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [[$type_name:ident $($arg:tt)*] $generics:tt $bounds:tt $methods:tt $skip:tt $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt module: $name:ident $([$($export:ident),*])? $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [[$type_name $($arg)*] $generics $bounds $methods $skip [$name * $([$($export),*])?]] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt traits: [$($trait:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis [$($trait)*] $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt [$($added:tt)*] $attrs:tt $flags:tt traits_add: [$($new_trait:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis $traits [$($added)* , $($new_trait)*] $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt [$(#[$attr:meta])*] $flags:tt attrs: [$(#[$new_attr:meta]),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis $traits $added [$(#[$attr])* $(#[$new_attr])*] $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt no_panics $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* no_panics] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt serde_as_std $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (serde as_std)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt json_schema $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (json_schema)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt to_schema $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (to_schema)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt ts $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (ts)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt defmt $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (defmt)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt arbitrary $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (arbitrary)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt proptest $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (proptest)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt quickcheck $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* (quickcheck)] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt forbid_unsafe $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* forbid_unsafe] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt no_must_use $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback [$args $generics $bounds $methods [$($skipped,)* no_must_use] $module] $vis $traits $added $attrs $flags $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt $attrs:tt [$($flag:ident),*] $new_flag:ident $(, $($rest:tt)*)?) => {
+        $crate::__alias_options!(@parse $callback $args $vis $traits $added $attrs [$($flag,)* $new_flag] $($($rest)*)?);
+    };
+    (@parse $callback:ident $args:tt $vis:tt [$($trait:tt)*] [$($added:tt)*] $attrs:tt $flags:tt) => {
+        $crate::__alias_options!(@order $callback $args $vis [$($trait)* , $($added)*] $attrs $flags $flags);
+    };
+    (@parse $callback:ident $args:tt $vis:tt $traits:tt $added:tt $attrs:tt $flags:tt $($rest:tt)+) => {
+        compile_error!(concat!(
+            "`", stringify!($callback), "!` could not parse its options, starting at `", stringify!($($rest)+),
+            "`. Options are flags like `implement_try`, or `name: value` pairs like `vis: pub(crate)`, separated by commas",
+        ));
+    };
+    (@order $callback:ident $args:tt $vis:tt $traits:tt $attrs:tt $flags:tt [none_last $(, $rest:ident)*]) => {
+        $crate::__alias_options!(@traits $callback $args $vis [] $traits $attrs $flags reorder);
+    };
+    (@order $callback:ident $args:tt $vis:tt $traits:tt $attrs:tt $flags:tt [err_first $(, $rest:ident)*]) => {
+        $crate::__alias_options!(@traits $callback $args $vis [] $traits $attrs $flags reorder);
+    };
+    (@order $callback:ident $args:tt $vis:tt $traits:tt $attrs:tt $flags:tt [$other:ident $(, $rest:ident)*]) => {
+        $crate::__alias_options!(@order $callback $args $vis $traits $attrs $flags [$($rest),*]);
+    };
+    (@order $callback:ident $args:tt $vis:tt $traits:tt $attrs:tt $flags:tt []) => {
+        $crate::__alias_options!(@traits $callback $args $vis [] $traits $attrs $flags derive);
+    };
+    (@traits $callback:ident $args:tt $vis:tt $kept:tt [, $($rest:tt)*] $attrs:tt $flags:tt $order:ident) => {
+        $crate::__alias_options!(@traits $callback $args $vis $kept [$($rest)*] $attrs $flags $order);
+    };
+    (@traits $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $kept:tt [PartialOrd $($rest:tt)*] $attrs:tt $flags:tt reorder) => {
+        $crate::__alias_options!(@traits $callback [$args $generics $bounds $methods [$($skipped,)* (partial_ord)] $module] $vis $kept [$($rest)*] $attrs $flags reorder);
+    };
+    (@traits $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt [$($skipped:tt),*] $module:tt] $vis:tt $kept:tt [Ord $($rest:tt)*] $attrs:tt $flags:tt reorder) => {
+        $crate::__alias_options!(@traits $callback [$args $generics $bounds $methods [$($skipped,)* (ord)] $module] $vis $kept [$($rest)*] $attrs $flags reorder);
+    };
+    (@traits $callback:ident $args:tt $vis:tt [$($kept:path),*] [$trait:path $(, $($rest:tt)*)?] $attrs:tt $flags:tt $order:ident) => {
+        $crate::__alias_options!(@traits $callback $args $vis [$($kept,)* $trait] [$($($rest)*)?] $attrs $flags $order);
+    };
+    (@traits $callback:ident $args:tt $vis:tt $traits:tt [] $attrs:tt $flags:tt $order:ident) => {
+        $crate::__alias_options!(@done $callback $args $vis $traits $attrs $flags);
+    };
+    (@traits $callback:ident $args:tt $vis:tt $traits:tt [$($rest:tt)+] $attrs:tt $flags:tt $order:ident) => {
+        compile_error!(concat!("`", stringify!($callback), "!` expected a list of trait paths, got `", stringify!($($rest)+), "`"));
+    };
+    (@done $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt $skip:tt []] [$($vis:tt)*] $traits:tt $attrs:tt $flags:tt) => {
+        $crate::$callback!(@impl $($vis)* $args, $generics, $bounds, $methods, $skip, $traits, $attrs, $flags);
+    };
+    (@done $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt $skip:tt [$name:ident $default_exports:tt $([$($export:ident),*])?]] [$($vis:tt)*] $traits:tt $attrs:tt $flags:tt) => {
+        $crate::__alias_options!(@module $callback [$args $generics $bounds $methods $skip] [$($vis)*] $traits $attrs $flags $name $([$($export),*])? $default_exports);
+    };
+    (@module $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt $skip:tt] [$($vis:tt)*] $traits:tt $attrs:tt $flags:tt $name:ident [$($export:ident),*] *) => {
+        $($vis)* mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::$callback!(@impl pub $args, $generics, $bounds, $methods, $skip, $traits, $attrs, $flags);
+        }
+
+        $($vis)* use $name::{$($export),*};
+    };
+    (@module $callback:ident [$args:tt $generics:tt $bounds:tt $methods:tt $skip:tt] [$($vis:tt)*] $traits:tt $attrs:tt $flags:tt $name:ident *) => {
+        $($vis)* mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::$callback!(@impl pub $args, $generics, $bounds, $methods, $skip, $traits, $attrs, $flags);
+        }
+
+        $($vis)* use $name::*;
+    };
+}
+
+/// Emits a `compile_error!` if the two variant names of an alias macro are the same, only differ
+/// in case, or one is the other with one of the given method suffixes appended, so the generated
+/// methods would collide.
+///
+/// The suffixes are the ones the alias macro appends to a snake case variant name, like `mut` for
+/// `as_found_mut`, which is also the shared accessor of a `FoundMut` variant. Identifiers can only
+/// be compared against literals, so the check defines a macro with the first variant name as its
+/// literal, and calls it with the second one. The `$` of that macro is passed in as `($d:tt)`, and
+/// the macro is kept in a const block so it does not leak into the caller's scope.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_validate {
+    ($macro_name:ident, $first:ident, $second:ident, [$($suffix:ident),*]) => {
+        $crate::__alias_validate!(@check ($) $macro_name, $first, $second);
+        $crate::__alias_validate!(@suffix ($) $macro_name, $first, $second, [$($suffix),*]);
+        $crate::__alias_validate!(@suffix ($) $macro_name, $second, $first, [$($suffix),*]);
+    };
+    (@suffix ($d:tt) $macro_name:ident, $base:ident, $other:ident, [$($suffix:ident),*]) => {
+        paste::paste! {
+            const _: () = {
+                macro_rules! check_suffixes {
+                    $(
+                    ([<$base:snake _ $suffix>]) => {
+                        compile_error!(concat!(
+                            "the variants `", stringify!($base), "` and `", stringify!($other), "` of `",
+                            stringify!($macro_name), "!` both generate methods with `", stringify!([<$base:snake _ $suffix>]),
+                            "` in their name, use variant names that do not extend each other with `_", stringify!($suffix), "`",
+                        ));
+                    };
+                    )*
+                    ($d other:ident) => {};
+                }
+
+                check_suffixes!([<$other:snake>]);
+            };
+        }
+    };
+    (@check ($d:tt) $macro_name:ident, $first:ident, $second:ident) => {
+        paste::paste! {
+            const _: () = {
+                macro_rules! check_variants {
+                    (same $first) => {
+                        compile_error!(concat!(
+                            "`", stringify!($macro_name), "!` needs two different variant names, but got `",
+                            stringify!($first), "` twice",
+                        ));
+                    };
+                    (same $d other:ident) => {
+                        check_variants!(snake [<$second:snake>]);
+                    };
+                    (snake [<$first:snake>]) => {
+                        compile_error!(concat!(
+                            "the variants `", stringify!($first), "` and `", stringify!($second), "` of `",
+                            stringify!($macro_name), "!` both generate methods like `is_", stringify!([<$first:snake>]),
+                            "`, use variant names that differ in more than case",
+                        ));
+                    };
+                    (snake $d other:ident) => {};
+                }
+
+                check_variants!(same $second);
+            };
+        }
+    };
+}
+
+/// Sorts the attributes given with `serde_attrs:` by variant, and calls the alias macro back as
+/// `$callback!(@enum [first variant attrs] [second variant attrs] args...)`.
+///
+/// The attributes are keyed by variant name, which can only be compared against literals, so the
+/// sorting is done by a macro named after the type, with the variant names as its literals. The
+/// `$` of that macro is passed in as `($d:tt)`. Without `serde_attrs:` the alias macro is called
+/// back directly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_variant_attrs {
+    ($type_name:ident, $variants:tt, [$($skip:tt),*], $callback:ident $args:tt) => {
+        $crate::__alias_variant_attrs!(@collect $type_name, $variants, [], [$($skip),*], $callback $args);
+    };
+    (@collect $type_name:ident, $variants:tt, [$($found:tt)*], [(attrs $variant:ident $attrs:tt) $(, $rest:tt)*], $callback:ident $args:tt) => {
+        $crate::__alias_variant_attrs!(@collect $type_name, $variants, [$($found)* $variant $attrs], [$($rest),*], $callback $args);
+    };
+    (@collect $type_name:ident, $variants:tt, $found:tt, [$other:tt $(, $rest:tt)*], $callback:ident $args:tt) => {
+        $crate::__alias_variant_attrs!(@collect $type_name, $variants, $found, [$($rest),*], $callback $args);
+    };
+    (@collect $type_name:ident, $variants:tt, [], [], $callback:ident ($($args:tt)*)) => {
+        $crate::$callback!(@enum [] [] $($args)*);
+    };
+    (@collect $type_name:ident, $variants:tt, [$($found:tt)+], [], $callback:ident $args:tt) => {
+        $crate::__alias_variant_attrs!(@sort ($) $type_name, $variants, [$($found)+], $callback $args);
+    };
+    (@sort ($d:tt) $type_name:ident, [$first:ident, $second:ident], [$($found:tt)+], $callback:ident ($($args:tt)*)) => {
+        paste::paste! {
+            macro_rules! [<__alias_ $type_name _variant_attrs>] {
+                ([$d($d first_attr:tt)*] $d second_attrs:tt $first [$d($d attr:tt)*] $d($d rest:tt)*) => {
+                    [<__alias_ $type_name _variant_attrs>]!([$d($d first_attr)* $d($d attr)*] $d second_attrs $d($d rest)*);
+                };
+                ($d first_attrs:tt [$d($d second_attr:tt)*] $second [$d($d attr:tt)*] $d($d rest:tt)*) => {
+                    [<__alias_ $type_name _variant_attrs>]!($d first_attrs [$d($d second_attr)* $d($d attr)*] $d($d rest)*);
+                };
+                ($d first_attrs:tt $d second_attrs:tt $d other:ident $d($d rest:tt)*) => {
+                    compile_error!(concat!(
+                        "`serde_attrs:` names `", stringify!($d other), "`, which is not a variant of `",
+                        stringify!($type_name), "`",
+                    ));
+                };
+                ($d first_attrs:tt $d second_attrs:tt) => {
+                    $crate::$callback!(@enum $d first_attrs $d second_attrs $($args)*);
+                };
+            }
+
+            [<__alias_ $type_name _variant_attrs>]!([] [] $($found)+);
+        }
+    };
+}
+
+/// Emits the type aliases given with `boxed:` and `borrowed:`, which fill every generic parameter
+/// with a `Box` or a reference, so unsized payloads like `dyn Fn()` or `str` can be named directly.
+///
+/// Bounds on the parameters of a type alias are not enforced, so the parameters are left unbounded,
+/// which also lifts the implicit `Sized` bound.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_unsized {
+    ($vis:vis $type_name:ident, [$($generic:ident),*], [(boxed $name:ident) $(, $rest:tt)*]) => {
+        #[doc = concat!("[`", stringify!($type_name), "`] holding its values in a `Box`, so they can be unsized.")]
+        $vis type $name<$($generic),*> = $type_name<$(Box<$generic>),*>;
+
+        $crate::__alias_unsized!($vis $type_name, [$($generic),*], [$($rest),*]);
+    };
+    ($vis:vis $type_name:ident, [$($generic:ident),*], [(borrowed $name:ident) $(, $rest:tt)*]) => {
+        #[doc = concat!("[`", stringify!($type_name), "`] holding references to its values, so they can be unsized.")]
+        $vis type $name<'a, $($generic),*> = $type_name<$(&'a $generic),*>;
+
+        $crate::__alias_unsized!($vis $type_name, [$($generic),*], [$($rest),*]);
+    };
+    ($vis:vis $type_name:ident, $generics:tt, [$other:tt $(, $rest:tt)*]) => {
+        $crate::__alias_unsized!($vis $type_name, $generics, [$($rest),*]);
+    };
+    ($vis:vis $type_name:ident, $generics:tt, []) => {};
+}
+
+/// Implements the traits of other crates requested with the `json_schema`, `to_schema`, `ts`,
+/// `defmt`, `arbitrary`, `proptest` and `quickcheck` flags, which arrive as `(json_schema)`,
+/// `(to_schema)`, `(ts)`, `(defmt)`, `(arbitrary)`, `(proptest)` and `(quickcheck)`.
+///
+/// `alias_option!`, `alias_result!` and `alias_either!` pass their kind and their usual arguments.
+/// The other alias macros pass `variants` and their variants with their payload types, e.g.
+/// `[Fetch [Fetched(T), Fetching]]`, and only support `arbitrary`, `proptest` and `quickcheck`.
+/// They accept none of the other entries of the `[skip]` list.
+///
+/// The schemas describe the serde representation: the std counterpart's with `serde: std`, and the
+/// externally tagged one of `#[derive(Serialize)]` otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_impls {
+    ($kind:ident $generics:tt $args:tt, [$($skip:tt),*]) => {
+        $crate::__alias_impls!(@find $kind $generics $args, [$($skip),*], [], tagged);
+    };
+    (@find $kind:ident $generics:tt $args:tt, [($flag:ident) $(, $rest:tt)*], [$($found:ident)*], $repr:ident) => {
+        $crate::__alias_impls!(@find $kind $generics $args, [$($rest),*], [$($found)* $flag], $repr);
+    };
+    (@find variants $generics:tt [$type_name:ident $variants:tt], [$other:tt $(, $rest:tt)*], $found:tt, $repr:ident) => {
+        compile_error!(concat!("the alias macro of `", stringify!($type_name), "` does not accept `", stringify!($other), "`"));
+    };
+    (@find $kind:ident $generics:tt $args:tt, [(serde std) $(, $rest:tt)*], $found:tt, $repr:ident) => {
+        $crate::__alias_impls!(@find $kind $generics $args, [$($rest),*], $found, std);
+    };
+    (@find $kind:ident $generics:tt $args:tt, [$other:tt $(, $rest:tt)*], $found:tt, $repr:ident) => {
+        $crate::__alias_impls!(@find $kind $generics $args, [$($rest),*], $found, $repr);
+    };
+    (@find $kind:ident $generics:tt $args:tt, [], [$($found:ident)*], $repr:ident) => {
+        $($crate::__alias_impls!(@emit $found $repr $kind $generics $args);)*
+    };
+    (@emit arbitrary $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_arbitrary!($kind $generics $args);
+    };
+    (@emit proptest $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_proptest!($kind $generics $args);
+    };
+    (@emit quickcheck $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_quickcheck!($kind $generics $args);
+    };
+    (@emit $flag:ident $repr:ident variants $generics:tt [$type_name:ident $variants:tt]) => {
+        compile_error!(concat!(
+            "`", stringify!($flag), "` is only supported by `alias_option!`, `alias_result!` and `alias_either!`, not by the alias macro of `",
+            stringify!($type_name), "`",
+        ));
+    };
+    (@emit json_schema $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_schemars!($repr $kind $args);
+    };
+    (@emit to_schema $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_utoipa!($repr $kind $args);
+    };
+    (@emit ts $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_ts!($repr $kind $generics $args);
+    };
+    (@emit defmt $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_defmt!($kind $generics $args);
+    };
+    (@emit partial_ord $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_cmp!(PartialOrd $kind $generics $args);
+    };
+    (@emit ord $repr:ident $kind:ident $generics:tt $args:tt) => {
+        $crate::__alias_cmp!(Ord $kind $generics $args);
+    };
+    (@emit $other:ident $repr:ident $kind:ident $generics:tt $args:tt) => {};
+}
+
+/// Emits the `PartialOrd` or `Ord` impl of the `none_last` and `err_first` flags, which order the
+/// `Some` variant before the `None` variant, and the `Err` variant before the `Ok` variant. The
+/// options parser takes the derives of these traits out of the derive set and passes them on as
+/// `(partial_ord)` and `(ord)`, so the variants keep the declaration order of the std type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_cmp {
+    (PartialOrd option [$T:ident] [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        impl<$T: ::core::cmp::PartialOrd + $($bound)*> ::core::cmp::PartialOrd for $type_name<$T> {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                match (self, other) {
+                    ($type_name::$some_variant(a), $type_name::$some_variant(b)) => a.partial_cmp(b),
+                    ($type_name::$some_variant(_), $type_name::$none_variant) => Some(::core::cmp::Ordering::Less),
+                    ($type_name::$none_variant, $type_name::$some_variant(_)) => Some(::core::cmp::Ordering::Greater),
+                    ($type_name::$none_variant, $type_name::$none_variant) => Some(::core::cmp::Ordering::Equal),
+                }
+            }
+        }
+    };
+    (Ord option [$T:ident] [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        impl<$T: ::core::cmp::Ord + $($bound)*> ::core::cmp::Ord for $type_name<$T> {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                match (self, other) {
+                    ($type_name::$some_variant(a), $type_name::$some_variant(b)) => a.cmp(b),
+                    ($type_name::$some_variant(_), $type_name::$none_variant) => ::core::cmp::Ordering::Less,
+                    ($type_name::$none_variant, $type_name::$some_variant(_)) => ::core::cmp::Ordering::Greater,
+                    ($type_name::$none_variant, $type_name::$none_variant) => ::core::cmp::Ordering::Equal,
+                }
+            }
+        }
+    };
+    (PartialOrd result [$T:ident, $E:ident] [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        impl<$T: ::core::cmp::PartialOrd + $($bound)*, $E: ::core::cmp::PartialOrd + $($bound)*> ::core::cmp::PartialOrd for $type_name<$T, $E> {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                match (self, other) {
+                    ($type_name::$ok_variant(a), $type_name::$ok_variant(b)) => a.partial_cmp(b),
+                    ($type_name::$err_variant(a), $type_name::$err_variant(b)) => a.partial_cmp(b),
+                    ($type_name::$err_variant(_), $type_name::$ok_variant(_)) => Some(::core::cmp::Ordering::Less),
+                    ($type_name::$ok_variant(_), $type_name::$err_variant(_)) => Some(::core::cmp::Ordering::Greater),
+                }
+            }
+        }
+    };
+    (Ord result [$T:ident, $E:ident] [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        impl<$T: ::core::cmp::Ord + $($bound)*, $E: ::core::cmp::Ord + $($bound)*> ::core::cmp::Ord for $type_name<$T, $E> {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                match (self, other) {
+                    ($type_name::$ok_variant(a), $type_name::$ok_variant(b)) => a.cmp(b),
+                    ($type_name::$err_variant(a), $type_name::$err_variant(b)) => a.cmp(b),
+                    ($type_name::$err_variant(_), $type_name::$ok_variant(_)) => ::core::cmp::Ordering::Less,
+                    ($type_name::$ok_variant(_), $type_name::$err_variant(_)) => ::core::cmp::Ordering::Greater,
+                }
+            }
+        }
+    };
+}
+
+/// Emits the `JsonSchema` impl of `json_schema`. With `serde: std` the schema is the std
+/// counterpart's, otherwise each variant is an object with the variant name as its only key, and
+/// payload-less variants are the variant name as a string.
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_schemars {
+    (std option [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        impl<T: $crate::__private::schemars::JsonSchema + $($bound)*> $crate::__private::schemars::JsonSchema for $type_name<T> {
+            fn inline_schema() -> bool {
+                <Option<T> as $crate::__private::schemars::JsonSchema>::inline_schema()
+            }
+
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                format!(concat!(stringify!($type_name), "_for_{}"), T::schema_name()).into()
+            }
+
+            fn schema_id() -> std::borrow::Cow<'static, str> {
+                format!(concat!(module_path!(), "::", stringify!($type_name), "<{}>"), T::schema_id()).into()
+            }
+
+            fn json_schema(generator: &mut $crate::__private::schemars::SchemaGenerator) -> $crate::__private::schemars::Schema {
+                <Option<T> as $crate::__private::schemars::JsonSchema>::json_schema(generator)
+            }
+        }
+    };
+    (std result [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        impl<T: $crate::__private::schemars::JsonSchema + $($bound)*, E: $crate::__private::schemars::JsonSchema + $($bound)*> $crate::__private::schemars::JsonSchema for $type_name<T, E> {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                format!(concat!(stringify!($type_name), "_of_{}_or_{}"), T::schema_name(), E::schema_name()).into()
+            }
+
+            fn schema_id() -> std::borrow::Cow<'static, str> {
+                format!(concat!(module_path!(), "::", stringify!($type_name), "<{}, {}>"), T::schema_id(), E::schema_id()).into()
+            }
+
+            fn json_schema(generator: &mut $crate::__private::schemars::SchemaGenerator) -> $crate::__private::schemars::Schema {
+                <Result<T, E> as $crate::__private::schemars::JsonSchema>::json_schema(generator)
+            }
+        }
+    };
+    (tagged option [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        impl<T: $crate::__private::schemars::JsonSchema + $($bound)*> $crate::__private::schemars::JsonSchema for $type_name<T> {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                format!(concat!(stringify!($type_name), "_for_{}"), T::schema_name()).into()
+            }
+
+            fn schema_id() -> std::borrow::Cow<'static, str> {
+                format!(concat!(module_path!(), "::", stringify!($type_name), "<{}>"), T::schema_id()).into()
+            }
+
+            fn json_schema(generator: &mut $crate::__private::schemars::SchemaGenerator) -> $crate::__private::schemars::Schema {
+                $crate::__private::schemars::json_schema!({
+                    "oneOf": [
+                        $crate::__alias_schemars!(@variant generator, $some_variant, T),
+                        { "type": "string", "const": stringify!($none_variant) },
+                    ]
+                })
+            }
+        }
+    };
+    (tagged result [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        $crate::__alias_schemars!(@two $type_name, [$($bound)*], "_of_{}_or_{}", $ok_variant, $err_variant, T, E);
+    };
+    (tagged either [[$($bound:tt)*], $type_name:ident, $left_variant:ident, $right_variant:ident]) => {
+        $crate::__alias_schemars!(@two $type_name, [$($bound)*], "_of_{}_or_{}", $left_variant, $right_variant, L, R);
+    };
+    (std $kind:ident $args:tt) => {};
+    (@two $type_name:ident, [$($bound:tt)*], $name:literal, $first_variant:ident, $second_variant:ident, $First:ident, $Second:ident) => {
+        impl<$First: $crate::__private::schemars::JsonSchema + $($bound)*, $Second: $crate::__private::schemars::JsonSchema + $($bound)*> $crate::__private::schemars::JsonSchema for $type_name<$First, $Second> {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                format!(concat!(stringify!($type_name), $name), $First::schema_name(), $Second::schema_name()).into()
+            }
+
+            fn schema_id() -> std::borrow::Cow<'static, str> {
+                format!(concat!(module_path!(), "::", stringify!($type_name), "<{}, {}>"), $First::schema_id(), $Second::schema_id()).into()
+            }
+
+            fn json_schema(generator: &mut $crate::__private::schemars::SchemaGenerator) -> $crate::__private::schemars::Schema {
+                $crate::__private::schemars::json_schema!({
+                    "oneOf": [
+                        $crate::__alias_schemars!(@variant generator, $first_variant, $First),
+                        $crate::__alias_schemars!(@variant generator, $second_variant, $Second),
+                    ]
+                })
+            }
+        }
+    };
+    (@variant $generator:ident, $variant:ident, $payload:ident) => {
+        $crate::__private::schemars::json_schema!({
+            "type": "object",
+            "properties": { stringify!($variant): $generator.subschema_for::<$payload>() },
+            "required": [stringify!($variant)],
+            "additionalProperties": false,
+        })
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_schemars {
+    ($repr:ident $kind:ident $args:tt) => {
+        compile_error!("`json_schema` needs the `schemars` feature of enumizer");
+    };
+}
+
+/// Emits the `PartialSchema` and `ToSchema` impls of `to_schema`, with the same layout as the
+/// `JsonSchema` impl of `json_schema`. Payload schemas are inlined, and the schema name includes
+/// the names of the payload schemas, so each instantiation of a generic alias gets its own
+/// component.
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_utoipa {
+    (std option [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        $crate::__alias_utoipa!(@one $type_name, [$($bound)*], T, {
+            $crate::__private::utoipa::openapi::schema::OneOfBuilder::new()
+                .item(<T as $crate::__private::utoipa::PartialSchema>::schema())
+                .item($crate::__private::utoipa::openapi::schema::ObjectBuilder::new()
+                    .schema_type($crate::__private::utoipa::openapi::schema::Type::Null))
+        });
+    };
+    (std result [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        $crate::__alias_utoipa!(@two $type_name, [$($bound)*], Ok, Err, T, E);
+    };
+    (tagged option [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        $crate::__alias_utoipa!(@one $type_name, [$($bound)*], T, {
+            $crate::__private::utoipa::openapi::schema::OneOfBuilder::new()
+                .item($crate::__alias_utoipa!(@variant $some_variant, T))
+                .item($crate::__private::utoipa::openapi::schema::ObjectBuilder::new()
+                    .schema_type($crate::__private::utoipa::openapi::schema::Type::String)
+                    .enum_values(Some([stringify!($none_variant)])))
+        });
+    };
+    (tagged result [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        $crate::__alias_utoipa!(@two $type_name, [$($bound)*], $ok_variant, $err_variant, T, E);
+    };
+    (tagged either [[$($bound:tt)*], $type_name:ident, $left_variant:ident, $right_variant:ident]) => {
+        $crate::__alias_utoipa!(@two $type_name, [$($bound)*], $left_variant, $right_variant, L, R);
+    };
+    (std $kind:ident $args:tt) => {};
+    (@one $type_name:ident, [$($bound:tt)*], $T:ident, $schema:block) => {
+        impl<$T: $crate::__private::utoipa::ToSchema + $($bound)*> $crate::__private::utoipa::PartialSchema for $type_name<$T> {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<$crate::__private::utoipa::openapi::schema::Schema> {
+                $schema.into()
+            }
+        }
+
+        impl<$T: $crate::__private::utoipa::ToSchema + $($bound)*> $crate::__private::utoipa::ToSchema for $type_name<$T> {
+            fn name() -> std::borrow::Cow<'static, str> {
+                format!(concat!(stringify!($type_name), "_{}"), $T::name()).into()
+            }
+
+            fn schemas(schemas: &mut Vec<(String, $crate::__private::utoipa::openapi::RefOr<$crate::__private::utoipa::openapi::schema::Schema>)>) {
+                $T::schemas(schemas);
+            }
+        }
+    };
+    (@two $type_name:ident, [$($bound:tt)*], $first_variant:ident, $second_variant:ident, $First:ident, $Second:ident) => {
+        impl<$First: $crate::__private::utoipa::ToSchema + $($bound)*, $Second: $crate::__private::utoipa::ToSchema + $($bound)*> $crate::__private::utoipa::PartialSchema for $type_name<$First, $Second> {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<$crate::__private::utoipa::openapi::schema::Schema> {
+                $crate::__private::utoipa::openapi::schema::OneOfBuilder::new()
+                    .item($crate::__alias_utoipa!(@variant $first_variant, $First))
+                    .item($crate::__alias_utoipa!(@variant $second_variant, $Second))
+                    .into()
+            }
+        }
+
+        impl<$First: $crate::__private::utoipa::ToSchema + $($bound)*, $Second: $crate::__private::utoipa::ToSchema + $($bound)*> $crate::__private::utoipa::ToSchema for $type_name<$First, $Second> {
+            fn name() -> std::borrow::Cow<'static, str> {
+                format!(concat!(stringify!($type_name), "_{}_{}"), $First::name(), $Second::name()).into()
+            }
+
+            fn schemas(schemas: &mut Vec<(String, $crate::__private::utoipa::openapi::RefOr<$crate::__private::utoipa::openapi::schema::Schema>)>) {
+                $First::schemas(schemas);
+                $Second::schemas(schemas);
+            }
+        }
+    };
+    (@variant $variant:ident, $payload:ident) => {
+        $crate::__private::utoipa::openapi::schema::ObjectBuilder::new()
+            .schema_type($crate::__private::utoipa::openapi::schema::Type::Object)
+            .property(stringify!($variant), <$payload as $crate::__private::utoipa::PartialSchema>::schema())
+            .required(stringify!($variant))
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_utoipa {
+    ($repr:ident $kind:ident $args:tt) => {
+        compile_error!("`to_schema` needs the `utoipa` feature of enumizer");
+    };
+}
+
+/// Emits the `TS` impl of `ts`, which declares the type as a union with the same layout as the
+/// `JsonSchema` impl of `json_schema`. The layout is a format string with a `{}` per payload,
+/// filled with the payload type names for `inline`, and with the generic parameter names for the
+/// generic declaration of `decl`.
+#[cfg(feature = "ts-rs")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_ts {
+    (std option $generics:tt [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        $crate::__alias_ts!(@impl $type_name, $generics, [$($bound)*], "{} | null");
+    };
+    (std result $generics:tt [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        $crate::__alias_ts!(@impl $type_name, $generics, [$($bound)*], "{{ \"Ok\": {} }} | {{ \"Err\": {} }}");
+    };
+    (tagged option $generics:tt [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        $crate::__alias_ts!(@impl $type_name, $generics, [$($bound)*], concat!("{{ \"", stringify!($some_variant), "\": {} }} | \"", stringify!($none_variant), "\""));
+    };
+    (tagged $kind:ident $generics:tt [[$($bound:tt)*], $type_name:ident, $first_variant:ident, $second_variant:ident]) => {
+        $crate::__alias_ts!(@impl $type_name, $generics, [$($bound)*], concat!("{{ \"", stringify!($first_variant), "\": {} }} | {{ \"", stringify!($second_variant), "\": {} }}"));
+    };
+    (std $kind:ident $generics:tt $args:tt) => {};
+    (@impl $type_name:ident, [$($generic:ident),+], $bounds:tt, $layout:expr) => {
+        $crate::__alias_ts!(@bounded $type_name, [$($generic $bounds),+], $layout);
+    };
+    (@bounded $type_name:ident, [$($generic:ident [$($bound:tt)*]),+], $layout:expr) => {
+        impl<$($generic: $crate::__private::ts_rs::TS + $($bound)*),+> $crate::__private::ts_rs::TS for $type_name<$($generic),+> {
+            type WithoutGenerics = $type_name<$($crate::__alias_ts!(@dummy $generic)),+>;
+            type OptionInnerType = Self;
+
+            fn ident() -> String {
+                stringify!($type_name).to_owned()
+            }
+
+            fn name() -> String {
+                format!("{}<{}>", stringify!($type_name), [$(<$generic as $crate::__private::ts_rs::TS>::name()),+].join(", "))
+            }
+
+            fn decl() -> String {
+                format!("type {}<{}> = {};", stringify!($type_name), [$(stringify!($generic)),+].join(", "), format!($layout, $(stringify!($generic)),+))
+            }
+
+            fn decl_concrete() -> String {
+                format!("type {} = {};", stringify!($type_name), <Self as $crate::__private::ts_rs::TS>::inline())
+            }
+
+            fn inline() -> String {
+                format!($layout, $(<$generic as $crate::__private::ts_rs::TS>::name()),+)
+            }
+
+            fn inline_flattened() -> String {
+                format!("({})", <Self as $crate::__private::ts_rs::TS>::inline())
+            }
+
+            fn visit_dependencies(v: &mut impl $crate::__private::ts_rs::TypeVisitor)
+            where
+                Self: 'static,
+            {
+                $(
+                    v.visit::<$generic>();
+                    <$generic as $crate::__private::ts_rs::TS>::visit_generics(v);
+                )+
+            }
+
+            fn visit_generics(v: &mut impl $crate::__private::ts_rs::TypeVisitor)
+            where
+                Self: 'static,
+            {
+                $(
+                    v.visit::<$generic>();
+                    <$generic as $crate::__private::ts_rs::TS>::visit_generics(v);
+                )+
+            }
+
+            fn output_path() -> Option<std::path::PathBuf> {
+                Some(std::path::PathBuf::from(concat!(stringify!($type_name), ".ts")))
+            }
+        }
+    };
+    (@dummy $generic:ident) => {
+        $crate::__private::ts_rs::Dummy
+    };
+}
+
+#[cfg(not(feature = "ts-rs"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_ts {
+    ($repr:ident $kind:ident $generics:tt $args:tt) => {
+        compile_error!("`ts` needs the `ts-rs` feature of enumizer");
+    };
+}
+
+/// Emits the `Format` impl of `defmt`, which prints the variant name, and the payload in
+/// parentheses.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_defmt {
+    (option [$T:ident] [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        impl<$T: $crate::__private::defmt::Format + $($bound)*> $crate::__private::defmt::Format for $type_name<$T> {
+            fn format(&self, f: $crate::__private::defmt::Formatter) {
+                match self {
+                    $type_name::$some_variant(v) => $crate::__private::defmt::write!(f, "{=str}({=?})", stringify!($some_variant), v),
+                    $type_name::$none_variant => $crate::__private::defmt::write!(f, "{=str}", stringify!($none_variant)),
+                }
+            }
+        }
+    };
+    ($kind:ident [$First:ident, $Second:ident] [[$($bound:tt)*], $type_name:ident, $first_variant:ident, $second_variant:ident]) => {
+        impl<$First: $crate::__private::defmt::Format + $($bound)*, $Second: $crate::__private::defmt::Format + $($bound)*> $crate::__private::defmt::Format for $type_name<$First, $Second> {
+            fn format(&self, f: $crate::__private::defmt::Formatter) {
+                match self {
+                    $type_name::$first_variant(v) => $crate::__private::defmt::write!(f, "{=str}({=?})", stringify!($first_variant), v),
+                    $type_name::$second_variant(v) => $crate::__private::defmt::write!(f, "{=str}({=?})", stringify!($second_variant), v),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_defmt {
+    ($kind:ident $generics:tt $args:tt) => {
+        compile_error!("`defmt` needs the `defmt` feature of enumizer");
+    };
+}
+
+/// Emits the `Arbitrary` impl of `arbitrary`, which picks a variant from the input, and then
+/// builds its payloads with their own `Arbitrary` impls.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_arbitrary {
+    (option [$T:ident] [$bounds:tt, $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        $crate::__alias_arbitrary!(@impl $type_name, [$T], $bounds, [$some_variant($T), $none_variant]);
+    };
+    ($kind:ident [$First:ident, $Second:ident] [$bounds:tt, $type_name:ident, $first_variant:ident, $second_variant:ident]) => {
+        $crate::__alias_arbitrary!(@impl $type_name, [$First, $Second], $bounds, [$first_variant($First), $second_variant($Second)]);
+    };
+    (variants [$($generic:ident),*] [$type_name:ident $variants:tt]) => {
+        $crate::__alias_arbitrary!(@impl $type_name, [$($generic),*], [], $variants);
+    };
+    (@impl $type_name:ident, [$($generic:ident),*], $bounds:tt, $variants:tt) => {
+        $crate::__alias_arbitrary!(@bounded $type_name, [$($generic $bounds),*], $variants);
+    };
+    (@bounded $type_name:ident, [$($generic:ident [$($bound:tt)*]),*], [$($variant:ident $(($($payload:ty),+))?),+]) => {
+        impl<'a, $($generic: $crate::__private::arbitrary::Arbitrary<'a> + $($bound)*),*> $crate::__private::arbitrary::Arbitrary<'a> for $type_name<$($generic),*> {
+            fn arbitrary(u: &mut $crate::__private::arbitrary::Unstructured<'a>) -> $crate::__private::arbitrary::Result<Self> {
+                let variants: &[fn(&mut $crate::__private::arbitrary::Unstructured<'a>) -> $crate::__private::arbitrary::Result<Self>] = &[
+                    $(|_u| Ok($type_name::$variant $(($(<$payload as $crate::__private::arbitrary::Arbitrary<'a>>::arbitrary(_u)?),+))?)),+
+                ];
+                variants[u.choose_index(variants.len())?](u)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_arbitrary {
+    ($kind:ident $generics:tt $args:tt) => {
+        compile_error!("`arbitrary` needs the `arbitrary` feature of enumizer");
+    };
+}
+
+/// Emits the `Arbitrary` impl of `proptest`, a weighted union of a strategy per variant. Variants
+/// with payloads are weighted 3 to 1 against the ones without, which only have a single value.
+/// The payloads are generated with their own `Arbitrary` impls, as a tuple that is then spread
+/// into the variant.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_proptest {
+    (option [$T:ident] [$bounds:tt, $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        $crate::__alias_proptest!(@impl $type_name, [$T], $bounds, [$some_variant($T), $none_variant]);
+    };
+    ($kind:ident [$First:ident, $Second:ident] [$bounds:tt, $type_name:ident, $first_variant:ident, $second_variant:ident]) => {
+        $crate::__alias_proptest!(@impl $type_name, [$First, $Second], $bounds, [$first_variant($First), $second_variant($Second)]);
+    };
+    (variants [$($generic:ident),*] [$type_name:ident $variants:tt]) => {
+        $crate::__alias_proptest!(@impl $type_name, [$($generic),*], [], $variants);
+    };
+    (@impl $type_name:ident, [$($generic:ident),*], $bounds:tt, $variants:tt) => {
+        $crate::__alias_proptest!(@bounded $type_name, [$($generic $bounds),*], $variants);
+    };
+    (@bounded $type_name:ident, [$($generic:ident [$($bound:tt)*]),*], [$($variant:ident $(($($payload:ty),+))?),+]) => {
+        impl<$($generic: $crate::__private::proptest::arbitrary::Arbitrary + 'static + $($bound)*),*> $crate::__private::proptest::arbitrary::Arbitrary for $type_name<$($generic),*> {
+            type Parameters = ();
+            type Strategy = $crate::__private::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                use $crate::__private::proptest::strategy::Strategy;
+
+                $crate::__private::proptest::strategy::Union::new_weighted(vec![
+                    $($crate::__alias_proptest!(@variant $type_name::$variant $(($($payload),+))?)),+
+                ])
+                .boxed()
+            }
+        }
+    };
+    (@variant $type_name:ident::$variant:ident) => {
+        (1, $crate::__private::proptest::strategy::LazyJust::new(|| $type_name::$variant).boxed())
+    };
+    (@variant $type_name:ident::$variant:ident($($payload:ty),+)) => {
+        (3, $crate::__private::proptest::arbitrary::any::<($($payload,)+)>()
+            .prop_map(|values| $crate::__alias_proptest!(@spread $type_name::$variant, values, [$($payload),+], [0 1 2 3 4 5 6 7], []))
+            .boxed())
+    };
+    (@spread $type_name:ident::$variant:ident, $values:ident, [$payload:ty $(, $rest:ty)*], [$index:tt $($indices:tt)*], [$($spread:tt)*]) => {
+        $crate::__alias_proptest!(@spread $type_name::$variant, $values, [$($rest),*], [$($indices)*], [$($spread)* $values.$index,])
+    };
+    (@spread $type_name:ident::$variant:ident, $values:ident, [], $indices:tt, [$($spread:tt)*]) => {
+        $type_name::$variant($($spread)*)
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_proptest {
+    ($kind:ident $generics:tt $args:tt) => {
+        compile_error!("`proptest` needs the `proptest` feature of enumizer");
+    };
+}
+
+/// Emits the `Arbitrary` impl of `quickcheck`. A value with payloads shrinks to the variants
+/// without payloads first, e.g. `Some(1)` to `None`, and then to the same variant with shrunk
+/// payloads. The payloads are bound to names from a fixed pool, which limits variants to eight
+/// payloads.
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_quickcheck {
+    (option [$T:ident] [$bounds:tt, $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        $crate::__alias_quickcheck!(@impl $type_name, [$T], $bounds, [$some_variant($T), $none_variant]);
+    };
+    ($kind:ident [$First:ident, $Second:ident] [$bounds:tt, $type_name:ident, $first_variant:ident, $second_variant:ident]) => {
+        $crate::__alias_quickcheck!(@impl $type_name, [$First, $Second], $bounds, [$first_variant($First), $second_variant($Second)]);
+    };
+    (variants [$($generic:ident),*] [$type_name:ident $variants:tt]) => {
+        $crate::__alias_quickcheck!(@impl $type_name, [$($generic),*], [], $variants);
+    };
+    (@impl $type_name:ident, [$($generic:ident),*], $bounds:tt, $variants:tt) => {
+        $crate::__alias_quickcheck!(@bounded $type_name, [$($generic $bounds),*], $variants, $variants);
+    };
+    (@bounded $type_name:ident, [$($generic:ident [$($bound:tt)*]),*], [$($variant:ident $(($($payload:ty),+))?),+], $all:tt) => {
+        impl<$($generic: $crate::__private::quickcheck::Arbitrary + $($bound)*),*> $crate::__private::quickcheck::Arbitrary for $type_name<$($generic),*> {
+            fn arbitrary(g: &mut $crate::__private::quickcheck::Gen) -> Self {
+                let variants: &[fn(&mut $crate::__private::quickcheck::Gen) -> Self] = &[
+                    $(|_g| $type_name::$variant $(($(<$payload as $crate::__private::quickcheck::Arbitrary>::arbitrary(_g)),+))?),+
+                ];
+                g.choose(variants).expect("an enum has at least one variant")(g)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                $($crate::__alias_quickcheck!(@shrink self, $all, $type_name::$variant $(($($payload),+))?);)+
+                unreachable!()
+            }
+        }
+    };
+    (@shrink $self:ident, $all:tt, $type_name:ident::$variant:ident) => {
+        if let $type_name::$variant = $self {
+            return $crate::__private::quickcheck::empty_shrinker();
+        }
+    };
+    (@shrink $self:ident, $all:tt, $type_name:ident::$variant:ident($($payload:ty),+)) => {
+        $crate::__alias_quickcheck!(@bind $self, $all, $type_name::$variant, [$($payload),+], [a b c d e f g h], []);
+    };
+    (@bind $self:ident, $all:tt, $type_name:ident::$variant:ident, [$payload:ty $(, $rest:ty)*], [$name:ident $($names:ident)*], [$($bound:ident)*]) => {
+        $crate::__alias_quickcheck!(@bind $self, $all, $type_name::$variant, [$($rest),*], [$($names)*], [$($bound)* $name]);
+    };
+    (@bind $self:ident, [$($other:ident $(($($other_payload:ty),+))?),+], $type_name:ident::$variant:ident, [], $names:tt, [$($name:ident)+]) => {
+        if let $type_name::$variant($($name),+) = $self {
+            let empty = [$($crate::__alias_quickcheck!(@empty $type_name::$other $(($($other_payload),+))?)),+];
+            let shrunk = $crate::__private::quickcheck::Arbitrary::shrink(&($($name.clone(),)+));
+            return Box::new(empty.into_iter().flatten().chain(shrunk.map(|($($name,)+)| $type_name::$variant($($name),+))));
+        }
+    };
+    (@empty $type_name:ident::$variant:ident) => {
+        Some($type_name::$variant)
+    };
+    (@empty $type_name:ident::$variant:ident($($payload:ty),+)) => {
+        None
+    };
+}
+
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_quickcheck {
+    ($kind:ident $generics:tt $args:tt) => {
+        compile_error!("`quickcheck` needs the `quickcheck` feature of enumizer");
+    };
+}
+
+/// Emits the serde impls for the mode given with `serde:`, and the companion module of the
+/// `serde_as_std` flag.
+///
+/// `serde: std` implements `Serialize` and `Deserialize` by hand, with the representation of the
+/// std counterpart, so `alias_option!` serializes like `Option` and `alias_result!` like `Result`.
+/// `serde_as_std` emits the same representation as a module for `#[serde(with = "...")]`, and
+/// arrives as `(serde as_std)`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_serde {
+    ($kind:ident $vis:tt $args:tt, [(serde $mode:ident) $(, $rest:tt)*]) => {
+        $crate::__alias_serde!(@mode $mode $kind $vis $args);
+        $crate::__alias_serde!($kind $vis $args, [$($rest),*]);
+    };
+    ($kind:ident $vis:tt $args:tt, [$other:tt $(, $rest:tt)*]) => {
+        $crate::__alias_serde!($kind $vis $args, [$($rest),*]);
+    };
+    ($kind:ident $vis:tt $args:tt, []) => {};
+    (@mode std option $vis:tt $args:tt) => {
+        $crate::__alias_serde_std!(option $args);
+    };
+    (@mode std result $vis:tt $args:tt) => {
+        $crate::__alias_serde_std!(result $args);
+    };
+    (@mode std $kind:ident $vis:tt $args:tt) => {
+        compile_error!(concat!("`alias_", stringify!($kind), "!` has no std counterpart, and does not accept `serde: std`"));
+    };
+    (@mode as_std option $vis:tt $args:tt) => {
+        $crate::__alias_serde_as_std!(option $vis $args);
+    };
+    (@mode as_std result $vis:tt $args:tt) => {
+        $crate::__alias_serde_as_std!(result $vis $args);
+    };
+    (@mode as_std $kind:ident $vis:tt $args:tt) => {
+        compile_error!(concat!("`alias_", stringify!($kind), "!` has no std counterpart, and does not accept `serde_as_std`"));
+    };
+    (@mode $mode:ident $kind:ident $vis:tt $args:tt) => {
+        compile_error!(concat!(
+            "unknown serde mode `", stringify!($mode), "`, expected `serde: std`, `serde: untagged`, ",
+            "`serde: [tag = \"...\"]` or `serde: [tag = \"...\", content = \"...\"]`",
+        ));
+    };
+}
+
+/// Implements `Serialize` and `Deserialize` with the representation of the std counterpart, for
+/// `serde: std`. The impls go through the std type, so they match it exactly.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_serde_std {
+    (option [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        impl<T: $crate::__private::serde::Serialize + $($bound)*> $crate::__private::serde::Serialize for $type_name<T> {
+            fn serialize<S: $crate::__private::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    $type_name::$some_variant(v) => serializer.serialize_some(v),
+                    $type_name::$none_variant => serializer.serialize_none(),
+                }
+            }
+        }
+
+        impl<'de, T: $crate::__private::serde::Deserialize<'de> + $($bound)*> $crate::__private::serde::Deserialize<'de> for $type_name<T> {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <Option<T> as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+    (result [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        impl<T: $crate::__private::serde::Serialize + $($bound)*, E: $crate::__private::serde::Serialize + $($bound)*> $crate::__private::serde::Serialize for $type_name<T, E> {
+            fn serialize<S: $crate::__private::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    $type_name::$ok_variant(v) => serializer.serialize_newtype_variant("Result", 0, "Ok", v),
+                    $type_name::$err_variant(e) => serializer.serialize_newtype_variant("Result", 1, "Err", e),
+                }
+            }
+        }
+
+        impl<'de, T: $crate::__private::serde::Deserialize<'de> + $($bound)*, E: $crate::__private::serde::Deserialize<'de> + $($bound)*> $crate::__private::serde::Deserialize<'de> for $type_name<T, E> {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <Result<T, E> as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_serde_std {
+    ($kind:ident $args:tt) => {
+        compile_error!("`serde: std` needs the `serde` feature of enumizer");
+    };
+}
+
+/// Emits the module of `serde_as_std`, named after the type, e.g. `reply_as_std` for `Reply`. Its
+/// `serialize` and `deserialize` functions go through the std type, like `serde: std`.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_serde_as_std {
+    (option [$vis:vis] [[$($bound:tt)*], $type_name:ident, $some_variant:ident, $none_variant:ident]) => {
+        paste::paste! {
+            #[doc = concat!("Serializes and deserializes a [`", stringify!($type_name), "`] like an `Option`, for `#[serde(with = \"", stringify!([<$type_name:snake _as_std>]), "\")]`.")]
+            $vis mod [<$type_name:snake _as_std>] {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// Serializes the value like the matching `Option`
+                pub fn serialize<T, S>(value: &super::$type_name<T>, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    T: $crate::__private::serde::Serialize + $($bound)*,
+                    S: $crate::__private::serde::Serializer,
+                {
+                    match value {
+                        super::$type_name::$some_variant(v) => serializer.serialize_some(v),
+                        super::$type_name::$none_variant => serializer.serialize_none(),
+                    }
+                }
+
+                /// Deserializes the value from the representation of the matching `Option`
+                pub fn deserialize<'de, T, D>(deserializer: D) -> ::core::result::Result<super::$type_name<T>, D::Error>
+                where
+                    T: $crate::__private::serde::Deserialize<'de> + $($bound)*,
+                    D: $crate::__private::serde::Deserializer<'de>,
+                {
+                    <::core::option::Option<T> as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer).map(super::$type_name::from)
+                }
+            }
+        }
+    };
+    (result [$vis:vis] [[$($bound:tt)*], $type_name:ident, $ok_variant:ident, $err_variant:ident]) => {
+        paste::paste! {
+            #[doc = concat!("Serializes and deserializes a [`", stringify!($type_name), "`] like a `Result`, for `#[serde(with = \"", stringify!([<$type_name:snake _as_std>]), "\")]`.")]
+            $vis mod [<$type_name:snake _as_std>] {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// Serializes the value like the matching `Result`
+                pub fn serialize<T, E, S>(value: &super::$type_name<T, E>, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    T: $crate::__private::serde::Serialize + $($bound)*,
+                    E: $crate::__private::serde::Serialize + $($bound)*,
+                    S: $crate::__private::serde::Serializer,
+                {
+                    match value {
+                        super::$type_name::$ok_variant(v) => serializer.serialize_newtype_variant("Result", 0, "Ok", v),
+                        super::$type_name::$err_variant(e) => serializer.serialize_newtype_variant("Result", 1, "Err", e),
+                    }
+                }
+
+                /// Deserializes the value from the representation of the matching `Result`
+                pub fn deserialize<'de, T, E, D>(deserializer: D) -> ::core::result::Result<super::$type_name<T, E>, D::Error>
+                where
+                    T: $crate::__private::serde::Deserialize<'de> + $($bound)*,
+                    E: $crate::__private::serde::Deserialize<'de> + $($bound)*,
+                    D: $crate::__private::serde::Deserializer<'de>,
+                {
+                    <::core::result::Result<T, E> as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer).map(super::$type_name::from)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_serde_as_std {
+    ($kind:ident $vis:tt $args:tt) => {
+        compile_error!("`serde_as_std` needs the `serde` feature of enumizer");
+    };
+}
+
+/// Emits the items of a method group, if the group was selected with `methods:`.
+///
+/// An empty selection means `methods:` was not given, and emits every group. The `core` group is
+/// always emitted.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_methods {
+    ($group:ident, [], { $($item:tt)* }) => {
+        $($item)*
+    };
+    (core, [$($method:ident),*], { $($item:tt)* }) => {
+        $($item)*
+    };
+    ($group:ident, [$($method:ident),+], $items:tt) => {
+        $crate::__alias_methods!(@find $group, [$($method),+], $items);
+    };
+    (@find combinators, [combinators $(, $rest:ident)*], { $($item:tt)* }) => {
+        $($item)*
+    };
+    (@find conversions, [conversions $(, $rest:ident)*], { $($item:tt)* }) => {
+        $($item)*
+    };
+    (@find iterators, [iterators $(, $rest:ident)*], { $($item:tt)* }) => {
+        $($item)*
+    };
+    (@find $group:ident, [$other:ident $(, $rest:ident)*], $items:tt) => {
+        $crate::__alias_methods!(@find $group, [$($rest),*], $items);
+    };
+    (@find $group:ident, [], $items:tt) => {};
+}
+
+/// Emits the items of a method, unless the method was named with `skip:`.
+///
+/// Only methods with a fixed name can be skipped or renamed, since the name is compared with
+/// `__alias_same_method!`. The `no_panics` flag is passed along as a skipped name, and guards every
+/// panicking method. Methods renamed with `rename:` or `method_prefix:` are emitted through
+/// `__alias_rename!`, and an explicit rename takes precedence over the prefix.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_skip {
+    ($name:ident, [], { $($item:tt)* }) => {
+        $($item)*
+    };
+    ($name:ident, [$($skipped:tt),+], $items:tt) => {
+        $crate::__alias_skip!(@find $name, [$($skipped),+], [], $items);
+    };
+    (@find $name:ident, [($old:ident => $new:ident) $(, $rest:tt)*], $renamed:tt, $items:tt) => {
+        $crate::__alias_same_method!($name, $old, {
+            $crate::__alias_skip!(@find $name, [$($rest),*], [$new], $items);
+        }, {
+            $crate::__alias_skip!(@find $name, [$($rest),*], $renamed, $items);
+        });
+    };
+    (@find no_panics, [(prefix $prefix:ident) $(, $rest:tt)*], $renamed:tt, $items:tt) => {
+        $crate::__alias_skip!(@find no_panics, [$($rest),*], $renamed, $items);
+    };
+    (@find forbid_unsafe, [(prefix $prefix:ident) $(, $rest:tt)*], $renamed:tt, $items:tt) => {
+        $crate::__alias_skip!(@find forbid_unsafe, [$($rest),*], $renamed, $items);
+    };
+    (@find $name:ident, [(prefix $prefix:ident) $(, $rest:tt)*], [], $items:tt) => {
+        $crate::__alias_skip!(@find $name, [$($rest),*], [$prefix $name], $items);
+    };
+    (@find $name:ident, [(prefix $prefix:ident) $(, $rest:tt)*], $renamed:tt, $items:tt) => {
+        $crate::__alias_skip!(@find $name, [$($rest),*], $renamed, $items);
+    };
+    (@find $name:ident, [prefixed $(, $rest:tt)*], $renamed:tt, $items:tt) => {};
+    (@find $name:ident, [$other:ident $(, $rest:tt)*], $renamed:tt, $items:tt) => {
+        $crate::__alias_same_method!($name, $other, {}, {
+            $crate::__alias_skip!(@find $name, [$($rest),*], $renamed, $items);
+        });
+    };
+    (@find $name:ident, [$other:tt $(, $rest:tt)*], $renamed:tt, $items:tt) => {
+        $crate::__alias_skip!(@find $name, [$($rest),*], $renamed, $items);
+    };
+    (@find $name:ident, [], $renamed:tt, $items:tt) => {
+        $crate::__alias_rename!($renamed, $items);
+    };
+    (@tested $callback:ident [$($before:tt)*], [$($skipped:tt),*], [$($after:tt)*]) => {
+        $crate::__alias_skip!(@tested $callback [$($before)*], [], [$($skipped),*], [$($after)*]);
+    };
+    (@tested $callback:ident $before:tt, [$($done:ident),*], [($old:ident => $new:ident) $(, $rest:tt)*], $after:tt) => {
+        $crate::__alias_skip!(@tested $callback $before, [$($done,)* $old], [$($rest),*], $after);
+    };
+    (@tested $callback:ident $before:tt, [$($done:ident),*], [(prefix $prefix:ident) $(, $rest:tt)*], $after:tt) => {
+        $crate::__alias_skip!(@tested $callback $before, [$($done,)* prefixed], [$($rest),*], $after);
+    };
+    (@tested $callback:ident $before:tt, [$($done:ident),*], [$other:ident $(, $rest:tt)*], $after:tt) => {
+        $crate::__alias_skip!(@tested $callback $before, [$($done,)* $other], [$($rest),*], $after);
+    };
+    (@tested $callback:ident $before:tt, $done:tt, [$other:tt $(, $rest:tt)*], $after:tt) => {
+        $crate::__alias_skip!(@tested $callback $before, $done, [$($rest),*], $after);
+    };
+    (@tested $callback:ident [$($before:tt)*], $done:tt, [], [$($after:tt)*]) => {
+        $crate::$callback!($($before)*, $done, $($after)*);
+    };
+}
+
+/// Emits the first group of items if both method names are the same, and the second one otherwise.
+///
+/// Identifiers can only be compared against literals, so each method that can be skipped or
+/// renamed has its own arm below. Comparing a name with itself checks that it is in the table.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_same_method {
+    (and, and, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (no_panics, no_panics, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (forbid_unsafe, forbid_unsafe, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (and_then, and_then, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (as_deref, as_deref, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (as_deref_mut, as_deref_mut, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (as_mut, as_mut, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (as_pin_mut, as_pin_mut, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (as_pin_ref, as_pin_ref, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (as_ref, as_ref, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (cloned, cloned, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (copied, copied, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (either, either, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (either_into, either_into, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (either_with, either_with, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (expect, expect, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (factor_err, factor_err, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (factor_none, factor_none, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (factor_ok, factor_ok, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (flatten, flatten, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (flip, flip, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (from_option_or, from_option_or, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (from_option_or_else, from_option_or_else, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (inspect, inspect, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (into_inner, into_inner, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (into_result, into_result, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (iter, iter, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (iter_mut, iter_mut, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (map, map, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (map_either, map_either, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (map_either_with, map_either_with, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (map_err, map_err, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (map_or, map_or, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (map_or_else, map_or_else, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (or, or, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (or_else, or_else, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (transpose, transpose, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (unwrap, unwrap, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (unwrap_or, unwrap_or, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (unwrap_or_default, unwrap_or_default, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (unwrap_or_else, unwrap_or_else, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    ($name:ident, $other:ident, $then:tt, { $($else:tt)* }) => {
+        $($else)*
+    };
+}
+
+/// Emits an item under the name given with `rename:` or `method_prefix:`.
+///
+/// The name is the single identifier after the first top level `fn`. When the item is wrapped in
+/// `__alias_must_use!`, the new name is passed on to it, so the attribute stays on the method. An
+/// empty name emits the item unchanged.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_rename {
+    ([], { $($item:tt)* }) => {
+        $($item)*
+    };
+    ([$($name:tt)+], { $($item:tt)* }) => {
+        $crate::__alias_rename!(@munch [$($name)+], [], $($item)*);
+    };
+    (@munch [$($name:tt)+], [$($done:tt)*], fn $old:ident $($rest:tt)*) => {
+        paste::paste! {
+            $($done)* fn [<$($name)+>] $($rest)*
+        }
+    };
+    (@munch $name:tt, [$($done:tt)*], $krate:tt :: __alias_must_use ! ( $($args:tt)* ); $($rest:tt)*) => {
+        $($done)*
+        $krate::__alias_must_use!(@as $name $($args)*);
+        $($rest)*
+    };
+    (@munch $name:tt, [$($done:tt)*], $next:tt $($rest:tt)*) => {
+        $crate::__alias_rename!(@munch $name, [$($done)* $next], $($rest)*);
+    };
+    (@munch $name:tt, [$($done:tt)*],) => {
+        $($done)*
+    };
+}
+
+/// Emits a method named after a variant alone, renamed with a trailing underscore if the name is
+/// a keyword.
+///
+/// Methods like `ok` or `left` take the snake case variant name, which is a keyword for variants
+/// like `Loop` or `r#type`, and can not be generated as a raw identifier. The `@if` form emits the
+/// first group of items for a keyword and the second one otherwise. Each keyword has its own arm
+/// below.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_keyword {
+    (@if abstract, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if as, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if async, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if await, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if become, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if box, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if break, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if const, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if continue, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if crate, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if do, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if dyn, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if else, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if enum, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if extern, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if false, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if final, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if fn, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if for, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if gen, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if if, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if impl, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if in, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if let, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if loop, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if macro, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if match, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if mod, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if move, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if mut, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if override, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if priv, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if pub, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if ref, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if return, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if self, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if static, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if struct, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if super, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if trait, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if true, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if try, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if type, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if typeof, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if unsafe, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if unsized, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if use, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if virtual, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if where, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if while, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if yield, { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@if $name:tt, $then:tt, { $($else:tt)* }) => {
+        $($else)*
+    };
+    ($name:tt, $items:tt) => {
+        $crate::__alias_keyword!(@if $name, {
+            $crate::__alias_rename!([$name _], $items);
+        }, $items);
+    };
+}
+
+/// Emits an item whose name is derived from a variant, with a `doc(alias)` to the std name.
+///
+/// When the variant has the std name the item already has the aliased name, which rustdoc
+/// rejects, so the alias is left out. Each std variant has its own arm below.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_doc_alias {
+    (Some, Some, $alias:literal, { $($item:tt)* }) => {
+        $($item)*
+    };
+    (None, None, $alias:literal, { $($item:tt)* }) => {
+        $($item)*
+    };
+    (Ok, Ok, $alias:literal, { $($item:tt)* }) => {
+        $($item)*
+    };
+    (Err, Err, $alias:literal, { $($item:tt)* }) => {
+        $($item)*
+    };
+    (Left, Left, $alias:literal, { $($item:tt)* }) => {
+        $($item)*
+    };
+    (Right, Right, $alias:literal, { $($item:tt)* }) => {
+        $($item)*
+    };
+    ($variant:ident, $std:ident, $alias:literal, { $($item:tt)* }) => {
+        #[doc(alias = $alias)]
+        $($item)*
+    };
+}
+
+/// Emits items only when no `bounds:` were given.
+///
+/// `generate_tests` uses it for checks that instantiate the type with references, collections or
+/// nested aliases, which the caller's bounds may not admit.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_unbounded {
+    ([], { $($item:tt)* }) => {
+        $($item)*
+    };
+    ([$($bound:tt)+], $items:tt) => {};
+}
+
+/// Emits the first group of items if the given flag was passed, and the second one otherwise.
+///
+/// Flags are plain identifiers, so each flag that is queried has its own arm below.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_if_flag {
+    ($flag:ident, [$($flags:ident),*], $then:tt, $else:tt) => {
+        $crate::__alias_if_flag!(@find $flag, [$($flags),*], $then, $else);
+    };
+    (@find pin_project, [pin_project $(, $rest:ident)*], { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@find $flag:ident, [$other:ident $(, $rest:ident)*], $then:tt, $else:tt) => {
+        $crate::__alias_if_flag!(@find $flag, [$($rest),*], $then, $else);
+    };
+    (@find $flag:ident, [], $then:tt, { $($else:tt)* }) => {
+        $($else)*
+    };
+}
+
+/// Emits the impls of an alias macro inside a `#[forbid(unsafe_code)]` const block if the
+/// `forbid_unsafe` flag was given, so the compiler checks that they contain no `unsafe` code.
+///
+/// The flag is passed along as a skipped name, like `no_panics`, and the items that need `unsafe`
+/// are left out with `__alias_skip!(forbid_unsafe, ...)`. The impls stay in effect outside the
+/// const block, while the type definition and the `generate_tests` module are emitted outside of
+/// it. With `@unsafe`, the items of an opt-in flag that needs `unsafe` are emitted, or a
+/// `compile_error!` if `forbid_unsafe` was given too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_forbid_unsafe {
+    (@unsafe $flag:ident, [$($skip:tt),*], $items:tt) => {
+        $crate::__alias_forbid_unsafe!(@find [$($skip),*], {
+            compile_error!(concat!("`", stringify!($flag), "` generates `unsafe` code, and can not be combined with `forbid_unsafe`"));
+        }, $items);
+    };
+    ([$($skip:tt),*], { $($item:tt)* }) => {
+        $crate::__alias_forbid_unsafe!(@find [$($skip),*], {
+            #[forbid(unsafe_code)]
+            const _: () = {
+                $($item)*
+            };
+        }, {
+            $($item)*
+        });
+    };
+    (@find [forbid_unsafe $(, $rest:tt)*], { $($then:tt)* }, $else:tt) => {
+        $($then)*
+    };
+    (@find [$other:tt $(, $rest:tt)*], $then:tt, $else:tt) => {
+        $crate::__alias_forbid_unsafe!(@find [$($rest),*], $then, $else);
+    };
+    (@find [], $then:tt, { $($else:tt)* }) => {
+        $($else)*
+    };
+}
+
+/// Emits an item with a `#[must_use]` attribute, unless the `no_must_use` flag was given.
+///
+/// The flag is passed along as a skipped name, like `no_panics`. The message is optional. A method
+/// renamed by `__alias_skip!` arrives with `@as` and its new name.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_must_use {
+    (@as $name:tt [$($skip:tt),*], { $($item:tt)* }) => {
+        $crate::__alias_must_use!(@find [$($skip),*], [], $name, { $($item)* });
+    };
+    (@as $name:tt [$($skip:tt),*], $message:expr, { $($item:tt)* }) => {
+        $crate::__alias_must_use!(@find [$($skip),*], [= $message], $name, { $($item)* });
+    };
+    ([$($skip:tt),*], $($args:tt)*) => {
+        $crate::__alias_must_use!(@as [] [$($skip),*], $($args)*);
+    };
+    (@find [no_must_use $(, $rest:tt)*], $message:tt, $name:tt, $items:tt) => {
+        $crate::__alias_rename!($name, $items);
+    };
+    (@find [$other:tt $(, $rest:tt)*], $message:tt, $name:tt, $items:tt) => {
+        $crate::__alias_must_use!(@find [$($rest),*], $message, $name, $items);
+    };
+    (@find [], [$($message:tt)*], $name:tt, { $($item:tt)* }) => {
+        $crate::__alias_rename!($name, {
+            #[must_use $($message)*]
+            $($item)*
+        });
+    };
+}
+
+/// Emits const assertions that each generated type has the size of its std counterpart.
+///
+/// The second list holds probes that the caller's bounds may not admit, and is only checked when
+/// no `bounds:` were given.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_assert_same_size {
+    ($bounds:tt, [$($alias:ty => $std:ty),*], []) => {
+        $(
+            const _: () = assert!(
+                ::core::mem::size_of::<$alias>() == ::core::mem::size_of::<$std>(),
+                concat!("`", stringify!($alias), "` does not have the size of `", stringify!($std), "`"),
+            );
+        )*
+    };
+    ($bounds:tt, $probes:tt, [$($unbounded_alias:ty => $unbounded_std:ty),+]) => {
+        $crate::__alias_assert_same_size!($bounds, $probes, []);
+        $crate::__alias_unbounded!($bounds, {
+            $crate::__alias_assert_same_size!([], [$($unbounded_alias => $unbounded_std),+], []);
+        });
+    };
+}
+
+/// Invocations the options parser rejects, and uses of the generated types that the options rule
+/// out. They fail to build, so they are checked as `compile_fail` doctests instead of in the tests
+/// below.
+///
+/// `define!` only accepts the variant names of the std type it is like.
+///
+/// ```compile_fail
+/// enumizer::define! {
+///     enum Response like Result { Ok => Success, Some => Failure }
+/// }
+/// ```
+///
+/// The bounds given with `bounds:` are carried by the type itself.
+///
+/// ```compile_fail
+/// use enumizer::alias_option;
+///
+/// alias_option!(Job, Queued, Idle, bounds: [Send + 'static]);
+/// let job: Job<std::rc::Rc<u8>> = Job::Idle;
+/// ```
+///
+/// `assert_same_size` fails the build when an attribute changes the layout of the type.
+///
+/// ```compile_fail
+/// use enumizer::alias_option;
+///
+/// alias_option!(Slot, Filled, Empty, attrs: [#[repr(u8)]], assert_same_size);
+/// ```
+///
+/// The method groups that are not listed in `methods:` are left out.
+///
+/// ```compile_fail
+/// use enumizer::alias_result;
+///
+/// alias_result!(Status, Up, Down, methods: [core]);
+/// let status: Status<u8, ()> = Status::Up(1);
+/// status.map(u16::from);
+/// ```
+///
+/// `skip:` only takes the names of generated methods.
+///
+/// ```compile_fail
+/// use enumizer::alias_option;
+///
+/// alias_option!(Tag, Named, Anonymous, skip: [mapp]);
+/// ```
+///
+/// Without `no_must_use`, the pure methods are `#[must_use]`.
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use enumizer::alias_result;
+///
+/// alias_result!(Checked, Passed, Failed);
+/// let checked: Checked<u8, ()> = Checked::Passed(1);
+/// checked.is_passed();
+/// ```
+///
+/// `no_panics` leaves out the methods that can panic.
+///
+/// ```compile_fail
+/// use enumizer::alias_result;
+///
+/// alias_result!(Checked, Passed, Failed, no_panics);
+/// let checked: Checked<u8, ()> = Checked::Passed(1);
+/// checked.unwrap();
+/// ```
+///
+/// `serde_attrs:` only takes the variant names of the generated type.
+///
+/// ```compile_fail
+/// use enumizer::alias_result;
+///
+/// alias_result!(Reply, Success, Failure, traits_add: [serde::Serialize], serde_attrs: [
+///     Ok => #[serde(rename = "ok")],
+/// ]);
+/// ```
+///
+/// The variant names must differ, also after a method suffix like the `mut` of `as_found_mut` is
+/// appended to the first one.
+///
+/// ```compile_fail
+/// use enumizer::alias_either;
+///
+/// alias_either!(Route, Local, Local);
+/// ```
+///
+/// ```compile_fail
+/// use enumizer::alias_result;
+///
+/// alias_result!(Lookup, Found, FoundMut);
+/// ```
+///
+/// Flags the alias macro does not know are reported instead of ignored.
+///
+/// ```compile_fail
+/// use enumizer::alias_option;
+///
+/// alias_option!(Slot, Filled, Empty, implement_iterator);
+/// ```
+#[cfg(doctest)]
+struct RejectedOptions;
+
+#[cfg(test)]
+mod tests {
+    mod scoped {
+        crate::alias_option!(Hidden, Here, Gone, vis: private);
+        crate::alias_result!(Internal, Fine, Broken, vis: pub(super), traits: [Debug, PartialEq]);
+
+        pub(super) fn lookup(key: u8) -> Internal<u8, ()> {
+            let hidden = if key > 0 {
+                Hidden::Here(key)
+            } else {
+                Hidden::Gone
+            };
+            Option::from(hidden).ok_or(()).into()
+        }
+    }
+
+    #[test]
+    fn visibility() {
+        assert_eq!(scoped::lookup(1), scoped::Internal::Fine(1));
+        assert!(scoped::lookup(0).is_broken());
+    }
+
+    #[test]
+    fn traits_add() {
+        use std::collections::HashSet;
+
+        crate::alias_poll!(Progress, Done, Working, traits: [Debug, PartialEq], traits_add: [Eq, Hash]);
+        crate::alias_poll!(Staged, Ready, Queued, traits_add: [serde::Serialize]);
+
+        let set: HashSet<Progress<u8>> = [Progress::Done(1), Progress::Done(1)].into();
+        assert_eq!(set.len(), 1);
+        let staged = Staged::Ready(1);
+        assert!(staged < Staged::Queued);
+        assert_eq!(serde_json::to_string(&staged).unwrap(), r#"{"Ready":1}"#);
+    }
+
+    #[test]
+    fn generics() {
+        crate::alias_control_flow!(Step, Next, Stop, generics: [Output, State]);
+        crate::alias_cow!(Text, Shared, Owned, generics: [Content]);
+
+        let step: Step<&str, u8> = Step::Next(1);
+        assert_eq!(step.next_value(), Some(1));
+        let text: Text<str> = Text::Shared("shared");
+        assert_eq!(&*text, "shared");
+    }
+
+    #[test]
+    fn bounds() {
+        crate::alias_result!(Outcome, Passed, Failed, bounds: [Send + 'static]);
+        crate::alias_either!(Route, Local, Remote, bounds: [Send + Sync]);
+
+        let outcome: Outcome<u8, String> = Outcome::Passed(1);
+        let handle = std::thread::spawn(move || outcome.map(u16::from));
+        assert_eq!(handle.join().unwrap(), Outcome::Passed(1));
+        let route: Route<u8, &str> = Route::Remote("host");
+        assert_eq!(route.as_ref().copied(), Route::Remote("host"));
+    }
+
+    #[test]
+    fn methods() {
+        crate::alias_either!(Source, Memory, Disk, methods: [iterators]);
+        crate::alias_option!(Cached, Hit, Miss, methods: [core]);
+
+        let source: Source<std::ops::Range<u8>, std::vec::IntoIter<u8>> = Source::Memory(0..3);
+        assert_eq!(source.collect::<Vec<_>>(), vec![0, 1, 2]);
+        let cached: Cached<u8> = Some(1).into();
+        assert_eq!(cached.unwrap_or(0), 1);
+    }
+
+    #[test]
+    fn skip() {
+        crate::alias_result!(Fetch, Fetched, Missing, skip: [map, iter]);
+
+        trait Count {
+            fn iter(&self) -> usize;
+        }
+        impl Count for Fetch<Vec<u8>, ()> {
+            fn iter(&self) -> usize {
+                self.as_fetched().map_or(0, Vec::len)
+            }
+        }
+
+        let fetch: Fetch<Vec<u8>, ()> = Fetch::Fetched(vec![1, 2]);
+        assert_eq!(fetch.iter(), 2);
+        assert_eq!((&fetch).into_iter().count(), 1);
+    }
+
+    #[test]
+    fn no_panics() {
+        crate::alias_either!(Branch, Taken, Skipped, no_panics, traits: [Debug, PartialEq]);
+
+        trait UnwrapTaken {
+            fn unwrap_taken(self) -> u8;
+        }
+        impl UnwrapTaken for Branch<u8, ()> {
+            fn unwrap_taken(self) -> u8 {
+                self.taken_or(0)
+            }
+        }
+
+        assert_eq!(Branch::Taken(3).unwrap_taken(), 3);
+        assert_eq!(Branch::Skipped(()).unwrap_taken(), 0);
+    }
+
+    #[test]
+    fn module() {
+        crate::alias_poll!(Fetch, Fetched, Fetching, vis: private, module: fetch);
+        crate::alias_tristate!(Vote, Yes, No, Abstain, module: votes [Vote]);
+
+        let fetch: Fetch<u8> = fetch::Fetch::Fetched(1);
+        assert!(fetch.is_fetched());
+        assert_eq!(Vote::from(Some(true)), votes::Vote::Yes);
+
+        crate::alias_either!(Text, Inline, Shared, boxed: BoxedText, borrowed: TextRef, vis: private, module: texts);
+        let boxed: BoxedText<str, str> = Text::Inline("inline".into());
+        let borrowed: TextRef<'_, str, str> = texts::TextRef::Shared("shared");
+        assert!(boxed.is_inline() && borrowed.is_shared());
+    }
+
+    #[test]
+    fn rename() {
+        crate::alias_option!(Slot, Filled, Empty, rename: [unwrap => into_value, map => map_payload]);
+        crate::alias_result!(Reply, Sent, Dropped, method_prefix: reply_, rename: [map => map_sent]);
+        crate::alias_either!(Side, Near, Far, method_prefix: side_, skip: [flip]);
+
+        trait Payload {
+            fn map(self) -> u8;
+        }
+        impl Payload for Slot<u8> {
+            fn map(self) -> u8 {
+                self.unwrap_or(0)
+            }
+        }
+
+        assert_eq!(Slot::Filled(1).map_payload(|v| v + 1).into_value(), 2);
+        assert_eq!(Slot::Filled(3).map(), 3);
+        let reply: Reply<u8, ()> = Reply::Sent(1);
+        assert_eq!(reply.map_sent(u16::from).reply_unwrap(), 1);
+        assert_eq!(reply.reply_map_err(|()| 0).reply_unwrap_or(0), 1);
+        assert!(reply.is_sent());
+        let side: Side<u8, u8> = Side::Near(1);
+        assert_eq!(side.side_map_either(|v| v + 1, |v| v), Side::Near(2));
+        assert_eq!(side.near(), Some(1));
+    }
+
+    mod conformance {
+        crate::alias_option!(Reading, Measured, Unmeasured, generate_tests);
+        crate::alias_option!(Lease, Held, Released, bounds: [Copy], skip: [map], generate_tests);
+        crate::alias_result!(Lookup, Found, Failed, generate_tests);
+        crate::alias_result!(Parsed, Valid, Invalid, methods: [core], no_panics, generate_tests);
+        crate::alias_either!(Route, Local, Remote, generate_tests);
+        crate::alias_either!(Branch, Taken, Skipped, left_is_ok, bounds: [Copy], generate_tests);
+        crate::alias_poll!(Fetch, Fetched, Fetching, generate_tests);
+        crate::alias_control_flow!(Visit, Descend, Stop, generate_tests);
+        crate::alias_option!(Mark, Set, Unset, rename: [map => map_set], generate_tests);
+        crate::alias_result!(Check, Passed, Rejected, method_prefix: check_, generate_tests);
+        crate::alias_either!(Pick, First, Second, method_prefix: pick_, rename: [flip => swap], generate_tests);
+        crate::alias_result!(Iteration, Loop, Break, generate_tests);
+        crate::alias_either!(Task, Running, Queued, forbid_unsafe, generate_tests);
+        crate::alias_either!(Job, Pending, Done, pin_project, generate_tests);
+        crate::alias_result!(Audit, Clean, Flagged, forbid_unsafe, method_prefix: audit_, generate_tests);
+    }
+
+    #[test]
+    fn assert_same_size() {
+        crate::alias_option!(Cached, Hit, Miss, assert_same_size);
+        crate::alias_result!(Sent, Delivered, Bounced, bounds: [Copy], assert_same_size);
+        crate::alias_either!(Source, Inline, External, left_is_ok, assert_same_size);
+        crate::alias_poll!(Load, Loaded, Loading, assert_same_size);
+        crate::alias_control_flow!(Walk, Enter, Leave, assert_same_size);
+
+        assert!(Cached::Hit(1).is_hit());
+        assert!(Load::Loaded(1).is_loaded());
+    }
+
+    #[test]
+    #[deny(unused_must_use)]
+    fn no_must_use() {
+        crate::alias_result!(Ack, Received, Lost, no_must_use);
+
+        fn lose() -> Ack<u8, ()> {
+            Ack::Lost(())
+        }
+
+        let ack: Ack<u8, ()> = Ack::Received(1);
+        ack.is_received();
+        ack.map(u16::from);
+        lose();
+    }
+
+    #[test]
+    fn serde_attrs() {
+        crate::alias_result!(Reply, Success, Failure, serde_attrs: [Success => #[serde(rename = "ok")], Failure => #[serde(rename = "error")]], traits_add: [serde::Serialize, serde::Deserialize]);
+        crate::alias_option!(Field, Set, Unset, none_last, serde_attrs: [Unset => #[serde(alias = "missing")] #[serde(rename = "none")]], traits: [Debug, PartialEq, serde::Serialize, serde::Deserialize]);
+        crate::alias_either!(Value, Number, Text, serde_attrs: [Text => #[serde(rename = "text")]], traits: [Debug, PartialEq, serde::Serialize]);
+
+        let reply: Reply<u8, String> = Reply::Success(1);
+        assert_eq!(serde_json::to_string(&reply).unwrap(), r#"{"ok":1}"#);
+        let failed: Reply<u8, String> = serde_json::from_str(r#"{"error":"lost"}"#).unwrap();
+        assert_eq!(failed, Reply::Failure("lost".to_string()));
+        assert_eq!(
+            serde_json::to_string(&Field::<u8>::Unset).unwrap(),
+            r#""none""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Field<u8>>(r#""missing""#).unwrap(),
+            Field::Unset
+        );
+        let text: Value<u8, &str> = Value::Text("a");
+        assert_eq!(serde_json::to_string(&text).unwrap(), r#"{"text":"a"}"#);
+    }
+
+    #[test]
+    fn keyword_variant_names() {
+        crate::alias_result!(Iteration, Loop, Break);
+        crate::alias_either!(Token, r#Type, Match);
+        crate::alias_option!(Step, r#Move, Stay);
+
+        let iteration: Iteration<u8, ()> = Iteration::Loop(1);
+        assert_eq!(iteration.loop_(), Some(1));
+        assert_eq!(iteration.break_(), None);
+        let token: Token<u8, &str> = Token::Match("m");
+        assert_eq!(token.type_(), None);
+        assert_eq!(token.match_(), Some("m"));
+        assert!(token.is_match());
+        assert!(Step::r#Move(1).is_move());
+    }
+
+    #[test]
+    fn std_variant_names() {
+        crate::alias_option!(Maybe, Some, None);
+        crate::alias_result!(Fallible, Ok, Err);
+        crate::alias_either!(Split, Left, Right);
+
+        assert!(Maybe::Some(1).is_some());
+        assert!(Fallible::<u8, ()>::Err(()).is_err());
+        assert_eq!(Split::<u8, ()>::Left(1).left(), Some(1));
+    }
+
+    #[test]
+    fn unsized_payloads() {
+        crate::alias_option!(Handler, Set, Unset, boxed: BoxedHandler, vis: private);
+        crate::alias_result!(Parsed, Valid, Invalid, boxed: BoxedParsed, borrowed: ParsedRef);
+        crate::alias_either!(Text, Inline, Shared, borrowed: TextRef, traits: [Debug, PartialEq]);
+
+        let handler: BoxedHandler<dyn Fn(u8) -> u8> = Handler::Set(Box::new(|v| v + 1));
+        assert_eq!(handler.as_deref().map(|f| f(1)).unwrap(), 2);
+        let parsed: BoxedParsed<str, dyn std::error::Error> = Parsed::Invalid("bad".into());
+        assert!(parsed.is_invalid());
+        let borrowed: ParsedRef<str, [u8]> = Parsed::Valid("fine");
+        assert_eq!(borrowed.valid(), Some("fine"));
+        let text: TextRef<str, str> = Text::Shared("shared");
+        assert_eq!(text, Text::Shared("shared"));
+        let owned: Text<String, Box<str>> = Text::Inline("inline".to_string());
+        assert_eq!(owned.as_deref(), Text::Inline("inline"));
+        assert!(Handler::Set("set".to_string()).set_eq("set"));
+    }
+
+    #[test]
+    fn forbid_unsafe() {
+        crate::alias_either!(Task, Running, Queued, forbid_unsafe, traits: [Debug, PartialEq]);
+        crate::alias_option!(Slot, Filled, Empty, forbid_unsafe);
+
+        let mut task: Task<u8, ()> = Task::Running(1);
+        assert_eq!(
+            std::pin::Pin::new(&task).as_pin_ref().running().as_deref(),
+            Some(&1)
+        );
+        *std::pin::Pin::new(&mut task)
+            .as_pin_mut()
+            .running()
+            .unwrap() += 1;
+        assert_eq!(task.as_ref().copied(), Task::Running(2));
+        assert!(Slot::Filled(1).is_filled());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_std() {
+        crate::alias_option!(Nickname, Given, Unset, serde: std);
+        crate::alias_result!(Reply, Delivered, Bounced, serde: std, traits: [Debug, PartialEq]);
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct User {
+            nickname: Nickname<String>,
+            last_reply: Reply<u8, String>,
+        }
+
+        let user = User {
+            nickname: Nickname::Given("ferris".to_string()),
+            last_reply: Reply::Bounced("full".to_string()),
+        };
+        let json = serde_json::to_string(&user).unwrap();
+        assert_eq!(json, r#"{"nickname":"ferris","last_reply":{"Err":"full"}}"#);
+
+        let user: User =
+            serde_json::from_str(r#"{"nickname":null,"last_reply":{"Ok":1}}"#).unwrap();
+        assert_eq!(user.nickname, Nickname::Unset);
+        assert_eq!(user.last_reply, Reply::Delivered(1));
+        assert_eq!(
+            serde_json::to_string(&Nickname::<u8>::Unset).unwrap(),
+            "null"
+        );
+    }
+
+    #[test]
+    fn serde_tagging() {
+        crate::alias_result!(Value, Number, Text, serde: untagged, traits_add: [serde::Serialize, serde::Deserialize]);
+        crate::alias_either!(Event, Click, Key, serde: [tag = "kind", content = "data"], traits: [Debug, PartialEq, serde::Serialize, serde::Deserialize]);
+        crate::alias_poll!(Job, Done, Running, serde: [tag = "state"], traits: [Debug, PartialEq, serde::Serialize, serde::Deserialize]);
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Summary {
+            count: u8,
+        }
+
+        assert_eq!(
+            serde_json::to_string(&Value::<u8, String>::Number(1)).unwrap(),
+            "1"
+        );
+        assert_eq!(
+            serde_json::from_str::<Value<u8, String>>(r#""one""#).unwrap(),
+            Value::Text("one".to_string())
+        );
+        let event: Event<(u8, u8), char> = Event::Key('a');
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"kind":"Key","data":"a"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Event<(u8, u8), char>>(r#"{"kind":"Click","data":[1,2]}"#)
+                .unwrap(),
+            Event::Click((1, 2))
+        );
+        let job = Job::Done(Summary { count: 2 });
+        assert_eq!(
+            serde_json::to_string(&job).unwrap(),
+            r#"{"state":"Done","count":2}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Job<Summary>>(r#"{"state":"Running"}"#).unwrap(),
+            Job::Running
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    mod frozen_schema {
+        crate::alias_option!(Nickname, Given, Unset, serde_as_std, vis: private, traits_add: [serde::Serialize]);
+        crate::alias_result!(Reply, Delivered, Bounced, serde_as_std, vis: pub(super));
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub(super) struct User {
+            #[serde(with = "nickname_as_std")]
+            nickname: Nickname<String>,
+            #[serde(with = "reply_as_std")]
+            pub(super) last_reply: Reply<u8, String>,
+        }
+
+        #[test]
+        fn serde_as_std() {
+            let user = User {
+                nickname: Nickname::Given("ferris".to_string()),
+                last_reply: Reply::Bounced("full".to_string()),
+            };
+            assert_eq!(
+                serde_json::to_string(&user).unwrap(),
+                r#"{"nickname":"ferris","last_reply":{"Err":"full"}}"#
+            );
+            assert_eq!(
+                serde_json::to_string(&user.nickname).unwrap(),
+                r#"{"Given":"ferris"}"#
+            );
+
+            let user: User =
+                serde_json::from_str(r#"{"nickname":null,"last_reply":{"Ok":1}}"#).unwrap();
+            assert!(user.nickname.is_unset());
+            assert_eq!(user.last_reply, Reply::Delivered(1));
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        crate::alias_option!(Nickname, Given, Unset, json_schema);
+        crate::alias_result!(Reply, Delivered, Bounced, serde: std, json_schema);
+        crate::alias_either!(Route, Local, Remote, json_schema);
+
+        let nickname = schemars::schema_for!(Nickname<String>);
+        assert_eq!(nickname.get("title").unwrap(), "Nickname_for_string");
+        assert_eq!(
+            nickname.get("oneOf").unwrap(),
+            &serde_json::json!([
+                {"type": "object", "properties": {"Given": {"type": "string"}}, "required": ["Given"], "additionalProperties": false},
+                {"type": "string", "const": "Unset"},
+            ])
+        );
+
+        let reply = schemars::schema_for!(Reply<bool, String>);
+        assert_eq!(
+            reply.get("oneOf"),
+            schemars::schema_for!(Result<bool, String>).get("oneOf")
+        );
+        let route = schemars::schema_for!(Route<bool, String>);
+        assert_eq!(
+            route.get("oneOf").unwrap()[1]["required"],
+            serde_json::json!(["Remote"])
+        );
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn to_schema() {
+        use utoipa::{PartialSchema, ToSchema};
+
+        crate::alias_option!(Nickname, Given, Unset, to_schema);
+        crate::alias_result!(Reply, Delivered, Bounced, serde: std, to_schema);
+        crate::alias_either!(Route, Local, Remote, to_schema);
+
+        assert_eq!(Nickname::<String>::name(), "Nickname_String");
+        assert_eq!(Route::<bool, String>::name(), "Route_bool_String");
+        assert_eq!(
+            serde_json::to_value(Nickname::<String>::schema()).unwrap(),
+            serde_json::json!({"oneOf": [
+                {"type": "object", "properties": {"Given": {"type": "string"}}, "required": ["Given"]},
+                {"type": "string", "enum": ["Unset"]},
+            ]})
+        );
+        let reply = serde_json::to_value(Reply::<bool, String>::schema()).unwrap();
+        assert_eq!(
+            reply["oneOf"][1]["properties"]["Err"],
+            serde_json::json!({"type": "string"})
+        );
+    }
+
+    #[cfg(feature = "ts-rs")]
+    #[test]
+    fn ts() {
+        use ts_rs::TS;
+
+        crate::alias_option!(Nickname, Given, Unset, generics: [Name], ts);
+        crate::alias_result!(Reply, Delivered, Bounced, serde: std, ts);
+        crate::alias_either!(Route, Local, Remote, ts);
+
+        assert_eq!(Nickname::<String>::name(), "Nickname<string>");
+        assert_eq!(
+            Nickname::<String>::inline(),
+            r#"{ "Given": string } | "Unset""#
+        );
+        assert_eq!(
+            Nickname::<String>::decl(),
+            r#"type Nickname<Name> = { "Given": Name } | "Unset";"#
+        );
+        assert_eq!(
+            Reply::<bool, String>::inline(),
+            r#"{ "Ok": boolean } | { "Err": string }"#
+        );
+        assert_eq!(
+            Route::<bool, u8>::decl_concrete(),
+            r#"type Route = { "Local": boolean } | { "Remote": number };"#
+        );
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn defmt() {
+        fn assert_format<T: defmt::Format>() {}
+
+        crate::alias_option!(Nickname, Given, Unset, defmt, traits: []);
+        crate::alias_result!(Reply, Delivered, Bounced, defmt, traits: []);
+        crate::alias_either!(Route, Local, Remote, defmt, traits: []);
+
+        assert_format::<Nickname<u8>>();
+        assert_format::<Reply<u8, bool>>();
+        assert_format::<Route<u8, Nickname<u8>>>();
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        crate::alias_option!(Nickname, Given, Unset, arbitrary);
+        crate::alias_result!(Reply, Delivered, Bounced, arbitrary);
+        crate::alias_either!(Route, Local, Remote, arbitrary);
+        crate::alias_these!(Sides, Left, Right, Both, arbitrary);
+        crate::alias_tristate!(Answer, Yes, No, Unknown, arbitrary);
+        crate::alias_oneof!(Shape, Circle(f32), Square(u8), arbitrary);
+
+        fn variants<T: for<'a> Arbitrary<'a>>() -> usize {
+            let values = (0..=u8::MAX)
+                .map(|first| T::arbitrary(&mut Unstructured::new(&[first, 1, 2, 3])).unwrap());
+            values
+                .map(|value| std::mem::discriminant(&value))
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        }
+
+        assert_eq!(variants::<Nickname<u8>>(), 2);
+        assert_eq!(variants::<Reply<u8, bool>>(), 2);
+        assert_eq!(variants::<Route<u8, bool>>(), 2);
+        assert_eq!(variants::<Sides<u8, bool>>(), 3);
+        assert_eq!(variants::<Answer>(), 3);
+        assert_eq!(variants::<Shape>(), 2);
+        let mut u = Unstructured::new(&[0, 7]);
+        assert_eq!(
+            Nickname::<u8>::arbitrary(&mut u).unwrap(),
+            Nickname::Given(7)
+        );
+    }
+
+    #[cfg(feature = "proptest")]
+    mod proptest_strategies {
+        use proptest::prelude::*;
+
+        crate::alias_option!(Nickname, Given, Unset, proptest);
+        crate::alias_result!(Reply, Delivered, Bounced, proptest);
+        crate::alias_these!(Sides, Left, Right, Both, proptest);
+        crate::alias_tristate!(Answer, Yes, No, Unknown, proptest);
+
+        proptest! {
+            #[test]
+            fn round_trip(nickname: Nickname<u8>, reply: Reply<u8, String>, sides: Sides<u8, bool>, answer: Answer) {
+                prop_assert_eq!(Nickname::from(Option::from(nickname)), nickname);
+                prop_assert_eq!(Reply::from(Result::from(reply.clone())), reply);
+                prop_assert_eq!(sides.has_left(), !matches!(sides, Sides::Right(_)));
+                prop_assert_eq!(Answer::from(Option::<bool>::from(answer)), answer);
+            }
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck() {
+        use quickcheck::Arbitrary;
+
+        crate::alias_option!(Nickname, Given, Unset, quickcheck);
+        crate::alias_result!(Reply, Delivered, Bounced, quickcheck);
+        crate::alias_these!(Sides, Left, Right, Both, quickcheck);
+        crate::alias_remote_data!(Fetch, Idle, Loading, Loaded, Errored, quickcheck);
+
+        let shrunk: Vec<_> = Nickname::Given(2u8).shrink().collect();
+        assert_eq!(
+            shrunk,
+            [Nickname::Unset, Nickname::Given(0), Nickname::Given(1)]
+        );
+        assert_eq!(Nickname::<u8>::Unset.shrink().count(), 0);
+        assert!(
+            Reply::<u8, u8>::Bounced(2)
+                .shrink()
+                .all(|reply| matches!(reply, Reply::Bounced(_)))
+        );
+        assert!(
+            Sides::Both(1u8, false)
+                .shrink()
+                .any(|sides| sides == Sides::Both(0, false))
+        );
+        assert_eq!(
+            Fetch::<u8, u8>::Loaded(1).shrink().collect::<Vec<_>>(),
+            [Fetch::Idle, Fetch::Loading, Fetch::Loaded(0)]
+        );
+
+        let mut g = quickcheck::Gen::new(8);
+        assert!((0..64).any(|_| Nickname::<u8>::arbitrary(&mut g) == Nickname::Unset));
+    }
+}
@@ -0,0 +1,185 @@
+/// Creates an Ordering-like enum with custom variant names.
+///
+/// See [`examples::OrderingExample`](crate::examples::OrderingExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_ordering;
+///
+/// alias_ordering!(Comparison, Before, Same, After);
+///
+/// let before = Comparison::Before;
+/// let same = Comparison::Same;
+///
+/// assert!(before.is_before());
+/// assert!(!before.is_after());
+/// assert!(same.is_same());
+/// assert!(Comparison::Before < Comparison::After);
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_ordering;
+/// alias_ordering!(Comparison, Before, Same, After);
+///
+/// assert_eq!(Comparison::Before.reverse(), Comparison::After);
+/// assert_eq!(Comparison::Same.reverse(), Comparison::Same);
+///
+/// assert_eq!(Comparison::Same.then(Comparison::After), Comparison::After);
+/// assert_eq!(Comparison::Before.then(Comparison::After), Comparison::Before);
+///
+/// let mut called = false;
+/// let result = Comparison::Before.then_with(|| {
+///     called = true;
+///     Comparison::After
+/// });
+/// assert_eq!(result, Comparison::Before);
+/// assert!(!called);
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be losslessly converted to and from `Ordering`.
+///
+/// ```
+/// use enumizer::alias_ordering;
+/// use std::cmp::Ordering;
+/// alias_ordering!(Comparison, Before, Same, After);
+///
+/// let from_ordering: Comparison = 1.cmp(&2).into();
+/// assert_eq!(from_ordering, Comparison::Before);
+///
+/// let to_ordering: Ordering = Comparison::After.into();
+/// assert_eq!(to_ordering, Ordering::Greater);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_ordering;
+/// alias_ordering!(CustomComparison, Lower, Equal, Higher, traits: [Debug, Clone, Copy]);
+/// let val = CustomComparison::Lower;
+/// assert_eq!(format!("{:?}", val.reverse()), "Higher");
+/// ```
+#[macro_export]
+macro_rules! alias_ordering {
+    ($type_name:ident, $less_variant:ident, $equal_variant:ident, $greater_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_ordering [$type_name, $less_variant, $equal_variant, $greater_variant] [] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $less_variant:ident, $equal_variant:ident, $greater_variant:ident], [], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [] [$type_name [$less_variant, $equal_variant, $greater_variant]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        #[repr(i8)]
+        $vis enum $type_name {
+            $less_variant = -1,
+            $equal_variant = 0,
+            $greater_variant = 1,
+        }
+
+        #[allow(dead_code)]
+        impl $type_name {
+            /// Behaves like [`Ordering::is_lt`](https://doc.rust-lang.org/std/cmp/enum.Ordering.html#method.is_lt)
+            $vis fn [<is_ $less_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$less_variant)
+            }
+
+            /// Behaves like [`Ordering::is_eq`](https://doc.rust-lang.org/std/cmp/enum.Ordering.html#method.is_eq)
+            $vis fn [<is_ $equal_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$equal_variant)
+            }
+
+            /// Behaves like [`Ordering::is_gt`](https://doc.rust-lang.org/std/cmp/enum.Ordering.html#method.is_gt)
+            $vis fn [<is_ $greater_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$greater_variant)
+            }
+
+            /// Behaves like [`Ordering::reverse`](https://doc.rust-lang.org/std/cmp/enum.Ordering.html#method.reverse)
+            $vis fn reverse(self) -> Self {
+                match self {
+                    $type_name::$less_variant => $type_name::$greater_variant,
+                    $type_name::$equal_variant => $type_name::$equal_variant,
+                    $type_name::$greater_variant => $type_name::$less_variant,
+                }
+            }
+
+            /// Behaves like [`Ordering::then`](https://doc.rust-lang.org/std/cmp/enum.Ordering.html#method.then)
+            $vis fn then(self, other: Self) -> Self {
+                match self {
+                    $type_name::$equal_variant => other,
+                    _ => self,
+                }
+            }
+
+            /// Behaves like [`Ordering::then_with`](https://doc.rust-lang.org/std/cmp/enum.Ordering.html#method.then_with)
+            $vis fn then_with<F: FnOnce() -> Self>(self, f: F) -> Self {
+                match self {
+                    $type_name::$equal_variant => f(),
+                    _ => self,
+                }
+            }
+        }
+
+        impl From<::core::cmp::Ordering> for $type_name {
+            fn from(ordering: ::core::cmp::Ordering) -> Self {
+                match ordering {
+                    ::core::cmp::Ordering::Less => $type_name::$less_variant,
+                    ::core::cmp::Ordering::Equal => $type_name::$equal_variant,
+                    ::core::cmp::Ordering::Greater => $type_name::$greater_variant,
+                }
+            }
+        }
+
+        impl From<$type_name> for ::core::cmp::Ordering {
+            fn from(val: $type_name) -> Self {
+                match val {
+                    $type_name::$less_variant => ::core::cmp::Ordering::Less,
+                    $type_name::$equal_variant => ::core::cmp::Ordering::Equal,
+                    $type_name::$greater_variant => ::core::cmp::Ordering::Greater,
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    alias_ordering!(Comparison, Before, Same, After);
+
+    const VALUES: [Ordering; 3] = [Ordering::Less, Ordering::Equal, Ordering::Greater];
+
+    #[test]
+    fn matches_ordering() {
+        for value in VALUES {
+            let alias = Comparison::from(value);
+            assert_eq!(Ordering::from(alias), value);
+            assert_eq!(alias as i8, value as i8);
+            assert_eq!(alias.is_before(), value.is_lt());
+            assert_eq!(alias.is_same(), value.is_eq());
+            assert_eq!(alias.is_after(), value.is_gt());
+            assert_eq!(Ordering::from(alias.reverse()), value.reverse());
+            for other in VALUES {
+                let then = alias.then(Comparison::from(other));
+                assert_eq!(Ordering::from(then), value.then(other));
+                let then_with = alias.then_with(|| Comparison::from(other));
+                assert_eq!(Ordering::from(then_with), value.then(other));
+            }
+        }
+    }
+
+    #[test]
+    fn sort_by() {
+        let mut words = ["bb", "a", "ccc"];
+        words.sort_by(|a, b| Comparison::from(b.len().cmp(&a.len())).reverse().into());
+        assert_eq!(words, ["a", "bb", "ccc"]);
+    }
+}
@@ -0,0 +1,309 @@
+/// Creates a Poll-like enum with custom variant names.
+///
+/// See [`examples::PollExample`](crate::examples::PollExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_poll;
+///
+/// alias_poll!(State, Done, Working);
+///
+/// let working: State<i32> = State::Working;
+/// let done = State::Done(42);
+///
+/// assert!(working.is_working());
+/// assert!(!working.is_done());
+/// assert!(done.is_done());
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_poll;
+/// alias_poll!(State, Done, Working);
+///
+/// let doubled = State::Done(10).map(|x| x * 2);
+/// assert_eq!(doubled, State::Done(20));
+/// assert_eq!(State::<i32>::Working.map(|x| x * 2), State::Working);
+///
+/// // Results inside the ready variant can be mapped directly
+/// let parsed: State<Result<i32, String>> = State::Done(Ok(5));
+/// assert_eq!(parsed.clone().map_ok(|x| x + 1), State::Done(Ok(6)));
+/// assert_eq!(parsed.map_err(|e| e.len()), State::Done(Ok(5)));
+///
+/// let failed: State<Result<i32, String>> = State::Done(Err("bad".to_string()));
+/// assert_eq!(failed.map_err(|e| e.len()), State::Done(Err(3)));
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `Poll<T>`.
+///
+/// ```
+/// use enumizer::alias_poll;
+/// use std::task::Poll;
+/// alias_poll!(State, Done, Working);
+/// let from_ready: State<i32> = Poll::Ready(42).into();
+/// let from_pending: State<i32> = Poll::Pending.into();
+///
+/// assert_eq!(from_ready, State::Done(42));
+/// assert_eq!(from_pending, State::Working);
+///
+/// let to_poll: Poll<i32> = State::Done(42).into();
+/// assert_eq!(to_poll, Poll::Ready(42));
+///
+/// // Like `Poll<T>`, a bare value converts into the ready variant.
+/// let from_value: State<i32> = 42.into();
+/// assert_eq!(from_value, State::Done(42));
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_poll;
+/// alias_poll!(CustomPoll, Ready, Busy, traits: [Debug, Clone]);
+/// let val = CustomPoll::Ready(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Ready(42)");
+/// ```
+///
+/// # Try Trait Support (Nightly Only)
+///
+/// Add `implement_try` to enable the `?` operator on a ready `Result`, like `Poll<Result<T, E>>`:
+/// an error returns early, while a ready value or the pending variant is passed through.
+/// Requires nightly Rust with `#![feature(try_trait_v2)]`.
+///
+/// ```ignore
+/// #![feature(try_trait_v2)]
+/// use enumizer::alias_poll;
+///
+/// alias_poll!(State, Done, Working, implement_try);
+///
+/// fn try_example(state: State<Result<i32, String>>) -> State<Result<i32, String>> {
+///     let inner: State<i32> = state?;
+///     inner.map(|x| Ok(x * 2))
+/// }
+///
+/// assert_eq!(try_example(State::Done(Ok(5))), State::Done(Ok(10)));
+/// assert_eq!(try_example(State::Working), State::Working);
+/// assert_eq!(try_example(State::Done(Err("error".into()))), State::Done(Err("error".into())));
+/// ```
+#[macro_export]
+macro_rules! alias_poll {
+    ($type_name:ident, $ready_variant:ident, $pending_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_poll [$type_name, $ready_variant, $pending_variant] [T] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $ready_variant:ident, $pending_variant:ident], [$T:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        $crate::__alias_impls!(variants [$T] [$type_name [$ready_variant($T), $pending_variant]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T> {
+            $ready_variant($T),
+            $pending_variant,
+        }
+
+        #[allow(dead_code)]
+        impl<T> $type_name<T> {
+            /// Behaves like [`Poll::is_ready`](https://doc.rust-lang.org/std/task/enum.Poll.html#method.is_ready)
+            $vis fn [<is_ $ready_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$ready_variant(_))
+            }
+
+            /// Behaves like [`Poll::is_pending`](https://doc.rust-lang.org/std/task/enum.Poll.html#method.is_pending)
+            $vis fn [<is_ $pending_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$pending_variant)
+            }
+
+            /// Behaves like [`Poll::map`](https://doc.rust-lang.org/std/task/enum.Poll.html#method.map)
+            $vis fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U> {
+                match self {
+                    $type_name::$ready_variant(v) => $type_name::$ready_variant(f(v)),
+                    $type_name::$pending_variant => $type_name::$pending_variant,
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T, E> $type_name<Result<T, E>> {
+            /// Behaves like [`Poll::map_ok`](https://doc.rust-lang.org/std/task/enum.Poll.html#method.map_ok)
+            $vis fn map_ok<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<Result<U, E>> {
+                match self {
+                    $type_name::$ready_variant(Ok(v)) => $type_name::$ready_variant(Ok(f(v))),
+                    $type_name::$ready_variant(Err(e)) => $type_name::$ready_variant(Err(e)),
+                    $type_name::$pending_variant => $type_name::$pending_variant,
+                }
+            }
+
+            /// Behaves like [`Poll::map_err`](https://doc.rust-lang.org/std/task/enum.Poll.html#method.map_err)
+            $vis fn map_err<U, F: FnOnce(E) -> U>(self, f: F) -> $type_name<Result<T, U>> {
+                match self {
+                    $type_name::$ready_variant(Ok(v)) => $type_name::$ready_variant(Ok(v)),
+                    $type_name::$ready_variant(Err(e)) => $type_name::$ready_variant(Err(f(e))),
+                    $type_name::$pending_variant => $type_name::$pending_variant,
+                }
+            }
+        }
+
+        impl<T> From<::core::task::Poll<T>> for $type_name<T> {
+            fn from(poll: ::core::task::Poll<T>) -> Self {
+                match poll {
+                    ::core::task::Poll::Ready(v) => $type_name::$ready_variant(v),
+                    ::core::task::Poll::Pending => $type_name::$pending_variant,
+                }
+            }
+        }
+
+        impl<T> From<$type_name<T>> for ::core::task::Poll<T> {
+            fn from(val: $type_name<T>) -> Self {
+                match val {
+                    $type_name::$ready_variant(v) => ::core::task::Poll::Ready(v),
+                    $type_name::$pending_variant => ::core::task::Poll::Pending,
+                }
+            }
+        }
+
+        impl<T> From<T> for $type_name<T> {
+            fn from(val: T) -> Self {
+                $type_name::$ready_variant(val)
+            }
+        }
+        }
+
+        $(
+            $crate::__alias_poll_flag!($flag, $type_name, $ready_variant, $pending_variant);
+        )*
+    };
+    (@impl $vis:vis [$($arg:tt)*], $generics:tt, [$($bound:tt)+], $($rest:tt)*) => {
+        compile_error!("`alias_poll!` does not accept the `bounds:` option");
+    };
+    (@impl $vis:vis [$($arg:tt)*], $generics:tt, $bounds:tt, [$($method:ident),+], $($rest:tt)*) => {
+        compile_error!("`alias_poll!` does not accept the `methods:` option");
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_poll_flag {
+    (implement_try, $type_name:ident, $ready_variant:ident, $pending_variant:ident) => {
+        impl<T, E> ::core::ops::Try for $type_name<Result<T, E>> {
+            type Output = $type_name<T>;
+            type Residual = Result<::core::convert::Infallible, E>;
+
+            fn from_output(output: Self::Output) -> Self {
+                output.map(Ok)
+            }
+
+            fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, Self::Output> {
+                match self {
+                    $type_name::$ready_variant(Ok(v)) => {
+                        ::core::ops::ControlFlow::Continue($type_name::$ready_variant(v))
+                    }
+                    $type_name::$ready_variant(Err(e)) => ::core::ops::ControlFlow::Break(Err(e)),
+                    $type_name::$pending_variant => {
+                        ::core::ops::ControlFlow::Continue($type_name::$pending_variant)
+                    }
+                }
+            }
+        }
+
+        impl<T, E, F: From<E>> ::core::ops::FromResidual<Result<::core::convert::Infallible, E>>
+            for $type_name<Result<T, F>>
+        {
+            fn from_residual(residual: Result<::core::convert::Infallible, E>) -> Self {
+                match residual {
+                    Ok(never) => match never {},
+                    Err(e) => $type_name::$ready_variant(Err(From::from(e))),
+                }
+            }
+        }
+    };
+    (assert_same_size, $type_name:ident, $ready_variant:ident, $pending_variant:ident) => {
+        $crate::__alias_assert_same_size!([], [
+            $type_name<u8> => ::core::task::Poll<u8>,
+            $type_name<bool> => ::core::task::Poll<bool>,
+            $type_name<::core::num::NonZeroU32> => ::core::task::Poll<::core::num::NonZeroU32>,
+            $type_name<&'static u8> => ::core::task::Poll<&'static u8>,
+            $type_name<&'static [u8]> => ::core::task::Poll<&'static [u8]>
+        ], []);
+    };
+    (generate_tests, $type_name:ident, $ready_variant:ident, $pending_variant:ident) => {
+        paste::paste! {
+            #[cfg(test)]
+            mod [<$type_name:snake _conformance>] {
+                use super::*;
+                use ::core::task::Poll;
+
+                const VALUES: [Poll<Result<u8, u16>>; 3] = [Poll::Ready(Ok(1)), Poll::Ready(Err(2)), Poll::Pending];
+
+                fn alias(value: Poll<Result<u8, u16>>) -> $type_name<Result<u8, u16>> {
+                    value.into()
+                }
+
+                #[test]
+                fn round_trip() {
+                    for value in VALUES {
+                        assert_eq!(Poll::from(alias(value)), value);
+                    }
+                    assert_eq!(Poll::from($type_name::from(1u8)), Poll::Ready(1));
+                }
+
+                #[test]
+                fn size_equivalence() {
+                    assert_eq!(::core::mem::size_of::<$type_name<u8>>(), ::core::mem::size_of::<Poll<u8>>());
+                    assert_eq!(::core::mem::size_of::<$type_name<&u8>>(), ::core::mem::size_of::<Poll<&u8>>());
+                }
+
+                #[test]
+                fn methods() {
+                    for value in VALUES {
+                        assert_eq!(alias(value).[<is_ $ready_variant:lower>](), value.is_ready());
+                        assert_eq!(alias(value).[<is_ $pending_variant:lower>](), value.is_pending());
+                        assert_eq!(Poll::from(alias(value).map(|v| v.is_ok())), value.map(|v| v.is_ok()));
+                        assert_eq!(Poll::from(alias(value).map_ok(u32::from)), value.map_ok(u32::from));
+                        assert_eq!(Poll::from(alias(value).map_err(u32::from)), value.map_err(u32::from));
+                    }
+                }
+            }
+        }
+    };
+    ($flag:ident, $type_name:ident, $ready_variant:ident, $pending_variant:ident) => {
+        compile_error!(concat!("`alias_poll!` does not accept the `", stringify!($flag), "` option"));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Poll;
+
+    alias_poll!(State, Done, Working);
+
+    #[test]
+    fn map() {
+        assert_eq!(State::Done(2).map(|x| x * 2), State::Done(4));
+        assert_eq!(State::<i32>::Working.map(|x| x * 2), State::Working);
+
+        let parsed: State<Result<u8, &str>> = State::Done(Ok(1));
+        assert_eq!(parsed.map_ok(u16::from), State::Done(Ok(1)));
+        assert_eq!(parsed.map_err(str::len), State::Done(Ok(1)));
+        let failed: State<Result<u8, &str>> = State::Done(Err("bad"));
+        assert_eq!(failed.map_ok(u16::from), State::Done(Err("bad")));
+        assert_eq!(failed.map_err(str::len), State::Done(Err(3)));
+        assert_eq!(
+            State::<Result<u8, &str>>::Working.map_ok(u16::from),
+            State::Working
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(State::from(Poll::Ready(1)), State::Done(1));
+        assert_eq!(State::<u8>::from(Poll::Pending), State::Working);
+        assert_eq!(Poll::from(State::Done(1)), Poll::Ready(1));
+        assert_eq!(Poll::<u8>::from(State::Working), Poll::Pending);
+        assert_eq!(State::from(1), State::Done(1));
+    }
+}
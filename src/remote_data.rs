@@ -0,0 +1,214 @@
+/// Creates a RemoteData-like enum with custom variant names, describing the state of an asynchronous load.
+///
+/// See [`examples::RemoteDataExample`](crate::examples::RemoteDataExample) for a generated example.
+///
+/// The generated type has an idle variant (not requested yet), a loading variant, a loaded
+/// variant holding a `T` and an errored variant holding an `E`.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_remote_data;
+///
+/// alias_remote_data!(Fetch, Idle, Loading, Loaded, Errored);
+///
+/// let idle: Fetch<i32, String> = Fetch::Idle;
+/// let loaded: Fetch<i32, String> = Fetch::Loaded(42);
+///
+/// assert!(idle.is_idle());
+/// assert!(!idle.is_loading());
+/// assert!(loaded.is_loaded());
+/// assert!(!loaded.is_errored());
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_remote_data;
+/// alias_remote_data!(Fetch, Idle, Loading, Loaded, Errored);
+///
+/// let loaded: Fetch<i32, String> = Fetch::Loaded(10);
+/// assert_eq!(loaded.as_loaded(), Some(&10));
+/// assert_eq!(loaded.as_errored(), None);
+/// assert_eq!(loaded.clone().map(|x| x * 2), Fetch::Loaded(20));
+/// assert_eq!(loaded.clone().loaded(), Some(10));
+///
+/// let errored: Fetch<i32, String> = Fetch::Errored("timeout".to_string());
+/// assert_eq!(errored.as_errored().map(String::as_str), Some("timeout"));
+/// assert_eq!(errored.map_err(|e| e.len()), Fetch::Errored(7));
+///
+/// let loading: Fetch<i32, String> = Fetch::Loading;
+/// assert_eq!(loading.as_ref().map(|x| x + 1), Fetch::Loading);
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be created from a `Result<T, E>`, or from an `Option<Result<T, E>>`
+/// where `None` means the result is still loading.
+///
+/// ```
+/// use enumizer::alias_remote_data;
+/// alias_remote_data!(Fetch, Idle, Loading, Loaded, Errored);
+///
+/// let from_ok: Fetch<i32, String> = Ok(1).into();
+/// assert_eq!(from_ok, Fetch::Loaded(1));
+///
+/// let from_err: Fetch<i32, String> = Err("failed".to_string()).into();
+/// assert_eq!(from_err, Fetch::Errored("failed".to_string()));
+///
+/// let from_pending: Fetch<i32, String> = None.into();
+/// assert_eq!(from_pending, Fetch::Loading);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_remote_data;
+/// alias_remote_data!(CustomData, NotAsked, Pending, Success, Failure, traits: [Debug, Clone]);
+/// let val: CustomData<i32, ()> = CustomData::Success(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Success(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_remote_data {
+    ($type_name:ident, $idle_variant:ident, $loading_variant:ident, $loaded_variant:ident, $errored_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_remote_data [$type_name, $idle_variant, $loading_variant, $loaded_variant, $errored_variant] [T, E] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $idle_variant:ident, $loading_variant:ident, $loaded_variant:ident, $errored_variant:ident], [$T:ident, $E:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$T, $E] [$type_name [$idle_variant, $loading_variant, $loaded_variant($T), $errored_variant($E)]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T, $E> {
+            $idle_variant,
+            $loading_variant,
+            $loaded_variant($T),
+            $errored_variant($E),
+        }
+
+        #[allow(dead_code)]
+        impl<T, E> $type_name<T, E> {
+            /// Returns true if the load was not requested yet
+            $vis fn [<is_ $idle_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$idle_variant)
+            }
+
+            /// Returns true if the load is in progress
+            $vis fn [<is_ $loading_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$loading_variant)
+            }
+
+            /// Returns true if the load completed successfully
+            $vis fn [<is_ $loaded_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$loaded_variant(_))
+            }
+
+            /// Returns true if the load failed
+            $vis fn [<is_ $errored_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$errored_variant(_))
+            }
+
+            /// Returns a reference to the loaded value, if present
+            $vis fn [<as_ $loaded_variant:lower>](&self) -> Option<&T> {
+                match self {
+                    $type_name::$loaded_variant(v) => Some(v),
+                    _ => None,
+                }
+            }
+
+            /// Returns a reference to the error, if present
+            $vis fn [<as_ $errored_variant:lower>](&self) -> Option<&E> {
+                match self {
+                    $type_name::$errored_variant(e) => Some(e),
+                    _ => None,
+                }
+            }
+
+            /// Converts into an `Option` holding the loaded value, discarding any other state
+            $vis fn [<$loaded_variant:lower>](self) -> Option<T> {
+                match self {
+                    $type_name::$loaded_variant(v) => Some(v),
+                    _ => None,
+                }
+            }
+
+            /// Converts from `&Self` to an alias holding references to the values
+            $vis fn as_ref(&self) -> $type_name<&T, &E> {
+                match self {
+                    $type_name::$idle_variant => $type_name::$idle_variant,
+                    $type_name::$loading_variant => $type_name::$loading_variant,
+                    $type_name::$loaded_variant(v) => $type_name::$loaded_variant(v),
+                    $type_name::$errored_variant(e) => $type_name::$errored_variant(e),
+                }
+            }
+
+            /// Applies `f` to the loaded value, leaving any other state untouched
+            $vis fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U, E> {
+                match self {
+                    $type_name::$idle_variant => $type_name::$idle_variant,
+                    $type_name::$loading_variant => $type_name::$loading_variant,
+                    $type_name::$loaded_variant(v) => $type_name::$loaded_variant(f(v)),
+                    $type_name::$errored_variant(e) => $type_name::$errored_variant(e),
+                }
+            }
+
+            /// Applies `op` to the error, leaving any other state untouched
+            $vis fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> $type_name<T, F> {
+                match self {
+                    $type_name::$idle_variant => $type_name::$idle_variant,
+                    $type_name::$loading_variant => $type_name::$loading_variant,
+                    $type_name::$loaded_variant(v) => $type_name::$loaded_variant(v),
+                    $type_name::$errored_variant(e) => $type_name::$errored_variant(op(e)),
+                }
+            }
+        }
+
+        impl<T, E> From<Result<T, E>> for $type_name<T, E> {
+            fn from(result: Result<T, E>) -> Self {
+                match result {
+                    Ok(v) => $type_name::$loaded_variant(v),
+                    Err(e) => $type_name::$errored_variant(e),
+                }
+            }
+        }
+
+        impl<T, E> From<Option<Result<T, E>>> for $type_name<T, E> {
+            fn from(option: Option<Result<T, E>>) -> Self {
+                match option {
+                    Some(result) => result.into(),
+                    None => $type_name::$loading_variant,
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    alias_remote_data!(Fetch, Idle, Loading, Loaded, Errored);
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Fetch::from(Ok::<u8, &str>(1)), Fetch::Loaded(1));
+        assert_eq!(Fetch::from(Err::<u8, &str>("lost")), Fetch::Errored("lost"));
+        assert_eq!(Fetch::from(Some(Ok::<u8, &str>(1))), Fetch::Loaded(1));
+        assert_eq!(Fetch::<u8, &str>::from(None), Fetch::Loading);
+        assert_eq!(Fetch::<u8, &str>::Loaded(1).loaded(), Some(1));
+        assert_eq!(Fetch::<u8, &str>::Idle.loaded(), None);
+    }
+
+    #[test]
+    fn map() {
+        let loaded: Fetch<u8, &str> = Fetch::Loaded(2);
+        assert_eq!(loaded.map(u16::from), Fetch::Loaded(2u16));
+        assert_eq!(loaded.map_err(str::len), Fetch::Loaded(2));
+        let errored: Fetch<u8, &str> = Fetch::Errored("lost");
+        assert_eq!(errored.map(u16::from), Fetch::Errored("lost"));
+        assert_eq!(errored.map_err(str::len), Fetch::Errored(4));
+        assert_eq!(Fetch::<u8, &str>::Idle.map(u16::from), Fetch::Idle);
+        assert_eq!(Fetch::<u8, &str>::Loading.map_err(str::len), Fetch::Loading);
+    }
+}
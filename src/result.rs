@@ -28,8 +28,129 @@
 /// assert_eq!(val.as_success(), Some(&10));
 /// assert_eq!(val.as_failure(), None);
 ///
-/// let doubled = val.map(|x| x * 2);
+/// assert_eq!(val.clone().success(), Some(10));
+/// assert_eq!(val.clone().failure(), None);
+///
+/// let doubled = val.clone().map(|x| x * 2);
 /// assert_eq!(doubled.unwrap(), 20);
+///
+/// assert_eq!(val.clone().map_or(0, |x| x + 1), 11);
+/// let failed: Response<i32, String> = Response::Failure("oops".to_string());
+/// assert_eq!(failed.clone().map_or(0, |x| x + 1), 0);
+/// assert_eq!(failed.clone().map_or_else(|e| e.len(), |x| x as usize), 4);
+///
+/// assert_eq!(val.expect("should succeed"), 10);
+/// assert_eq!(failed.clone().unwrap_failure(), "oops");
+/// assert_eq!(failed.clone().expect_failure("should fail"), "oops");
+/// assert_eq!(failed.unwrap_or_default(), 0);
+/// ```
+///
+/// ```should_panic
+/// use enumizer::alias_result;
+/// alias_result!(Response, Success, Failure);
+/// let val: Response<i32, String> = Response::Success(10);
+/// val.unwrap_failure();
+/// ```
+///
+/// # Combinators
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Response, Success, Failure);
+/// fn halve(x: i32) -> Response<i32, String> {
+///     if x % 2 == 0 {
+///         Response::Success(x / 2)
+///     } else {
+///         Response::Failure(format!("{x} is odd"))
+///     }
+/// }
+///
+/// let val: Response<i32, String> = Response::Success(20);
+/// assert_eq!(val.clone().and_then(halve).and_then(halve), Response::Success(5));
+/// assert_eq!(val.clone().and_then(halve).and_then(halve).and_then(halve), Response::Failure("5 is odd".to_string()));
+/// assert_eq!(val.clone().and(Response::<u8, String>::Success(1)), Response::Success(1));
+///
+/// let failed: Response<i32, String> = Response::Failure("oops".to_string());
+/// assert_eq!(failed.clone().or(Response::<i32, ()>::Success(0)), Response::Success(0));
+/// assert_eq!(failed.clone().or_else(|e| Response::<i32, usize>::Failure(e.len())), Response::Failure(4));
+/// assert_eq!(val.clone().or_else(|e| Response::<i32, usize>::Failure(e.len())), Response::Success(20));
+///
+/// let mut seen = Vec::new();
+/// let _ = val.inspect(|x| seen.push(format!("value {x}")));
+/// let _ = failed.inspect_failure(|e| seen.push(format!("error {e}")));
+/// assert_eq!(seen, ["value 20", "error oops"]);
+/// ```
+///
+/// # Borrowed Views
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Response, Success, Failure);
+/// let mut name: Response<String, u32> = Response::Success("ferris".to_string());
+/// let borrowed: Response<&String, &u32> = name.as_ref();
+/// assert!(borrowed.is_success());
+///
+/// let view: Response<&str, &u32> = name.as_deref();
+/// assert_eq!(view, Response::Success("ferris"));
+///
+/// if let Response::Success(s) = name.as_deref_mut() {
+///     s.make_ascii_uppercase();
+/// }
+/// assert_eq!(name.as_deref(), Response::Success("FERRIS"));
+///
+/// if let Response::Success(s) = name.as_mut() {
+///     s.push('!');
+/// }
+/// assert_eq!(name, Response::Success("FERRIS!".to_string()));
+///
+/// // Borrowed views can be turned back into owned values.
+/// let owned: Response<String, u32> = name.as_ref().cloned();
+/// assert_eq!(owned, name);
+/// let code: Response<i32, u32> = Response::Failure(404);
+/// assert_eq!(code.as_ref().copied(), Response::Failure(404));
+/// ```
+///
+/// # Iterators
+///
+/// Like `Result<T, E>`, the generated type iterates over zero or one ok values.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Response, Success, Failure);
+/// let mut val: Response<i32, String> = Response::Success(3);
+/// let failed: Response<i32, String> = Response::Failure("oops".to_string());
+///
+/// assert_eq!(val.iter().collect::<Vec<_>>(), [&3]);
+/// assert_eq!(failed.iter().count(), 0);
+///
+/// for x in val.iter_mut() {
+///     *x += 1;
+/// }
+/// for x in &mut val {
+///     *x *= 10;
+/// }
+/// assert_eq!((&val).into_iter().next(), Some(&40));
+///
+/// let all: Vec<i32> = vec![val, failed.clone(), Response::Success(1)].into_iter().flatten().collect();
+/// assert_eq!(all, [40, 1]);
+///
+/// // Collecting stops at the first failure, just like collecting into a `Result`.
+/// let collected: Response<Vec<i32>, String> = vec![Response::Success(1), Response::Success(2)].into_iter().collect();
+/// assert_eq!(collected, Response::Success(vec![1, 2]));
+/// let collected: Response<Vec<i32>, String> = vec![Response::Success(1), failed, Response::Failure("later".to_string())]
+///     .into_iter()
+///     .collect();
+/// assert_eq!(collected, Response::Failure("oops".to_string()));
+///
+/// // `Sum` and `Product` short-circuit the same way.
+/// let total: Response<i64, String> = (1..=4).map(Response::Success).sum();
+/// assert_eq!(total, Response::Success(10));
+/// let product: Response<i64, String> = (1..=4).map(Response::Success).product();
+/// assert_eq!(product, Response::Success(24));
+/// let total: Response<i64, String> = vec![Response::Success(1), Response::Failure("nan".to_string())]
+///     .into_iter()
+///     .sum();
+/// assert_eq!(total, Response::Failure("nan".to_string()));
 /// ```
 ///
 /// # Conversions
@@ -47,15 +168,56 @@
 ///
 /// let to_result: Result<i32, String> = Response::Success(42).into();
 /// assert_eq!(to_result, Ok(42));
+///
+/// // A side that can never occur doesn't need to be matched.
+/// use std::convert::Infallible;
+/// let infallible: Response<i32, Infallible> = Response::Success(42);
+/// assert_eq!(infallible.into_success(), 42);
+/// let never_ok: Response<Infallible, String> = Response::Failure("failed".to_string());
+/// assert_eq!(never_ok.into_failure(), "failed");
+///
+/// // Transpose a wrapped `Option`
+/// let found: Response<Option<i32>, String> = Response::Success(Some(5));
+/// assert_eq!(found.transpose(), Some(Response::Success(5)));
+/// let missing: Response<Option<i32>, String> = Response::Success(None);
+/// assert_eq!(missing.transpose(), None);
+/// let failed: Response<Option<i32>, String> = Response::Failure("failed".to_string());
+/// assert_eq!(failed.transpose(), Some(Response::Failure("failed".to_string())));
+///
+/// // Flatten nested results
+/// let nested: Response<Response<i32, String>, String> = Response::Success(Response::Success(5));
+/// assert_eq!(nested.flatten(), Response::Success(5));
+/// let nested: Response<Result<i32, String>, String> = Response::Success(Err("inner".to_string()));
+/// assert_eq!(nested.flatten(), Response::Failure("inner".to_string()));
+/// ```
+///
+/// # Conditional Checks
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Response, Success, Failure);
+/// let val: Response<i32, String> = Response::Success(42);
+/// assert!(val.is_success_and(|&x| x > 40));
+/// assert!(!val.is_success_and(|&x| x < 40));
+/// assert!(!val.is_failure_and(|e| e.is_empty()));
+///
+/// let failed: Response<i32, String> = Response::Failure("oops".to_string());
+/// assert!(failed.is_failure_and(|e| e.starts_with('o')));
+/// assert!(!failed.is_success_and(|&x| x > 40));
+///
+/// assert!(val.success_eq(&42));
+/// assert!(!val.failure_eq(&"oops"));
+/// assert!(failed.failure_eq(&"oops"));
+/// assert!(!failed.success_eq(&42));
 /// ```
 ///
 /// # Try Trait Support (Nightly Only)
 ///
 /// Add `implement_try` to enable the `?` operator for early returns.
-/// Requires nightly Rust with `#![feature(try_trait_v2)]`.
+/// Requires nightly Rust with `#![feature(try_trait_v2, try_trait_v2_residual)]`.
 ///
 /// ```ignore
-/// #![feature(try_trait_v2)]
+/// #![feature(try_trait_v2, try_trait_v2_residual)]
 /// use enumizer::alias_result;
 ///
 /// alias_result!(Response, Success, Failure, implement_try);
@@ -70,6 +232,208 @@
 /// assert_eq!(try_example(Response::Failure("error".into()), Response::Success(15)), Response::Failure("error".into()));
 /// ```
 ///
+/// # Unchecked Extraction
+///
+/// Add `implement_unchecked` to generate the `unsafe` unchecked extractors.
+/// They are opt-in, so that by default the generated code contains no `unsafe` functions.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Response, Success, Failure, implement_unchecked);
+///
+/// let val: Response<i32, String> = Response::Success(42);
+/// // SAFETY: `val` was constructed as a `Success` above.
+/// assert_eq!(unsafe { val.unwrap_unchecked() }, 42);
+///
+/// let failed: Response<i32, String> = Response::Failure("oops".to_string());
+/// // SAFETY: `failed` was constructed as a `Failure` above.
+/// assert_eq!(unsafe { failed.unwrap_failure_unchecked() }, "oops");
+/// ```
+///
+/// # Returning From `main`
+///
+/// Add `implement_termination` to implement [`std::process::Termination`], so the generated type
+/// can be returned from `main` and from tests, reporting the failure payload like `Result` does.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Outcome, Success, Failure, implement_termination);
+///
+/// fn main() -> Outcome<(), String> {
+///     Outcome::Success(())
+/// }
+/// ```
+///
+/// # Error Wrapper Mode
+///
+/// Add `implement_error` to implement `Display` (forwarding to whichever payload is present) and
+/// `std::error::Error`, with `source()` returning the failure payload.
+///
+/// ```
+/// use enumizer::alias_result;
+/// use std::error::Error;
+/// alias_result!(Lookup, Cached, Refreshed, traits: [Debug], implement_error);
+///
+/// let refreshed: Lookup<&str, std::num::ParseIntError> = Lookup::Refreshed("x".parse::<i32>().unwrap_err());
+/// assert_eq!(refreshed.to_string(), "invalid digit found in string");
+/// assert!(refreshed.source().is_some());
+///
+/// let cached: Lookup<&str, std::num::ParseIntError> = Lookup::Cached("stale entry");
+/// assert_eq!(cached.to_string(), "stale entry");
+/// assert!(cached.source().is_none());
+///
+/// let boxed: Box<dyn Error> = Box::new(refreshed);
+/// assert_eq!(boxed.to_string(), "invalid digit found in string");
+/// ```
+///
+/// # Fixed Error Type
+///
+/// Use `error = Type` to fix the error type, so the generated type has a single generic parameter,
+/// like the `pub type Result<T> = Result<T, MyError>` aliases many crates define. The error type is
+/// written into the variant, so `IoOutcome::Done(1)` needs no annotation. The accessors,
+/// conversions and unwrapping methods are generated, along with `map` and `and_then`, which keep
+/// the error type. Methods that change the error type, like `map_err` and `or_else`, return a
+/// `Result`. The derived traits apply to the error type as written, so use `traits:` when it does
+/// not implement the default set. Of the flags, only `default_ok`, `default_err`,
+/// `implement_error`, `no_panics` and `no_must_use` are supported.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(IoOutcome, Done, Broken, error = std::io::Error, traits: [Debug]);
+///
+/// fn read_config(path: &str) -> IoOutcome<String> {
+///     std::fs::read_to_string(path).into()
+/// }
+///
+/// let missing = read_config("/definitely/not/a/real/path");
+/// assert!(missing.is_broken());
+/// assert_eq!(missing.map_err(|e| e.kind()).err(), Some(std::io::ErrorKind::NotFound));
+///
+/// assert_eq!(IoOutcome::Done(1).map(|v| v + 1).unwrap(), 2);
+/// ```
+///
+/// Like the other options, `error =` can be given in any position.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Outcome, Done, Failed, implement_error, traits: [Debug], error = String, vis: pub(crate));
+/// let outcome: Outcome<u8> = Outcome::Failed("broken".to_string());
+/// assert_eq!(outcome.to_string(), "broken");
+/// ```
+///
+/// # Concrete Payload Types
+///
+/// Use `types: [Ok, Err]` to fix both payload types, so the generated type is not generic and can
+/// be written without any generic parameters in public signatures. It gets the methods of
+/// `error =`, except that `map` and `and_then` return a `Result` too, since they change the ok
+/// type. `bounds:` does not apply. `traits:` may follow the types.
+///
+/// ```
+/// use enumizer::alias_result;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Session(u32);
+/// #[derive(Debug, PartialEq)]
+/// pub struct AuthError;
+///
+/// alias_result!(LoginOutcome, Accepted, Rejected, types: [Session, AuthError], traits: [Debug, PartialEq]);
+///
+/// fn login(password: &str) -> LoginOutcome {
+///     if password == "hunter2" {
+///         LoginOutcome::Accepted(Session(7))
+///     } else {
+///         LoginOutcome::Rejected(AuthError)
+///     }
+/// }
+///
+/// assert!(LoginOutcome::Accepted(Session(7)).is_accepted());
+/// assert_eq!(login("hunter2").as_accepted(), Some(&Session(7)));
+/// assert!(login("letmein").is_rejected());
+/// let id: Result<u32, AuthError> = login("hunter2").map(|session| session.0).into();
+/// assert_eq!(id, Ok(7));
+/// ```
+///
+/// # Default Variant
+///
+/// `Result` does not implement `Default`, since neither variant is the obvious default. Add
+/// `default_ok` or `default_err` to implement it with the chosen variant holding its default value,
+/// so the generated type can be used in `#[derive(Default)]` structs and with `std::mem::take`.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Progress, Finished, Pending, default_err);
+///
+/// #[derive(Default)]
+/// struct Job {
+///     progress: Progress<String, u8>,
+/// }
+///
+/// let mut job = Job::default();
+/// assert_eq!(job.progress, Progress::Pending(0));
+/// job.progress = Progress::Finished("done".to_string());
+/// assert_eq!(std::mem::take(&mut job.progress).finished(), Some("done".to_string()));
+/// assert!(job.progress.is_pending());
+/// ```
+///
+/// # Method Groups
+///
+/// Use `methods:` to generate only some of the method groups, which keeps the expansion small in
+/// crates with many aliases. The `core` group is always generated.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Status, Up, Down, methods: [core, combinators]);
+/// let status: Status<u8, ()> = Status::Up(1);
+/// assert_eq!(status.map(u16::from).unwrap(), 1);
+/// ```
+///
+/// # Renaming Methods
+///
+/// Use `rename:` to give single methods another name, and `method_prefix:` to put a prefix in front
+/// of the names of all of them. The methods named after a variant keep their names.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Upload, Stored, Rejected, method_prefix: upload_, rename: [unwrap => into_value]);
+/// let upload: Upload<u8, ()> = Upload::Stored(1);
+/// assert_eq!(upload.upload_map(u16::from).into_value(), 1);
+/// assert!(upload.is_stored());
+/// ```
+///
+/// # Modules
+///
+/// Use `module: name` to generate the type inside a module, which imports everything from the
+/// enclosing module, so the fixed error type of `error =` can be named as usual. The type is
+/// re-exported next to the module, unless the re-exports are listed, like the empty list of `peers`
+/// below.
+///
+/// ```
+/// use enumizer::{alias_option, alias_result};
+///
+/// pub struct Timeout;
+///
+/// alias_result!(Reply, Received, TimedOut, error = Timeout, traits: [], module: replies);
+/// alias_option!(Peer, Connected, Disconnected, module: peers []);
+///
+/// # fn main() {
+/// let reply: Reply<u8> = replies::Reply::Received(1);
+/// assert!(reply.is_received());
+/// assert!(peers::Peer::<u8>::Disconnected.is_disconnected());
+/// # }
+/// ```
+///
+/// # Generic Parameter Names
+///
+/// The generic parameters are named `T` and `E`, like those of `Result`. Use `generics:` to give
+/// them names that show up in rustdoc and IDE hints instead.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Response, Success, Failure, generics: [Value, Error]);
+/// let response: Response<u8, String> = Response::Success(1);
+/// assert!(response.is_success());
+/// ```
+///
 /// # Custom Traits
 ///
 /// You can specify custom traits to derive instead of the default set.
@@ -84,102 +448,1431 @@
 /// let json = serde_json::to_string(&val).unwrap();
 /// assert_eq!(json, r#"{"Ok":42}"#);
 /// ```
+///
+/// Use `traits_add:` instead to derive traits on top of the default set. Attributes given with
+/// `attrs:` come after the derives, so they can be helper attributes of the added derives.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Reply, Accepted, Rejected, traits_add: [serde::Serialize], attrs: [#[serde(rename_all = "camelCase")]]);
+/// let reply: Reply<u8, ()> = Reply::Accepted(1);
+/// assert_eq!(serde_json::to_string(&reply).unwrap(), r#"{"accepted":1}"#);
+///
+/// let mut replies = vec![Reply::Rejected(()), reply];
+/// replies.sort();
+/// assert_eq!(replies[0], Reply::Accepted(1));
+/// ```
+///
+/// Use `serde_attrs:` to put attributes on single variants, like a serde rename.
+///
+/// ```
+/// use enumizer::alias_result;
+/// alias_result!(Reply, Success, Failure, traits_add: [serde::Serialize], serde_attrs: [
+///     Success => #[serde(rename = "ok")],
+///     Failure => #[serde(rename = "error")],
+/// ]);
+/// let reply: Reply<u8, ()> = Reply::Success(1);
+/// assert_eq!(serde_json::to_string(&reply).unwrap(), r#"{"ok":1}"#);
+/// ```
 #[macro_export]
 macro_rules! alias_result {
-    ($type_name:ident, $ok_variant:ident, $err_variant:ident) => {
-        $crate::alias_result!($type_name, $ok_variant, $err_variant, [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash]);
+    ($type_name:ident, $ok_variant:ident, $err_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_validate!(alias_result, $ok_variant, $err_variant, [mut, and, eq, unchecked]);
+        $crate::alias_result!(@types [$type_name, $ok_variant, $err_variant, [], []] [] $($($option)*)?);
+    };
+    (@types [$type_name:ident, $ok_variant:ident, $err_variant:ident, $ok_type:tt, $error_type:tt] [$($kept:tt)*] error = $new_error_type:ty $(, $($rest:tt)*)?) => {
+        $crate::alias_result!(@types [$type_name, $ok_variant, $err_variant, $ok_type, [$new_error_type]] [$($kept)*] $($($rest)*)?);
+    };
+    (@types [$type_name:ident, $ok_variant:ident, $err_variant:ident, $ok_type:tt, $error_type:tt] [$($kept:tt)*] types: [$new_ok_type:ty, $new_error_type:ty] $(, $($rest:tt)*)?) => {
+        $crate::alias_result!(@types [$type_name, $ok_variant, $err_variant, [$new_ok_type], [$new_error_type]] [$($kept)*] $($($rest)*)?);
+    };
+    (@types $args:tt $kept:tt $($rest:tt)+) => {
+        $crate::alias_result!(@skip $args $kept $($rest)+);
+    };
+    (@types $args:tt [$($kept:tt)*]) => {
+        $crate::__alias_options!(alias_result $args [T, E] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash], $($kept)*);
+    };
+    (@skip $args:tt [$($kept:tt)*] , $($rest:tt)*) => {
+        $crate::alias_result!(@types $args [$($kept)* ,] $($rest)*);
+    };
+    (@skip $args:tt [$($kept:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::alias_result!(@skip $args [$($kept)* $token] $($rest)*);
+    };
+    (@skip $args:tt [$($kept:tt)*]) => {
+        $crate::alias_result!(@types $args [$($kept)*]);
+    };
+    (@impl $vis:vis [$type_name:ident, $ok_variant:ident, $err_variant:ident, [], [$error_type:ty]], [$T:ident, $E:ident], [$($bound:tt)*], $methods:tt, $skip:tt, $traits:tt, $attrs:tt, $flags:tt) => {
+        $crate::__alias_result_fixed!([$vis] [$type_name, $ok_variant, $err_variant] [<$T: $($bound)*>] [<$T>] [$T, $error_type] [$type_name<U>] [$($bound)*], $methods, $skip, $traits, $attrs, $flags);
+    };
+    (@impl $vis:vis [$type_name:ident, $ok_variant:ident, $err_variant:ident, [$ok_type:ty], [$error_type:ty]], $generics:tt, [], $methods:tt, $skip:tt, $traits:tt, $attrs:tt, $flags:tt) => {
+        $crate::__alias_result_fixed!([$vis] [$type_name, $ok_variant, $err_variant] [] [] [$ok_type, $error_type] [Result<U, $error_type>] [], $methods, $skip, $traits, $attrs, $flags);
+    };
+    (@impl $vis:vis [$type_name:ident, $ok_variant:ident, $err_variant:ident, [$ok_type:ty], [$error_type:ty]], $($rest:tt)*) => {
+        compile_error!(concat!("`", stringify!($type_name), "` has fixed payload types, which do not support `bounds:`"));
+    };
+    (@impl $vis:vis [$type_name:ident, $ok_variant:ident, $err_variant:ident, [], []], [$T:ident, $E:ident], [$($bound:tt)*], [$($method:ident),*], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        $crate::__alias_variant_attrs!($type_name, [$ok_variant, $err_variant], [$($skip),*], alias_result ([$vis] [$type_name, $ok_variant, $err_variant], [$T, $E], [$($bound)*], [$($skip),*], [$($trait),*], [$(#[$attr])*], [$($flag),*]));
+        $crate::__alias_unsized!($vis $type_name, [$T, $E], [$($skip),*]);
+        $crate::__alias_serde!(result [$vis] [[$($bound)*], $type_name, $ok_variant, $err_variant], [$($skip),*]);
+        $crate::__alias_impls!(result [$T, $E] [[$($bound)*], $type_name, $ok_variant, $err_variant], [$($skip),*]);
+
+        $crate::__alias_forbid_unsafe!([$($skip),*], {
+        paste::paste! {
+        #[allow(dead_code)]
+        impl<T: $($bound)*, E: $($bound)*> $type_name<T, E> {
+            $crate::__alias_doc_alias!($ok_variant, Ok, "is_ok", {
+                $crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($ok_variant), "`, consider wrapping this in an `assert!()` instead"), {
+                    #[doc = concat!("Returns `true` if the value is [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ").")]
+                    ///
+                    #[doc = concat!("For example, `", stringify!($type_name), "::<u8, ()>::", stringify!($ok_variant), "(1).", stringify!([<is_ $ok_variant:snake>]), "()` returns `true`.")]
+                    ///
+                    /// Behaves like [`Result::is_ok`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_ok)
+                    $vis fn [<is_ $ok_variant:snake>](&self) -> bool {
+                        matches!(self, $type_name::$ok_variant(_))
+                    }
+                });
+            });
+
+            $crate::__alias_doc_alias!($err_variant, Err, "is_err", {
+                $crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($err_variant), "`, consider wrapping this in an `assert!()` instead"), {
+                    #[doc = concat!("Returns `true` if the value is [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ").")]
+                    ///
+                    #[doc = concat!("For example, `", stringify!($type_name), "::<(), u8>::", stringify!($err_variant), "(1).", stringify!([<is_ $err_variant:snake>]), "()` returns `true`.")]
+                    ///
+                    /// Behaves like [`Result::is_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_err)
+                    $vis fn [<is_ $err_variant:snake>](&self) -> bool {
+                        matches!(self, $type_name::$err_variant(_))
+                    }
+                });
+            });
+
+            $crate::__alias_doc_alias!($ok_variant, Ok, "is_ok_and", {
+                $crate::__alias_must_use!([$($skip),*], {
+                    #[doc = concat!("Returns `true` if the value is [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") and the contained value matches the predicate.")]
+                    ///
+                    /// Behaves like [`Result::is_ok_and`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_ok_and)
+                    $vis fn [<is_ $ok_variant:snake _and>]<F: FnOnce(&T) -> bool>(&self, f: F) -> bool {
+                        match self {
+                            $type_name::$ok_variant(v) => f(v),
+                            _ => false,
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_doc_alias!($err_variant, Err, "is_err_and", {
+                $crate::__alias_must_use!([$($skip),*], {
+                    #[doc = concat!("Returns `true` if the value is [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") and the contained value matches the predicate.")]
+                    ///
+                    /// Behaves like [`Result::is_err_and`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_err_and)
+                    $vis fn [<is_ $err_variant:snake _and>]<F: FnOnce(&E) -> bool>(&self, f: F) -> bool {
+                        match self {
+                            $type_name::$err_variant(e) => f(e),
+                            _ => false,
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_must_use!([$($skip),*], {
+                #[doc = concat!("Returns `true` if the value is [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") and the contained value equals `other`.")]
+                ///
+                /// Behaves like the unstable `Result::contains`
+                #[doc(alias = "contains")]
+                $vis fn [<$ok_variant:snake _eq>]<U: ?Sized>(&self, other: &U) -> bool
+                where
+                    T: PartialEq<U>,
+                {
+                    match self {
+                        $type_name::$ok_variant(v) => v == other,
+                        $type_name::$err_variant(_) => false,
+                    }
+                }
+            });
+
+            $crate::__alias_must_use!([$($skip),*], {
+                #[doc = concat!("Returns `true` if the value is [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") and the contained value equals `other`.")]
+                ///
+                /// Behaves like the unstable `Result::contains_err`
+                #[doc(alias = "contains_err")]
+                $vis fn [<$err_variant:snake _eq>]<F: ?Sized>(&self, other: &F) -> bool
+                where
+                    E: PartialEq<F>,
+                {
+                    match self {
+                        $type_name::$ok_variant(_) => false,
+                        $type_name::$err_variant(e) => e == other,
+                    }
+                }
+            });
+
+            $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                #[doc = concat!("Returns a reference to the contained value if the value is [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ").")]
+                ///
+                /// Behaves like [`Result::as_ref`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_ref) for the Ok variant
+                $vis fn [<as_ $ok_variant:snake>](&self) -> Option<&T> {
+                    match self {
+                        $type_name::$ok_variant(v) => Some(v),
+                        _ => None,
+                    }
+                }
+            });
+
+            $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                #[doc = concat!("Returns a mutable reference to the contained value if the value is [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ").")]
+                ///
+                /// Behaves like [`Result::as_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_mut) for the Ok variant
+                $vis fn [<as_ $ok_variant:snake _mut>](&mut self) -> Option<&mut T> {
+                    match self {
+                        $type_name::$ok_variant(v) => Some(v),
+                        _ => None,
+                    }
+                }
+            });
+
+            $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                #[doc = concat!("Returns a reference to the contained value if the value is [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ").")]
+                ///
+                /// Behaves like [`Result::as_ref`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_ref) for the Err variant
+                $vis fn [<as_ $err_variant:snake>](&self) -> Option<&E> {
+                    match self {
+                        $type_name::$err_variant(e) => Some(e),
+                        _ => None,
+                    }
+                }
+            });
+
+            $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                #[doc = concat!("Returns a mutable reference to the contained value if the value is [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ").")]
+                ///
+                /// Behaves like [`Result::as_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_mut) for the Err variant
+                $vis fn [<as_ $err_variant:snake _mut>](&mut self) -> Option<&mut E> {
+                    match self {
+                        $type_name::$err_variant(e) => Some(e),
+                        _ => None,
+                    }
+                }
+            });
+
+            $crate::__alias_skip!(as_ref, [$($skip),*], {
+                $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                    /// Behaves like [`Result::as_ref`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_ref)
+                    $vis fn as_ref<'a>(&'a self) -> $type_name<&'a T, &'a E>
+                    where
+                        &'a T: $($bound)*,
+                        &'a E: $($bound)*,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
+                            $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_skip!(as_mut, [$($skip),*], {
+                $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                    /// Behaves like [`Result::as_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_mut)
+                    $vis fn as_mut<'a>(&'a mut self) -> $type_name<&'a mut T, &'a mut E>
+                    where
+                        &'a mut T: $($bound)*,
+                        &'a mut E: $($bound)*,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
+                            $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_methods!(iterators, [$($method),*], {
+                $crate::__alias_skip!(iter, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "iterators are lazy and do nothing unless consumed", {
+                        /// Behaves like [`Result::iter`](https://doc.rust-lang.org/std/result/enum.Result.html#method.iter)
+                        $vis fn iter(&self) -> ::core::option::IntoIter<&T> {
+                            match self {
+                                $type_name::$ok_variant(v) => Some(v),
+                                $type_name::$err_variant(_) => None,
+                            }
+                            .into_iter()
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(iter_mut, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "iterators are lazy and do nothing unless consumed", {
+                        /// Behaves like [`Result::iter_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.iter_mut)
+                        $vis fn iter_mut(&mut self) -> ::core::option::IntoIter<&mut T> {
+                            match self {
+                                $type_name::$ok_variant(v) => Some(v),
+                                $type_name::$err_variant(_) => None,
+                            }
+                            .into_iter()
+                        }
+                    });
+                });
+            });
+
+            $crate::__alias_methods!(conversions, [$($method),*], {
+                $crate::__alias_doc_alias!($ok_variant, Ok, "ok", {
+                    $crate::__alias_keyword!([<$ok_variant:snake>], {
+                        $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                            #[doc = concat!("Converts into an `Option` holding the [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") value, discarding the `", stringify!($err_variant), "` value.")]
+                            ///
+                            #[doc = concat!("For example, `", stringify!($type_name), "::<u8, ()>::", stringify!($ok_variant), "(1).", stringify!([<$ok_variant:snake>]), "()` returns `Some(1)`.")]
+                            ///
+                            /// Behaves like [`Result::ok`](https://doc.rust-lang.org/std/result/enum.Result.html#method.ok)
+                            $vis fn [<$ok_variant:snake>](self) -> Option<T> {
+                                match self {
+                                    $type_name::$ok_variant(v) => Some(v),
+                                    $type_name::$err_variant(_) => None,
+                                }
+                            }
+                        });
+                    });
+                });
+
+                $crate::__alias_doc_alias!($err_variant, Err, "err", {
+                    $crate::__alias_keyword!([<$err_variant:snake>], {
+                        $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                            #[doc = concat!("Converts into an `Option` holding the [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") value, discarding the `", stringify!($ok_variant), "` value.")]
+                            ///
+                            #[doc = concat!("For example, `", stringify!($type_name), "::<(), u8>::", stringify!($err_variant), "(1).", stringify!([<$err_variant:snake>]), "()` returns `Some(1)`.")]
+                            ///
+                            /// Behaves like [`Result::err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.err)
+                            $vis fn [<$err_variant:snake>](self) -> Option<E> {
+                                match self {
+                                    $type_name::$ok_variant(_) => None,
+                                    $type_name::$err_variant(e) => Some(e),
+                                }
+                            }
+                        });
+                    });
+                });
+            });
+
+            $crate::__alias_methods!(combinators, [$($method),*], {
+                $crate::__alias_skip!(map, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::map`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map)
+                        $vis fn map<U: $($bound)*, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U, E> {
+                            match self {
+                                $type_name::$ok_variant(v) => $type_name::$ok_variant(f(v)),
+                                $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                            }
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(map_err, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::map_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map_err)
+                        $vis fn map_err<F: $($bound)*, O: FnOnce(E) -> F>(self, op: O) -> $type_name<T, F> {
+                            match self {
+                                $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
+                                $type_name::$err_variant(e) => $type_name::$err_variant(op(e)),
+                            }
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(map_or, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::map_or`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map_or)
+                        $vis fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
+                            match self {
+                                $type_name::$ok_variant(v) => f(v),
+                                $type_name::$err_variant(_) => default,
+                            }
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(map_or_else, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::map_or_else`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map_or_else)
+                        $vis fn map_or_else<U, D: FnOnce(E) -> U, F: FnOnce(T) -> U>(self, default: D, f: F) -> U {
+                            match self {
+                                $type_name::$ok_variant(v) => f(v),
+                                $type_name::$err_variant(e) => default(e),
+                            }
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(inspect, [$($skip),*], {
+                    /// Behaves like [`Result::inspect`](https://doc.rust-lang.org/std/result/enum.Result.html#method.inspect)
+                    $vis fn inspect<F: FnOnce(&T)>(self, f: F) -> Self {
+                        if let $type_name::$ok_variant(v) = &self {
+                            f(v);
+                        }
+                        self
+                    }
+                });
+
+                $crate::__alias_doc_alias!($err_variant, Err, "inspect_err", {
+                    #[doc = concat!("Calls `f` with a reference to the contained value if the value is [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ").")]
+                    ///
+                    /// Behaves like [`Result::inspect_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.inspect_err)
+                    $vis fn [<inspect_ $err_variant:snake>]<F: FnOnce(&E)>(self, f: F) -> Self {
+                        if let $type_name::$err_variant(e) = &self {
+                            f(e);
+                        }
+                        self
+                    }
+                });
+
+                $crate::__alias_skip!(and, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::and`](https://doc.rust-lang.org/std/result/enum.Result.html#method.and)
+                        $vis fn and<U: $($bound)*>(self, res: $type_name<U, E>) -> $type_name<U, E> {
+                            match self {
+                                $type_name::$ok_variant(_) => res,
+                                $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                            }
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(and_then, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::and_then`](https://doc.rust-lang.org/std/result/enum.Result.html#method.and_then)
+                        $vis fn and_then<U: $($bound)*, F: FnOnce(T) -> $type_name<U, E>>(self, op: F) -> $type_name<U, E> {
+                            match self {
+                                $type_name::$ok_variant(v) => op(v),
+                                $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                            }
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(or, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::or`](https://doc.rust-lang.org/std/result/enum.Result.html#method.or)
+                        $vis fn or<F: $($bound)*>(self, res: $type_name<T, F>) -> $type_name<T, F> {
+                            match self {
+                                $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
+                                $type_name::$err_variant(_) => res,
+                            }
+                        }
+                    });
+                });
+
+                $crate::__alias_skip!(or_else, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "if you don't need the returned value, use `if let` instead", {
+                        /// Behaves like [`Result::or_else`](https://doc.rust-lang.org/std/result/enum.Result.html#method.or_else)
+                        $vis fn or_else<F: $($bound)*, O: FnOnce(E) -> $type_name<T, F>>(self, op: O) -> $type_name<T, F> {
+                            match self {
+                                $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
+                                $type_name::$err_variant(e) => op(e),
+                            }
+                        }
+                    });
+                });
+            });
+
+            $crate::__alias_skip!(no_panics, [$($skip),*], {
+                $crate::__alias_skip!(unwrap, [$($skip),*], {
+                    /// Behaves like [`Result::unwrap`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap)
+                    #[track_caller]
+                    $vis fn unwrap(self) -> T
+                    where
+                        E: ::core::fmt::Debug,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => v,
+                            $type_name::$err_variant(e) => {
+                                panic!("called `unwrap()` on an `{}` value: {:?}", stringify!($err_variant), e)
+                            }
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_skip!(no_panics, [$($skip),*], {
+                $crate::__alias_skip!(expect, [$($skip),*], {
+                    /// Behaves like [`Result::expect`](https://doc.rust-lang.org/std/result/enum.Result.html#method.expect)
+                    #[track_caller]
+                    $vis fn expect(self, msg: &str) -> T
+                    where
+                        E: ::core::fmt::Debug,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => v,
+                            $type_name::$err_variant(e) => panic!("{}: {:?}", msg, e),
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_skip!(no_panics, [$($skip),*], {
+                $crate::__alias_doc_alias!($err_variant, Err, "unwrap_err", {
+                    #[doc = concat!("Returns the contained [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") value, panicking if the value is `", stringify!($ok_variant), "`.")]
+                    ///
+                    /// Behaves like [`Result::unwrap_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_err)
+                    #[track_caller]
+                    $vis fn [<unwrap_ $err_variant:snake>](self) -> E
+                    where
+                        T: ::core::fmt::Debug,
+                    {
+                        match self {
+                            $type_name::$err_variant(e) => e,
+                            $type_name::$ok_variant(v) => {
+                                panic!("called `unwrap_{}()` on a `{}` value: {:?}", stringify!([<$err_variant:snake>]), stringify!($ok_variant), v)
+                            }
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_skip!(no_panics, [$($skip),*], {
+                $crate::__alias_doc_alias!($err_variant, Err, "expect_err", {
+                    #[doc = concat!("Returns the contained [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") value, panicking with `msg` if the value is `", stringify!($ok_variant), "`.")]
+                    ///
+                    /// Behaves like [`Result::expect_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.expect_err)
+                    #[track_caller]
+                    $vis fn [<expect_ $err_variant:snake>](self, msg: &str) -> E
+                    where
+                        T: ::core::fmt::Debug,
+                    {
+                        match self {
+                            $type_name::$err_variant(e) => e,
+                            $type_name::$ok_variant(v) => panic!("{}: {:?}", msg, v),
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_skip!(unwrap_or, [$($skip),*], {
+                /// Behaves like [`Result::unwrap_or`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_or)
+                $vis fn unwrap_or(self, default: T) -> T {
+                    match self {
+                        $type_name::$ok_variant(v) => v,
+                        $type_name::$err_variant(_) => default,
+                    }
+                }
+            });
+
+            $crate::__alias_skip!(unwrap_or_else, [$($skip),*], {
+                /// Behaves like [`Result::unwrap_or_else`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_or_else)
+                $vis fn unwrap_or_else<F: FnOnce(E) -> T>(self, op: F) -> T {
+                    match self {
+                        $type_name::$ok_variant(v) => v,
+                        $type_name::$err_variant(e) => op(e),
+                    }
+                }
+            });
+
+            $crate::__alias_skip!(unwrap_or_default, [$($skip),*], {
+                /// Behaves like [`Result::unwrap_or_default`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_or_default)
+                $vis fn unwrap_or_default(self) -> T
+                where
+                    T: Default,
+                {
+                    match self {
+                        $type_name::$ok_variant(v) => v,
+                        $type_name::$err_variant(_) => T::default(),
+                    }
+                }
+            });
+        }
+
+        $crate::__alias_methods!(combinators, [$($method),*], {
+            #[allow(dead_code)]
+            impl<'a, T: Clone + $($bound)*, E: Clone + $($bound)*> $type_name<&'a T, &'a E>
+            where
+                &'a T: $($bound)*,
+                &'a E: $($bound)*,
+            {
+                $crate::__alias_skip!(cloned, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                        #[doc = concat!("Maps a `", stringify!($type_name), "<&T, &E>` to a `", stringify!($type_name), "<T, E>` by cloning whichever payload is present.")]
+                        $vis fn cloned(self) -> $type_name<T, E> {
+                            match self {
+                                $type_name::$ok_variant(v) => $type_name::$ok_variant(v.clone()),
+                                $type_name::$err_variant(e) => $type_name::$err_variant(e.clone()),
+                            }
+                        }
+                    });
+                });
+            }
+
+            #[allow(dead_code)]
+            impl<'a, T: Copy + $($bound)*, E: Copy + $($bound)*> $type_name<&'a T, &'a E>
+            where
+                &'a T: $($bound)*,
+                &'a E: $($bound)*,
+            {
+                $crate::__alias_skip!(copied, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                        #[doc = concat!("Maps a `", stringify!($type_name), "<&T, &E>` to a `", stringify!($type_name), "<T, E>` by copying whichever payload is present.")]
+                        $vis fn copied(self) -> $type_name<T, E> {
+                            match self {
+                                $type_name::$ok_variant(&v) => $type_name::$ok_variant(v),
+                                $type_name::$err_variant(&e) => $type_name::$err_variant(e),
+                            }
+                        }
+                    });
+                });
+            }
+        });
+
+        $crate::__alias_methods!(conversions, [$($method),*], {
+            #[allow(dead_code)]
+            impl<T: $($bound)*, E: $($bound)*> $type_name<Option<T>, E>
+            where
+                Option<T>: $($bound)*,
+            {
+                $crate::__alias_skip!(transpose, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                        /// Behaves like [`Result::transpose`](https://doc.rust-lang.org/std/result/enum.Result.html#method.transpose)
+                        $vis fn transpose(self) -> Option<$type_name<T, E>> {
+                            match self {
+                                $type_name::$ok_variant(Some(v)) => Some($type_name::$ok_variant(v)),
+                                $type_name::$ok_variant(None) => None,
+                                $type_name::$err_variant(e) => Some($type_name::$err_variant(e)),
+                            }
+                        }
+                    });
+                });
+            }
+        });
+
+        $crate::__alias_methods!(combinators, [$($method),*], {
+            #[allow(dead_code)]
+            impl<T: $($bound)*, E: $($bound)*> $type_name<$type_name<T, E>, E>
+            where
+                $type_name<T, E>: $($bound)*,
+            {
+                $crate::__alias_skip!(flatten, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                        /// Behaves like [`Result::flatten`](https://doc.rust-lang.org/std/result/enum.Result.html#method.flatten)
+                        $vis fn flatten(self) -> $type_name<T, E> {
+                            match self {
+                                $type_name::$ok_variant(inner) => inner,
+                                $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                            }
+                        }
+                    });
+                });
+            }
+
+            #[allow(dead_code)]
+            impl<T: $($bound)*, E: $($bound)*> $type_name<Result<T, E>, E>
+            where
+                Result<T, E>: $($bound)*,
+            {
+                $crate::__alias_skip!(flatten, [$($skip),*], {
+                    $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                        /// Behaves like [`Result::flatten`](https://doc.rust-lang.org/std/result/enum.Result.html#method.flatten), for a wrapped `Result`
+                        $vis fn flatten(self) -> $type_name<T, E> {
+                            match self {
+                                $type_name::$ok_variant(inner) => inner.into(),
+                                $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                            }
+                        }
+                    });
+                });
+            }
+        });
+
+        $crate::__alias_methods!(conversions, [$($method),*], {
+            #[allow(dead_code)]
+            impl<T: $($bound)*> $type_name<T, ::core::convert::Infallible>
+            where
+                ::core::convert::Infallible: $($bound)*,
+            {
+                $crate::__alias_doc_alias!($ok_variant, Ok, "into_ok", {
+                    $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                        #[doc = concat!("Returns the contained [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") value, which can not fail because the error type is uninhabited.")]
+                        ///
+                        /// Behaves like [`Result::into_ok`](https://doc.rust-lang.org/std/result/enum.Result.html#method.into_ok)
+                        $vis fn [<into_ $ok_variant:snake>](self) -> T {
+                            match self {
+                                $type_name::$ok_variant(v) => v,
+                                $type_name::$err_variant(e) => match e {},
+                            }
+                        }
+                    });
+                });
+            }
+
+            #[allow(dead_code)]
+            impl<E: $($bound)*> $type_name<::core::convert::Infallible, E>
+            where
+                ::core::convert::Infallible: $($bound)*,
+            {
+                $crate::__alias_doc_alias!($err_variant, Err, "into_err", {
+                    $crate::__alias_must_use!([$($skip),*], "this returns the converted value, and has no other effect", {
+                        #[doc = concat!("Returns the contained [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") value, which can not fail because the ok type is uninhabited.")]
+                        ///
+                        /// Behaves like [`Result::into_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.into_err)
+                        $vis fn [<into_ $err_variant:snake>](self) -> E {
+                            match self {
+                                $type_name::$ok_variant(v) => match v {},
+                                $type_name::$err_variant(e) => e,
+                            }
+                        }
+                    });
+                });
+            }
+        });
+
+        #[allow(dead_code)]
+        impl<T: ::core::ops::Deref + $($bound)*, E: $($bound)*> $type_name<T, E> {
+            $crate::__alias_skip!(as_deref, [$($skip),*], {
+                $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                    /// Behaves like [`Result::as_deref`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_deref)
+                    $vis fn as_deref<'a>(&'a self) -> $type_name<&'a T::Target, &'a E>
+                    where
+                        &'a T::Target: $($bound)*,
+                        &'a E: $($bound)*,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => $type_name::$ok_variant(v.deref()),
+                            $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                        }
+                    }
+                });
+            });
+        }
+
+        #[allow(dead_code)]
+        impl<T: ::core::ops::DerefMut + $($bound)*, E: $($bound)*> $type_name<T, E> {
+            $crate::__alias_skip!(as_deref_mut, [$($skip),*], {
+                $crate::__alias_must_use!([$($skip),*], "this returns a reference to the contained value, and has no other effect", {
+                    /// Behaves like [`Result::as_deref_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_deref_mut)
+                    $vis fn as_deref_mut<'a>(&'a mut self) -> $type_name<&'a mut T::Target, &'a mut E>
+                    where
+                        &'a mut T::Target: $($bound)*,
+                        &'a mut E: $($bound)*,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => $type_name::$ok_variant(v.deref_mut()),
+                            $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                        }
+                    }
+                });
+            });
+        }
+
+        impl<T: $($bound)*, E: $($bound)*> From<Result<T, E>> for $type_name<T, E> {
+            fn from(result: Result<T, E>) -> Self {
+                match result {
+                    Ok(v) => $type_name::$ok_variant(v),
+                    Err(e) => $type_name::$err_variant(e),
+                }
+            }
+        }
+
+        impl<T: $($bound)*, E: $($bound)*> From<$type_name<T, E>> for Result<T, E> {
+            fn from(val: $type_name<T, E>) -> Self {
+                match val {
+                    $type_name::$ok_variant(v) => Ok(v),
+                    $type_name::$err_variant(e) => Err(e),
+                }
+            }
+        }
+
+        $crate::__alias_methods!(iterators, [$($method),*], {
+            impl<T: $($bound)*, E: $($bound)*> IntoIterator for $type_name<T, E> {
+                type Item = T;
+                type IntoIter = ::core::option::IntoIter<T>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    match self {
+                        $type_name::$ok_variant(v) => Some(v),
+                        $type_name::$err_variant(_) => None,
+                    }
+                    .into_iter()
+                }
+            }
+
+            impl<'a, T: $($bound)*, E: $($bound)*> IntoIterator for &'a $type_name<T, E> {
+                type Item = &'a T;
+                type IntoIter = ::core::option::IntoIter<&'a T>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    match self {
+                        $type_name::$ok_variant(v) => Some(v),
+                        $type_name::$err_variant(_) => None,
+                    }
+                    .into_iter()
+                }
+            }
+
+            impl<'a, T: $($bound)*, E: $($bound)*> IntoIterator for &'a mut $type_name<T, E> {
+                type Item = &'a mut T;
+                type IntoIter = ::core::option::IntoIter<&'a mut T>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    match self {
+                        $type_name::$ok_variant(v) => Some(v),
+                        $type_name::$err_variant(_) => None,
+                    }
+                    .into_iter()
+                }
+            }
+
+            impl<A: $($bound)*, E: $($bound)*, V: FromIterator<A> + $($bound)*> FromIterator<$type_name<A, E>> for $type_name<V, E> {
+                fn from_iter<I: IntoIterator<Item = $type_name<A, E>>>(iter: I) -> Self {
+                    iter.into_iter().map(Result::from).collect::<Result<V, E>>().into()
+                }
+            }
+
+            impl<A: $($bound)*, E: $($bound)*, V: ::core::iter::Sum<A> + $($bound)*> ::core::iter::Sum<$type_name<A, E>> for $type_name<V, E> {
+                fn sum<I: Iterator<Item = $type_name<A, E>>>(iter: I) -> Self {
+                    iter.map(Result::from).sum::<Result<V, E>>().into()
+                }
+            }
+
+            impl<A: $($bound)*, E: $($bound)*, V: ::core::iter::Product<A> + $($bound)*> ::core::iter::Product<$type_name<A, E>> for $type_name<V, E> {
+                fn product<I: Iterator<Item = $type_name<A, E>>>(iter: I) -> Self {
+                    iter.map(Result::from).product::<Result<V, E>>().into()
+                }
+            }
+        });
+        }
+        });
+
+        $crate::__alias_result_flag!(@flags [$vis], [$($bound)*], [$($method),*], [$($skip),*], $type_name, $ok_variant, $err_variant, [$($flag),*]);
     };
-    ($type_name:ident, $ok_variant:ident, $err_variant:ident, traits: [$($trait:path),*]) => {
-        $crate::alias_result!($type_name, $ok_variant, $err_variant, [$($trait),*]);
+    (@enum [$(#[$ok_attr:meta])*] [$(#[$err_attr:meta])*] [$vis:vis] [$type_name:ident, $ok_variant:ident, $err_variant:ident], [$T:ident, $E:ident], [$($bound:tt)*], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        $crate::__alias_must_use!([$($skip),*], concat!("this `", stringify!($type_name), "` may hold the `", stringify!($err_variant), "` variant, which should be handled"), {
+            #[derive($($trait),*)]
+            $(#[$attr])*
+            $vis enum $type_name<$T: $($bound)*, $E: $($bound)*> {
+                $(#[$ok_attr])*
+                $ok_variant($T),
+                $(#[$err_attr])*
+                $err_variant($E),
+            }
+        });
     };
-    ($type_name:ident, $ok_variant:ident, $err_variant:ident, implement_try) => {
-        $crate::alias_result!($type_name, $ok_variant, $err_variant, [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash], implement_try);
+    ($type_name:ident <$($lifetime:lifetime),+ $(,)?> $($rest:tt)*) => {
+        compile_error!(concat!(
+            "`alias_result!` does not take lifetime parameters, its payloads can borrow through the generic parameters, e.g. `", stringify!($type_name), "<&'a str, Error>`. ",
+            "Use `borrowed: Name` for a `Name<'a, ..>` alias holding references",
+        ));
     };
-    ($type_name:ident, $ok_variant:ident, $err_variant:ident, traits: [$($trait:path),*], implement_try) => {
-        $crate::alias_result!($type_name, $ok_variant, $err_variant, [$($trait),*], implement_try);
+    ($($input:tt)*) => {
+        compile_error!(concat!(
+            "expected `alias_result!(Name, OkVariant, ErrVariant, options...)`, got `alias_result!(", stringify!($($input)*), ")`",
+        ));
     };
-    ($type_name:ident, $ok_variant:ident, $err_variant:ident, [$($trait:path),*]) => {
-        $crate::alias_result!($type_name, $ok_variant, $err_variant, [$($trait),*], );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_result_flag {
+    (@flags $vis:tt, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident, [$($flag:ident),*]) => {
+        $(
+            $crate::__alias_result_flag!($flag, $vis, $bounds, $methods, $skip, $type_name, $ok_variant, $err_variant);
+        )*
     };
-    ($type_name:ident, $ok_variant:ident, $err_variant:ident, [$($trait:path),*], $($implement_try:ident)?) => {
-        paste::paste! {
-        #[derive($($trait),*)]
-        pub enum $type_name<T, E> {
-            $ok_variant(T),
-            $err_variant(E),
+    (implement_try, [$vis:vis], [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        impl<T: $($bound)*, E: $($bound)*> ::core::ops::Try for $type_name<T, E>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type Output = T;
+            type Residual = $type_name<::core::convert::Infallible, E>;
+
+            fn from_output(output: Self::Output) -> Self {
+                $type_name::$ok_variant(output)
+            }
+
+            fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, Self::Output> {
+                match self {
+                    $type_name::$ok_variant(v) => ::core::ops::ControlFlow::Continue(v),
+                    $type_name::$err_variant(e) => ::core::ops::ControlFlow::Break($type_name::$err_variant(e)),
+                }
+            }
+        }
+
+        impl<T: $($bound)*, E: $($bound)*> ::core::ops::FromResidual for $type_name<T, E>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            fn from_residual(residual: $type_name<::core::convert::Infallible, E>) -> Self {
+                match residual {
+                    $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        impl<T: $($bound)*, E: $($bound)*> ::core::ops::Residual<T> for $type_name<::core::convert::Infallible, E>
+        where
+            ::core::convert::Infallible: $($bound)*,
+        {
+            type TryType = $type_name<T, E>;
+        }
+    };
+    (implement_unchecked, [$vis:vis], [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        $crate::__alias_forbid_unsafe!(@unsafe implement_unchecked, $skip, {
+            paste::paste! {
+            #[allow(dead_code)]
+            impl<T: $($bound)*, E: $($bound)*> $type_name<T, E> {
+                /// Behaves like [`Result::unwrap_unchecked`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_unchecked)
+                ///
+                /// # Safety
+                ///
+                #[doc = concat!("Calling this method on a `", stringify!($err_variant), "` is undefined behavior.")]
+                $vis unsafe fn unwrap_unchecked(self) -> T {
+                    match self {
+                        $type_name::$ok_variant(v) => v,
+                        // SAFETY: the caller guarantees this is the ok variant.
+                        $type_name::$err_variant(_) => unsafe { ::core::hint::unreachable_unchecked() },
+                    }
+                }
+
+                $crate::__alias_doc_alias!($err_variant, Err, "unwrap_err_unchecked", {
+                    #[doc = concat!("Returns the contained [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") value without checking that the value is `", stringify!($err_variant), "`.")]
+                    ///
+                    /// Behaves like [`Result::unwrap_err_unchecked`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_err_unchecked)
+                    ///
+                    /// # Safety
+                    ///
+                    #[doc = concat!("Calling this method on a `", stringify!($ok_variant), "` is undefined behavior.")]
+                    $vis unsafe fn [<unwrap_ $err_variant:snake _unchecked>](self) -> E {
+                        match self {
+                            // SAFETY: the caller guarantees this is the err variant.
+                            $type_name::$ok_variant(_) => unsafe { ::core::hint::unreachable_unchecked() },
+                            $type_name::$err_variant(e) => e,
+                        }
+                    }
+                });
+            }
+            }
+        });
+    };
+    (implement_termination, [$vis:vis], [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        impl<T: std::process::Termination + $($bound)*, E: ::core::fmt::Debug + $($bound)*> std::process::Termination for $type_name<T, E> {
+            fn report(self) -> std::process::ExitCode {
+                Result::from(self).report()
+            }
+        }
+    };
+    (implement_error, [$vis:vis], [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        impl<T: ::core::fmt::Display + $($bound)*, E: ::core::fmt::Display + $($bound)*> ::core::fmt::Display for $type_name<T, E> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $type_name::$ok_variant(v) => v.fmt(f),
+                    $type_name::$err_variant(e) => e.fmt(f),
+                }
+            }
         }
 
-        impl<T, E> $type_name<T, E> {
-            /// Behaves like [`Result::is_ok`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_ok)
-            pub fn [<is_ $ok_variant:lower>](&self) -> bool {
-                matches!(self, $type_name::$ok_variant(_))
+        impl<T: $($bound)*, E: $($bound)*> ::core::error::Error for $type_name<T, E>
+        where
+            T: ::core::fmt::Display,
+            E: ::core::error::Error + 'static,
+            Self: ::core::fmt::Debug,
+        {
+            fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                match self {
+                    $type_name::$ok_variant(_) => None,
+                    $type_name::$err_variant(e) => Some(e),
+                }
+            }
+        }
+    };
+    (default_ok, [$vis:vis], [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        impl<T: Default + $($bound)*, E: $($bound)*> Default for $type_name<T, E> {
+            #[doc = concat!("Returns [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") holding the default value.")]
+            fn default() -> Self {
+                $type_name::$ok_variant(T::default())
             }
+        }
+    };
+    (default_err, [$vis:vis], [$($bound:tt)*], $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        impl<T: $($bound)*, E: Default + $($bound)*> Default for $type_name<T, E> {
+            #[doc = concat!("Returns [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") holding the default value.")]
+            fn default() -> Self {
+                $type_name::$err_variant(E::default())
+            }
+        }
+    };
+    (err_first, [$vis:vis], $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {};
+    (assert_same_size, [$vis:vis], $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        $crate::__alias_assert_same_size!($bounds, [$type_name<u8, u16> => Result<u8, u16>], [
+            $type_name<bool, ()> => Result<bool, ()>,
+            $type_name<::core::num::NonZeroU32, ()> => Result<::core::num::NonZeroU32, ()>,
+            $type_name<&'static u8, ()> => Result<&'static u8, ()>,
+            $type_name<&'static [u8], ::core::convert::Infallible> => Result<&'static [u8], ::core::convert::Infallible>
+        ]);
+    };
+    (generate_tests, $vis:tt, $bounds:tt, $methods:tt, $skip:tt, $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        $crate::__alias_skip!(@tested __alias_result_flag [@generate_tests, $vis, $bounds, $methods], $skip, [$type_name, $ok_variant, $err_variant]);
+    };
+    (@generate_tests, [$vis:vis], [$($bound:tt)*], [$($method:ident),*], [$($skip:ident),*], $type_name:ident, $ok_variant:ident, $err_variant:ident) => {
+        paste::paste! {
+            #[cfg(test)]
+            mod [<$type_name:snake _conformance>] {
+                use super::*;
+
+                const VALUES: [Result<u8, u16>; 2] = [Ok(1), Err(2)];
+
+                fn alias(value: Result<u8, u16>) -> $type_name<u8, u16> {
+                    value.into()
+                }
+
+                #[test]
+                fn round_trip() {
+                    for value in VALUES {
+                        assert_eq!(Result::from(alias(value)), value);
+                    }
+                }
+
+                #[test]
+                fn size_equivalence() {
+                    assert_eq!(::core::mem::size_of::<$type_name<u8, u16>>(), ::core::mem::size_of::<Result<u8, u16>>());
+                    $crate::__alias_unbounded!([$($bound)*], {
+                        assert_eq!(::core::mem::size_of::<$type_name<&u8, ()>>(), ::core::mem::size_of::<Result<&u8, ()>>());
+                    });
+                }
+
+                #[test]
+                #[allow(unused_variables)]
+                fn methods() {
+                    for value in VALUES {
+                        assert_eq!(alias(value).[<is_ $ok_variant:snake>](), value.is_ok());
+                        assert_eq!(alias(value).[<is_ $err_variant:snake>](), value.is_err());
+                        assert_eq!(alias(value).[<is_ $ok_variant:snake _and>](|v| *v > 0), value.is_ok_and(|v| v > 0));
+                        assert_eq!(alias(value).[<is_ $err_variant:snake _and>](|e| *e > 0), value.is_err_and(|e| e > 0));
+                        assert_eq!(alias(value).[<$ok_variant:snake _eq>](&1), value == Ok(1));
+                        assert_eq!(alias(value).[<$err_variant:snake _eq>](&2), value == Err(2));
+                        assert_eq!(alias(value).[<as_ $ok_variant:snake>]().copied(), value.as_ref().ok().copied());
+                        assert_eq!(alias(value).[<as_ $err_variant:snake>]().copied(), value.as_ref().err().copied());
+
+                        let (mut aliased, mut expected) = (alias(value), value);
+                        assert_eq!(aliased.[<as_ $ok_variant:snake _mut>]().map(|v| *v), expected.as_mut().ok().map(|v| *v));
+                        assert_eq!(aliased.[<as_ $err_variant:snake _mut>]().map(|e| *e), expected.as_mut().err().map(|e| *e));
+
+                        $crate::__alias_unbounded!([$($bound)*], {
+                            $crate::__alias_skip!(as_ref, [$($skip),*], {
+                                assert_eq!(Result::from(alias(value).as_ref()), value.as_ref());
+                            });
+                            $crate::__alias_skip!(as_mut, [$($skip),*], {
+                                let (mut aliased, mut expected) = (alias(value), value);
+                                assert_eq!(Result::from(aliased.as_mut()), expected.as_mut());
+                            });
+                        });
+
+                        $crate::__alias_methods!(iterators, [$($method),*], {
+                            $crate::__alias_skip!(iter, [$($skip),*], {
+                                assert!(alias(value).iter().eq(value.iter()));
+                            });
+                            $crate::__alias_skip!(iter_mut, [$($skip),*], {
+                                let (mut aliased, mut expected) = (alias(value), value);
+                                assert!(aliased.iter_mut().eq(expected.iter_mut()));
+                            });
+                            assert!(alias(value).into_iter().eq(value));
+                        });
 
-            /// Behaves like [`Result::is_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_err)
-            pub fn [<is_ $err_variant:lower>](&self) -> bool {
-                matches!(self, $type_name::$err_variant(_))
+                        $crate::__alias_methods!(conversions, [$($method),*], {
+                            $crate::__alias_keyword!(@if [<$ok_variant:snake>], {}, {
+                                assert_eq!(alias(value).[<$ok_variant:snake>](), value.ok());
+                            });
+                            $crate::__alias_keyword!(@if [<$err_variant:snake>], {}, {
+                                assert_eq!(alias(value).[<$err_variant:snake>](), value.err());
+                            });
+                        });
+
+                        $crate::__alias_methods!(combinators, [$($method),*], {
+                            $crate::__alias_skip!(map, [$($skip),*], {
+                                assert_eq!(Result::from(alias(value).map(u32::from)), value.map(u32::from));
+                            });
+                            $crate::__alias_skip!(map_err, [$($skip),*], {
+                                assert_eq!(Result::from(alias(value).map_err(u32::from)), value.map_err(u32::from));
+                            });
+                            $crate::__alias_skip!(map_or, [$($skip),*], {
+                                assert_eq!(alias(value).map_or(0, u16::from), value.map_or(0, u16::from));
+                            });
+                            $crate::__alias_skip!(map_or_else, [$($skip),*], {
+                                assert_eq!(alias(value).map_or_else(|e| e, u16::from), value.map_or_else(|e| e, u16::from));
+                            });
+                            $crate::__alias_skip!(inspect, [$($skip),*], {
+                                let (mut seen, mut expected) = (None, None);
+                                let _ = alias(value).inspect(|v| seen = Some(*v));
+                                let _ = value.inspect(|v| expected = Some(*v));
+                                assert_eq!(seen, expected);
+                            });
+                            let (mut seen, mut expected) = (None, None);
+                            let _ = alias(value).[<inspect_ $err_variant:snake>](|e| seen = Some(*e));
+                            let _ = value.inspect_err(|e| expected = Some(*e));
+                            assert_eq!(seen, expected);
+                            $crate::__alias_skip!(and, [$($skip),*], {
+                                assert_eq!(Result::from(alias(value).and($type_name::from(Ok::<u32, u16>(3)))), value.and(Ok(3)));
+                            });
+                            $crate::__alias_skip!(and_then, [$($skip),*], {
+                                assert_eq!(
+                                    Result::from(alias(value).and_then(|v| $type_name::from(Ok::<u32, u16>(u32::from(v) + 1)))),
+                                    value.and_then(|v| Ok(u32::from(v) + 1))
+                                );
+                            });
+                            $crate::__alias_skip!(or, [$($skip),*], {
+                                assert_eq!(Result::from(alias(value).or($type_name::from(Ok::<u8, u32>(3)))), value.or(Ok(3)));
+                            });
+                            $crate::__alias_skip!(or_else, [$($skip),*], {
+                                assert_eq!(
+                                    Result::from(alias(value).or_else(|e| $type_name::from(Err::<u8, u32>(u32::from(e) + 1)))),
+                                    value.or_else(|e| Err(u32::from(e) + 1))
+                                );
+                            });
+                        });
+
+                        $crate::__alias_skip!(no_panics, [$($skip),*], {
+                            if let Ok(v) = value {
+                                $crate::__alias_skip!(unwrap, [$($skip),*], {
+                                    assert_eq!(alias(value).unwrap(), v);
+                                });
+                                $crate::__alias_skip!(expect, [$($skip),*], {
+                                    assert_eq!(alias(value).expect("ok value"), v);
+                                });
+                            }
+                            if let Err(e) = value {
+                                assert_eq!(alias(value).[<unwrap_ $err_variant:snake>](), e);
+                                assert_eq!(alias(value).[<expect_ $err_variant:snake>]("err value"), e);
+                            }
+                        });
+                        $crate::__alias_skip!(unwrap_or, [$($skip),*], {
+                            assert_eq!(alias(value).unwrap_or(5), value.unwrap_or(5));
+                        });
+                        $crate::__alias_skip!(unwrap_or_else, [$($skip),*], {
+                            assert_eq!(alias(value).unwrap_or_else(|_| 5), value.unwrap_or_else(|_| 5));
+                        });
+                        $crate::__alias_skip!(unwrap_or_default, [$($skip),*], {
+                            assert_eq!(alias(value).unwrap_or_default(), value.unwrap_or_default());
+                        });
+                    }
+
+                    $crate::__alias_unbounded!([$($bound)*], {
+                        $crate::__alias_methods!(combinators, [$($method),*], {
+                            for value in VALUES {
+                                $crate::__alias_skip!(cloned, [$($skip),*], {
+                                    assert_eq!(Result::from($type_name::from(value.as_ref()).cloned()), value);
+                                });
+                                $crate::__alias_skip!(copied, [$($skip),*], {
+                                    assert_eq!(Result::from($type_name::from(value.as_ref()).copied()), value);
+                                });
+                                $crate::__alias_skip!(flatten, [$($skip),*], {
+                                    let nested = value.map(Ok::<u8, u16>);
+                                    assert_eq!(Result::from($type_name::from(nested).flatten()), nested.and_then(::core::convert::identity));
+                                    let nested = $type_name::<$type_name<u8, u16>, u16>::from(value.map(|v| alias(Ok(v))));
+                                    assert_eq!(Result::from(nested.flatten()), value);
+                                });
+                            }
+                        });
+                        $crate::__alias_methods!(conversions, [$($method),*], {
+                            $crate::__alias_skip!(transpose, [$($skip),*], {
+                                for value in VALUES {
+                                    let nested = value.map(Some);
+                                    assert_eq!($type_name::from(nested).transpose().map(Result::from), nested.transpose());
+                                }
+                            });
+                        });
+
+                        $crate::__alias_methods!(conversions, [$($method),*], {
+                            assert_eq!($type_name::<u8, ::core::convert::Infallible>::from(Ok(1)).[<into_ $ok_variant:snake>](), 1);
+                            assert_eq!($type_name::<::core::convert::Infallible, u8>::from(Err(2)).[<into_ $err_variant:snake>](), 2);
+                        });
+                        $crate::__alias_skip!(as_deref, [$($skip),*], {
+                            let value: Result<String, u16> = Ok("deref".to_string());
+                            assert_eq!(Result::from($type_name::from(value.clone()).as_deref()), value.as_deref());
+                        });
+                        $crate::__alias_skip!(as_deref_mut, [$($skip),*], {
+                            let mut expected: Result<String, u16> = Ok("deref".to_string());
+                            let mut aliased = $type_name::from(expected.clone());
+                            assert_eq!(Result::from(aliased.as_deref_mut()), expected.as_deref_mut());
+                        });
+                        $crate::__alias_methods!(iterators, [$($method),*], {
+                            assert_eq!(
+                                Result::from(VALUES.into_iter().map(alias).collect::<$type_name<Vec<u8>, u16>>()),
+                                VALUES.into_iter().collect::<Result<Vec<u8>, u16>>()
+                            );
+                        });
+                    });
+
+                    $crate::__alias_methods!(iterators, [$($method),*], {
+                        let values = [Ok(1), Ok(2), Err(3)];
+                        assert_eq!(
+                            Result::from(values.into_iter().map(alias).sum::<$type_name<u8, u16>>()),
+                            values.into_iter().sum::<Result<u8, u16>>()
+                        );
+                        assert_eq!(
+                            Result::from(values[..2].iter().copied().map(alias).product::<$type_name<u8, u16>>()),
+                            values[..2].iter().copied().product::<Result<u8, u16>>()
+                        );
+                    });
+                }
             }
+        }
+    };
+    ($flag:ident, $($args:tt)*) => {
+        compile_error!(concat!("`alias_result!` does not accept the `", stringify!($flag), "` option"));
+    };
+}
+
+/// Emits the enum and the impls of an `alias_result!` type whose error type is fixed with
+/// `error = Type`.
+///
+/// The fixed payload type is written into the variant, so the impls can not change it, and the
+/// methods that would, like `map_err`, return a `Result` instead. The generic parameters arrive as
+/// their declaration and their use, like `[<T: Send>] [<T>]`, along with the payload types and the
+/// type that `map` and `and_then` produce. Bounds on the fixed types are written as
+/// `for<'a> Type: Trait`, which the compiler checks where the method is used instead of where it
+/// is declared.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_result_fixed {
+    ([$vis:vis] [$type_name:ident, $ok_variant:ident, $err_variant:ident] [$($decl:tt)*] [$($generic:tt)*] [$ok_type:ty, $error_type:ty] [$($mapped:tt)*] [$($bound:tt)*], [$($method:ident),*], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], [$($flag:ident),*]) => {
+        $crate::__alias_result_fixed!(@methods $type_name [$($method),*]);
+        $crate::__alias_result_fixed!(@skip $type_name [$($skip),*]);
+        $crate::__alias_variant_attrs!($type_name, [$ok_variant, $err_variant], [$($skip),*], __alias_result_fixed ([$vis] [$type_name, $ok_variant, $err_variant] [$($decl)*] [$ok_type, $error_type] [$($skip),*] [$($trait),*] [$(#[$attr])*]));
+
+        paste::paste! {
+        #[allow(dead_code)]
+        impl $($decl)* $type_name $($generic)* {
+            $crate::__alias_doc_alias!($ok_variant, Ok, "is_ok", {
+                $crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($ok_variant), "`, consider wrapping this in an `assert!()` instead"), {
+                    #[doc = concat!("Returns `true` if the value is [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ").")]
+                    ///
+                    /// Behaves like [`Result::is_ok`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_ok)
+                    $vis fn [<is_ $ok_variant:snake>](&self) -> bool {
+                        matches!(self, $type_name::$ok_variant(_))
+                    }
+                });
+            });
+
+            $crate::__alias_doc_alias!($err_variant, Err, "is_err", {
+                $crate::__alias_must_use!([$($skip),*], concat!("if you intended to assert that this is `", stringify!($err_variant), "`, consider wrapping this in an `assert!()` instead"), {
+                    #[doc = concat!("Returns `true` if the value is [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ").")]
+                    ///
+                    /// Behaves like [`Result::is_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_err)
+                    $vis fn [<is_ $err_variant:snake>](&self) -> bool {
+                        matches!(self, $type_name::$err_variant(_))
+                    }
+                });
+            });
+
+            $crate::__alias_doc_alias!($ok_variant, Ok, "is_ok_and", {
+                $crate::__alias_must_use!([$($skip),*], {
+                    /// Behaves like [`Result::is_ok_and`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_ok_and)
+                    $vis fn [<is_ $ok_variant:snake _and>]<F: FnOnce(&$ok_type) -> bool>(&self, f: F) -> bool {
+                        match self {
+                            $type_name::$ok_variant(v) => f(v),
+                            $type_name::$err_variant(_) => false,
+                        }
+                    }
+                });
+            });
+
+            $crate::__alias_doc_alias!($err_variant, Err, "is_err_and", {
+                $crate::__alias_must_use!([$($skip),*], {
+                    /// Behaves like [`Result::is_err_and`](https://doc.rust-lang.org/std/result/enum.Result.html#method.is_err_and)
+                    $vis fn [<is_ $err_variant:snake _and>]<F: FnOnce(&$error_type) -> bool>(&self, f: F) -> bool {
+                        match self {
+                            $type_name::$ok_variant(_) => false,
+                            $type_name::$err_variant(e) => f(e),
+                        }
+                    }
+                });
+            });
 
-            /// Behaves like [`Result::as_ref`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_ref) for the Ok variant
-            pub fn [<as_ $ok_variant:lower>](&self) -> Option<&T> {
+            #[doc = concat!("Returns a reference to the [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") value, if present.")]
+            $vis fn [<as_ $ok_variant:snake>](&self) -> Option<&$ok_type> {
                 match self {
                     $type_name::$ok_variant(v) => Some(v),
-                    _ => None,
+                    $type_name::$err_variant(_) => None,
                 }
             }
 
-            /// Behaves like [`Result::as_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_mut) for the Ok variant
-            pub fn [<as_ $ok_variant:lower _mut>](&mut self) -> Option<&mut T> {
+            #[doc = concat!("Returns a mutable reference to the [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") value, if present.")]
+            $vis fn [<as_ $ok_variant:snake _mut>](&mut self) -> Option<&mut $ok_type> {
                 match self {
                     $type_name::$ok_variant(v) => Some(v),
-                    _ => None,
+                    $type_name::$err_variant(_) => None,
                 }
             }
 
-            /// Behaves like [`Result::as_ref`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_ref) for the Err variant
-            pub fn [<as_ $err_variant:lower>](&self) -> Option<&E> {
+            #[doc = concat!("Returns a reference to the [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") value, if present.")]
+            $vis fn [<as_ $err_variant:snake>](&self) -> Option<&$error_type> {
                 match self {
+                    $type_name::$ok_variant(_) => None,
                     $type_name::$err_variant(e) => Some(e),
-                    _ => None,
                 }
             }
 
-            /// Behaves like [`Result::as_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.as_mut) for the Err variant
-            pub fn [<as_ $err_variant:lower _mut>](&mut self) -> Option<&mut E> {
+            #[doc = concat!("Returns a mutable reference to the [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") value, if present.")]
+            $vis fn [<as_ $err_variant:snake _mut>](&mut self) -> Option<&mut $error_type> {
                 match self {
+                    $type_name::$ok_variant(_) => None,
                     $type_name::$err_variant(e) => Some(e),
-                    _ => None,
                 }
             }
 
+            $crate::__alias_doc_alias!($ok_variant, Ok, "ok", {
+                /// Behaves like [`Result::ok`](https://doc.rust-lang.org/std/result/enum.Result.html#method.ok)
+                $vis fn [<$ok_variant:snake>](self) -> Option<$ok_type> {
+                    match self {
+                        $type_name::$ok_variant(v) => Some(v),
+                        $type_name::$err_variant(_) => None,
+                    }
+                }
+            });
+
+            $crate::__alias_doc_alias!($err_variant, Err, "err", {
+                /// Behaves like [`Result::err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.err)
+                $vis fn [<$err_variant:snake>](self) -> Option<$error_type> {
+                    match self {
+                        $type_name::$ok_variant(_) => None,
+                        $type_name::$err_variant(e) => Some(e),
+                    }
+                }
+            });
+
+            /// Behaves like [`Result::iter`](https://doc.rust-lang.org/std/result/enum.Result.html#method.iter)
+            $vis fn iter(&self) -> ::core::option::IntoIter<&$ok_type> {
+                self.[<as_ $ok_variant:snake>]().into_iter()
+            }
+
+            /// Behaves like [`Result::iter_mut`](https://doc.rust-lang.org/std/result/enum.Result.html#method.iter_mut)
+            $vis fn iter_mut(&mut self) -> ::core::option::IntoIter<&mut $ok_type> {
+                self.[<as_ $ok_variant:snake _mut>]().into_iter()
+            }
+
             /// Behaves like [`Result::map`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map)
-            pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U, E> {
+            $vis fn map<U: $($bound)*, F: FnOnce($ok_type) -> U>(self, f: F) -> $($mapped)* {
                 match self {
-                    $type_name::$ok_variant(v) => $type_name::$ok_variant(f(v)),
-                    $type_name::$err_variant(e) => $type_name::$err_variant(e),
+                    $type_name::$ok_variant(v) => Ok(f(v)),
+                    $type_name::$err_variant(e) => Err(e),
+                }
+                .into()
+            }
+
+            /// Behaves like [`Result::map_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map_err),
+            /// returning a `Result`, since the error type of this type is fixed.
+            $vis fn map_err<F, O: FnOnce($error_type) -> F>(self, op: O) -> Result<$ok_type, F> {
+                match self {
+                    $type_name::$ok_variant(v) => Ok(v),
+                    $type_name::$err_variant(e) => Err(op(e)),
                 }
             }
 
-            /// Behaves like [`Result::map_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map_err)
-            pub fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> $type_name<T, F> {
+            /// Behaves like [`Result::map_or`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map_or)
+            $vis fn map_or<U, F: FnOnce($ok_type) -> U>(self, default: U, f: F) -> U {
                 match self {
-                    $type_name::$ok_variant(v) => $type_name::$ok_variant(v),
-                    $type_name::$err_variant(e) => $type_name::$err_variant(op(e)),
+                    $type_name::$ok_variant(v) => f(v),
+                    $type_name::$err_variant(_) => default,
                 }
             }
 
-            /// Behaves like [`Result::unwrap`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap)
-            pub fn unwrap(self) -> T {
+            /// Behaves like [`Result::map_or_else`](https://doc.rust-lang.org/std/result/enum.Result.html#method.map_or_else)
+            $vis fn map_or_else<U, D: FnOnce($error_type) -> U, F: FnOnce($ok_type) -> U>(self, default: D, f: F) -> U {
                 match self {
-                    $type_name::$ok_variant(v) => v,
-                    $type_name::$err_variant(_) => {
-                        panic!("called `unwrap()` on an `{}`", stringify!($err_variant))
-                    }
+                    $type_name::$ok_variant(v) => f(v),
+                    $type_name::$err_variant(e) => default(e),
+                }
+            }
+
+            /// Behaves like [`Result::and_then`](https://doc.rust-lang.org/std/result/enum.Result.html#method.and_then)
+            $vis fn and_then<U: $($bound)*, F: FnOnce($ok_type) -> $($mapped)*>(self, op: F) -> $($mapped)* {
+                match self {
+                    $type_name::$ok_variant(v) => op(v),
+                    $type_name::$err_variant(e) => Err(e).into(),
+                }
+            }
+
+            /// Behaves like [`Result::or_else`](https://doc.rust-lang.org/std/result/enum.Result.html#method.or_else),
+            /// returning a `Result`, since the error type of this type is fixed.
+            $vis fn or_else<F, O: FnOnce($error_type) -> Result<$ok_type, F>>(self, op: O) -> Result<$ok_type, F> {
+                match self {
+                    $type_name::$ok_variant(v) => Ok(v),
+                    $type_name::$err_variant(e) => op(e),
+                }
+            }
+
+            /// Behaves like [`Result::inspect`](https://doc.rust-lang.org/std/result/enum.Result.html#method.inspect)
+            $vis fn inspect<F: FnOnce(&$ok_type)>(self, f: F) -> Self {
+                if let $type_name::$ok_variant(v) = &self {
+                    f(v);
                 }
+                self
             }
 
+            $crate::__alias_doc_alias!($err_variant, Err, "inspect_err", {
+                /// Behaves like [`Result::inspect_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.inspect_err)
+                $vis fn [<inspect_ $err_variant:snake>]<F: FnOnce(&$error_type)>(self, f: F) -> Self {
+                    if let $type_name::$err_variant(e) = &self {
+                        f(e);
+                    }
+                    self
+                }
+            });
+
+            $crate::__alias_skip!(no_panics, [$($skip),*], {
+                /// Behaves like [`Result::unwrap`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap)
+                #[track_caller]
+                $vis fn unwrap(self) -> $ok_type
+                where
+                    for<'a> $error_type: ::core::fmt::Debug,
+                {
+                    match self {
+                        $type_name::$ok_variant(v) => v,
+                        $type_name::$err_variant(e) => {
+                            panic!("called `unwrap()` on an `{}` value: {:?}", stringify!($err_variant), e)
+                        }
+                    }
+                }
+
+                /// Behaves like [`Result::expect`](https://doc.rust-lang.org/std/result/enum.Result.html#method.expect)
+                #[track_caller]
+                $vis fn expect(self, msg: &str) -> $ok_type
+                where
+                    for<'a> $error_type: ::core::fmt::Debug,
+                {
+                    match self {
+                        $type_name::$ok_variant(v) => v,
+                        $type_name::$err_variant(e) => panic!("{}: {:?}", msg, e),
+                    }
+                }
+
+                $crate::__alias_doc_alias!($err_variant, Err, "unwrap_err", {
+                    /// Behaves like [`Result::unwrap_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_err)
+                    #[track_caller]
+                    $vis fn [<unwrap_ $err_variant:snake>](self) -> $error_type
+                    where
+                        for<'a> $ok_type: ::core::fmt::Debug,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => {
+                                panic!("called `unwrap_{}()` on a `{}` value: {:?}", stringify!([<$err_variant:snake>]), stringify!($ok_variant), v)
+                            }
+                            $type_name::$err_variant(e) => e,
+                        }
+                    }
+                });
+
+                $crate::__alias_doc_alias!($err_variant, Err, "expect_err", {
+                    /// Behaves like [`Result::expect_err`](https://doc.rust-lang.org/std/result/enum.Result.html#method.expect_err)
+                    #[track_caller]
+                    $vis fn [<expect_ $err_variant:snake>](self, msg: &str) -> $error_type
+                    where
+                        for<'a> $ok_type: ::core::fmt::Debug,
+                    {
+                        match self {
+                            $type_name::$ok_variant(v) => panic!("{}: {:?}", msg, v),
+                            $type_name::$err_variant(e) => e,
+                        }
+                    }
+                });
+            });
+
             /// Behaves like [`Result::unwrap_or`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_or)
-            pub fn unwrap_or(self, default: T) -> T {
+            $vis fn unwrap_or(self, default: $ok_type) -> $ok_type {
                 match self {
                     $type_name::$ok_variant(v) => v,
                     $type_name::$err_variant(_) => default,
@@ -187,16 +1880,28 @@ macro_rules! alias_result {
             }
 
             /// Behaves like [`Result::unwrap_or_else`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_or_else)
-            pub fn unwrap_or_else<F: FnOnce(E) -> T>(self, op: F) -> T {
+            $vis fn unwrap_or_else<F: FnOnce($error_type) -> $ok_type>(self, op: F) -> $ok_type {
                 match self {
                     $type_name::$ok_variant(v) => v,
                     $type_name::$err_variant(e) => op(e),
                 }
             }
+
+            /// Behaves like [`Result::unwrap_or_default`](https://doc.rust-lang.org/std/result/enum.Result.html#method.unwrap_or_default)
+            $vis fn unwrap_or_default(self) -> $ok_type
+            where
+                for<'a> $ok_type: Default,
+            {
+                match self {
+                    $type_name::$ok_variant(v) => v,
+                    $type_name::$err_variant(_) => Default::default(),
+                }
+            }
+        }
         }
 
-        impl<T, E> From<Result<T, E>> for $type_name<T, E> {
-            fn from(result: Result<T, E>) -> Self {
+        impl $($decl)* From<Result<$ok_type, $error_type>> for $type_name $($generic)* {
+            fn from(result: Result<$ok_type, $error_type>) -> Self {
                 match result {
                     Ok(v) => $type_name::$ok_variant(v),
                     Err(e) => $type_name::$err_variant(e),
@@ -204,45 +1909,110 @@ macro_rules! alias_result {
             }
         }
 
-        impl<T, E> From<$type_name<T, E>> for Result<T, E> {
-            fn from(val: $type_name<T, E>) -> Self {
+        impl $($decl)* From<$type_name $($generic)*> for Result<$ok_type, $error_type> {
+            fn from(val: $type_name $($generic)*) -> Self {
                 match val {
                     $type_name::$ok_variant(v) => Ok(v),
                     $type_name::$err_variant(e) => Err(e),
                 }
             }
         }
-        }
 
-        $(
-            let _ = stringify!($implement_try);
-            paste::paste! {
-                impl<T, E> std::ops::Try for $type_name<T, E> {
-                    type Output = T;
-                    type Residual = $type_name<std::convert::Infallible, E>;
+        impl $($decl)* IntoIterator for $type_name $($generic)* {
+            type Item = $ok_type;
+            type IntoIter = ::core::option::IntoIter<$ok_type>;
 
-                    fn from_output(output: Self::Output) -> Self {
-                        $type_name::$ok_variant(output)
-                    }
+            fn into_iter(self) -> Self::IntoIter {
+                Result::from(self).ok().into_iter()
+            }
+        }
 
-                    fn branch(self) -> std::ops::ControlFlow<Self::Residual, Self::Output> {
-                        match self {
-                            $type_name::$ok_variant(v) => std::ops::ControlFlow::Continue(v),
-                            $type_name::$err_variant(e) => std::ops::ControlFlow::Break($type_name::$err_variant(e)),
-                        }
-                    }
+        $crate::__alias_result_fixed!(@flags [$($flag),*] [[$type_name, $ok_variant, $err_variant] [$($decl)*] [$($generic)*] [$ok_type, $error_type]]);
+    };
+    (@enum [$(#[$ok_attr:meta])*] [$(#[$err_attr:meta])*] [$vis:vis] [$type_name:ident, $ok_variant:ident, $err_variant:ident] [$($decl:tt)*] [$ok_type:ty, $error_type:ty] [$($skip:tt),*] [$($trait:path),*] [$(#[$attr:meta])*]) => {
+        $crate::__alias_must_use!([$($skip),*], concat!("this `", stringify!($type_name), "` may hold the `", stringify!($err_variant), "` variant, which should be handled"), {
+            #[derive($($trait),*)]
+            $(#[$attr])*
+            $vis enum $type_name $($decl)* {
+                $(#[$ok_attr])*
+                $ok_variant($ok_type),
+                $(#[$err_attr])*
+                $err_variant($error_type),
+            }
+        });
+    };
+    (@methods $type_name:ident []) => {};
+    (@methods $type_name:ident [$($method:ident),+]) => {
+        compile_error!(concat!("`", stringify!($type_name), "` has a fixed payload type, which does not support `methods:`"));
+    };
+    (@skip $type_name:ident [(attrs $($attrs:tt)*) $(, $rest:tt)*]) => {
+        $crate::__alias_result_fixed!(@skip $type_name [$($rest),*]);
+    };
+    (@skip $type_name:ident [no_must_use $(, $rest:tt)*]) => {
+        $crate::__alias_result_fixed!(@skip $type_name [$($rest),*]);
+    };
+    (@skip $type_name:ident [no_panics $(, $rest:tt)*]) => {
+        $crate::__alias_result_fixed!(@skip $type_name [$($rest),*]);
+    };
+    (@skip $type_name:ident [$other:tt $(, $rest:tt)*]) => {
+        compile_error!(concat!("`", stringify!($type_name), "` has a fixed payload type, which does not support `", stringify!($other), "`"));
+    };
+    (@skip $type_name:ident []) => {};
+    (@flags [$($flag:ident),*] $args:tt) => {
+        $($crate::__alias_result_fixed!(@flag $flag $args);)*
+    };
+    (@flag implement_error [[$type_name:ident, $ok_variant:ident, $err_variant:ident] [$($decl:tt)*] [$($generic:tt)*] [$ok_type:ty, $error_type:ty]]) => {
+        impl $($decl)* ::core::fmt::Display for $type_name $($generic)*
+        where
+            for<'a> $ok_type: ::core::fmt::Display,
+            for<'a> $error_type: ::core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $type_name::$ok_variant(v) => v.fmt(f),
+                    $type_name::$err_variant(e) => e.fmt(f),
                 }
+            }
+        }
 
-                impl<T, E> std::ops::FromResidual for $type_name<T, E> {
-                    fn from_residual(residual: $type_name<std::convert::Infallible, E>) -> Self {
-                        match residual {
-                            $type_name::$err_variant(e) => $type_name::$err_variant(e),
-                            _ => unreachable!(),
-                        }
-                    }
+        impl $($decl)* ::core::error::Error for $type_name $($generic)*
+        where
+            for<'a> $ok_type: ::core::fmt::Display,
+            for<'a> $error_type: ::core::error::Error + 'static,
+            for<'a> Self: ::core::fmt::Debug,
+        {
+            fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                match self {
+                    $type_name::$ok_variant(_) => None,
+                    $type_name::$err_variant(e) => Some(e),
                 }
             }
-        )?
+        }
+    };
+    (@flag default_ok [[$type_name:ident, $ok_variant:ident, $err_variant:ident] [$($decl:tt)*] [$($generic:tt)*] [$ok_type:ty, $error_type:ty]]) => {
+        impl $($decl)* Default for $type_name $($generic)*
+        where
+            for<'a> $ok_type: Default,
+        {
+            #[doc = concat!("Returns [`", stringify!($ok_variant), "`](Self::", stringify!($ok_variant), ") holding the default value.")]
+            fn default() -> Self {
+                $type_name::$ok_variant(Default::default())
+            }
+        }
+    };
+    (@flag default_err [[$type_name:ident, $ok_variant:ident, $err_variant:ident] [$($decl:tt)*] [$($generic:tt)*] [$ok_type:ty, $error_type:ty]]) => {
+        impl $($decl)* Default for $type_name $($generic)*
+        where
+            for<'a> $error_type: Default,
+        {
+            #[doc = concat!("Returns [`", stringify!($err_variant), "`](Self::", stringify!($err_variant), ") holding the default value.")]
+            fn default() -> Self {
+                $type_name::$err_variant(Default::default())
+            }
+        }
+    };
+    (@flag $flag:ident [[$type_name:ident, $ok_variant:ident, $err_variant:ident] $($args:tt)*]) => {
+        compile_error!(concat!("`", stringify!($type_name), "` has a fixed payload type, which does not support the `", stringify!($flag), "` option"));
     };
 }
 
@@ -267,4 +2037,107 @@ mod tests {
             std::mem::size_of::<Result<NonZeroU32, i32>>()
         );
     }
+
+    #[test]
+    #[should_panic(expected = "should succeed: \"oops\"")]
+    fn expect_reports_error() {
+        alias_result!(Response, Success, Failure);
+
+        Response::<u8, &str>::Failure("oops").expect("should succeed");
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap()` on an `Failure` value: \"oops\"")]
+    fn unwrap_reports_error() {
+        alias_result!(Response, Success, Failure);
+
+        Response::<u8, &str>::Failure("oops").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_failure()` on a `Success` value: 3")]
+    fn unwrap_failure_reports_value() {
+        alias_result!(Response, Success, Failure);
+
+        Response::<u8, &str>::Success(3).unwrap_failure();
+    }
+
+    #[test]
+    fn termination_report() {
+        use std::process::{ExitCode, Termination};
+
+        alias_result!(Outcome, Success, Failure, implement_termination);
+
+        assert_eq!(Outcome::<(), &str>::Success(()).report(), ExitCode::SUCCESS);
+        assert_eq!(
+            Outcome::<(), &str>::Failure("failed").report(),
+            ExitCode::FAILURE
+        );
+    }
+
+    #[test]
+    fn err_first() {
+        alias_result!(Check, Passed, Failed, err_first);
+
+        let mut checks: Vec<Check<u8, &str>> =
+            vec![Check::Passed(1), Check::Failed("b"), Check::Failed("a")];
+        checks.sort();
+        assert_eq!(
+            checks,
+            [Check::Failed("a"), Check::Failed("b"), Check::Passed(1)]
+        );
+
+        alias_result!(Score, Scored, Disqualified, err_first, traits: [Debug, PartialEq, PartialOrd]);
+        assert!(Score::<f64, u8>::Disqualified(9) < Score::Scored(0.5));
+        assert!(Score::<f64, u8>::Scored(0.5) < Score::Scored(1.5));
+    }
+
+    #[test]
+    fn fixed_error() {
+        alias_result!(Parsed, Value, Invalid, error = std::num::ParseIntError, traits: [Debug, PartialEq]);
+
+        fn parse(input: &str) -> Parsed<u8> {
+            input.parse().into()
+        }
+
+        assert!(Parsed::Value(1).is_value());
+        assert_eq!(
+            parse("7").map(u16::from).and_then(|v| Parsed::Value(v * 2)),
+            Parsed::Value(14)
+        );
+        assert!(parse("x").is_invalid());
+        assert_eq!(parse("x").map_err(|_| "invalid"), Err("invalid"));
+        assert_eq!(parse("x").unwrap_or_default(), 0);
+        assert_eq!(parse("300").iter().count(), 0);
+    }
+
+    #[test]
+    fn concrete_types() {
+        #[derive(Debug, PartialEq)]
+        struct Session(u32);
+
+        alias_result!(Login, Accepted, Rejected, types: [Session, &'static str], traits: [Debug, PartialEq], vis: private);
+
+        assert!(Login::Accepted(Session(7)).is_accepted());
+        assert_eq!(Login::Rejected("locked").rejected(), Some("locked"));
+        assert_eq!(Login::Accepted(Session(7)).map(|session| session.0), Ok(7));
+        assert_eq!(Login::from(Err("locked")).unwrap_or(Session(0)), Session(0));
+        assert_eq!(Result::from(Login::Accepted(Session(1))), Ok(Session(1)));
+    }
+
+    #[test]
+    fn default_ok() {
+        alias_result!(Lookup, Found, Missing, default_ok, error = &'static str);
+
+        #[derive(Default)]
+        struct Cache {
+            entry: Lookup<Vec<u8>>,
+        }
+
+        let mut cache = Cache::default();
+        assert_eq!(cache.entry, Lookup::Found(Vec::new()));
+        cache.entry = Lookup::Missing("evicted");
+        assert_eq!(std::mem::take(&mut cache.entry), Lookup::Missing("evicted"));
+        assert_eq!(cache.entry, Lookup::Found(Vec::new()));
+    }
 }
@@ -0,0 +1,346 @@
+/// Creates a [`TryRecvError`](std::sync::mpsc::TryRecvError)-like enum with custom variant names.
+///
+/// See [`examples::TryRecvErrorExample`](crate::examples::TryRecvErrorExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_try_recv_error;
+/// use std::sync::mpsc::{channel, TryRecvError};
+///
+/// alias_try_recv_error!(Poll, NothingYet, Closed);
+///
+/// let (sender, receiver) = channel::<i32>();
+/// let empty: Poll = receiver.try_recv().unwrap_err().into();
+/// assert!(empty.is_nothingyet());
+///
+/// drop(sender);
+/// let closed: Poll = receiver.try_recv().unwrap_err().into();
+/// assert!(closed.is_closed());
+/// assert_eq!(TryRecvError::from(closed), TryRecvError::Disconnected);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_try_recv_error;
+/// alias_try_recv_error!(CustomPoll, Pending, Gone, traits: [Debug]);
+/// assert_eq!(format!("{:?}", CustomPoll::Gone), "Gone");
+/// ```
+#[macro_export]
+macro_rules! alias_try_recv_error {
+    ($type_name:ident, $empty_variant:ident, $disconnected_variant:ident $(, $($option:tt)*)?) => {
+        $crate::alias_fieldless!($type_name, std::sync::mpsc::TryRecvError {
+            Empty => $empty_variant,
+            Disconnected => $disconnected_variant,
+        } $(, $($option)*)?);
+    };
+}
+
+/// Creates a [`RecvTimeoutError`](std::sync::mpsc::RecvTimeoutError)-like enum with custom variant names.
+///
+/// See [`examples::RecvTimeoutErrorExample`](crate::examples::RecvTimeoutErrorExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_recv_timeout_error;
+/// use std::sync::mpsc::{channel, RecvTimeoutError};
+/// use std::time::Duration;
+///
+/// alias_recv_timeout_error!(Wait, Expired, Closed);
+///
+/// let (_sender, receiver) = channel::<i32>();
+/// let timed_out: Wait = receiver.recv_timeout(Duration::from_millis(1)).unwrap_err().into();
+/// assert!(timed_out.is_expired());
+/// assert!(!timed_out.is_closed());
+/// assert_eq!(RecvTimeoutError::from(timed_out), RecvTimeoutError::Timeout);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_recv_timeout_error;
+/// alias_recv_timeout_error!(CustomWait, Late, Gone, traits: [Debug]);
+/// assert_eq!(format!("{:?}", CustomWait::Late), "Late");
+/// ```
+#[macro_export]
+macro_rules! alias_recv_timeout_error {
+    ($type_name:ident, $timeout_variant:ident, $disconnected_variant:ident $(, $($option:tt)*)?) => {
+        $crate::alias_fieldless!($type_name, std::sync::mpsc::RecvTimeoutError {
+            Timeout => $timeout_variant,
+            Disconnected => $disconnected_variant,
+        } $(, $($option)*)?);
+    };
+}
+
+/// Creates a [`TrySendError`](std::sync::mpsc::TrySendError)-like enum with custom variant names.
+///
+/// See [`examples::TrySendErrorExample`](crate::examples::TrySendErrorExample) for a generated example.
+///
+/// Both variants hold the message that could not be sent.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_try_send_error;
+/// use std::sync::mpsc::{sync_channel, TrySendError};
+///
+/// alias_try_send_error!(Offer, Busy, Closed);
+///
+/// let (sender, receiver) = sync_channel::<i32>(0);
+/// let busy: Offer<i32> = sender.try_send(1).unwrap_err().into();
+/// assert!(busy.is_busy());
+/// assert_eq!(busy.into_inner(), 1);
+///
+/// drop(receiver);
+/// let closed: Offer<i32> = sender.try_send(2).unwrap_err().into();
+/// assert!(closed.is_closed());
+/// assert_eq!(TrySendError::from(closed), TrySendError::Disconnected(2));
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_try_send_error;
+/// alias_try_send_error!(CustomOffer, Full, Gone, traits: [Debug, Clone]);
+/// let val = CustomOffer::Full(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Full(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_try_send_error {
+    ($type_name:ident, $full_variant:ident, $disconnected_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_try_send_error [$type_name, $full_variant, $disconnected_variant] [T] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $full_variant:ident, $disconnected_variant:ident], [$T:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$T] [$type_name [$full_variant($T), $disconnected_variant($T)]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T> {
+            $full_variant($T),
+            $disconnected_variant($T),
+        }
+
+        #[allow(dead_code)]
+        impl<T> $type_name<T> {
+            /// Returns true if the channel was full
+            $vis fn [<is_ $full_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$full_variant(_))
+            }
+
+            /// Returns true if the receiving half was disconnected
+            $vis fn [<is_ $disconnected_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$disconnected_variant(_))
+            }
+
+            /// Returns the message that could not be sent
+            $vis fn into_inner(self) -> T {
+                match self {
+                    $type_name::$full_variant(message) => message,
+                    $type_name::$disconnected_variant(message) => message,
+                }
+            }
+        }
+
+        impl<T> From<std::sync::mpsc::TrySendError<T>> for $type_name<T> {
+            fn from(error: std::sync::mpsc::TrySendError<T>) -> Self {
+                match error {
+                    std::sync::mpsc::TrySendError::Full(message) => $type_name::$full_variant(message),
+                    std::sync::mpsc::TrySendError::Disconnected(message) => $type_name::$disconnected_variant(message),
+                }
+            }
+        }
+
+        impl<T> From<$type_name<T>> for std::sync::mpsc::TrySendError<T> {
+            fn from(val: $type_name<T>) -> Self {
+                match val {
+                    $type_name::$full_variant(message) => std::sync::mpsc::TrySendError::Full(message),
+                    $type_name::$disconnected_variant(message) => std::sync::mpsc::TrySendError::Disconnected(message),
+                }
+            }
+        }
+        }
+    };
+}
+
+/// Creates a [`TryLockError`](std::sync::TryLockError)-like enum with custom variant names.
+///
+/// See [`examples::TryLockErrorExample`](crate::examples::TryLockErrorExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_try_lock_error;
+/// use std::sync::{Mutex, MutexGuard};
+///
+/// alias_try_lock_error!(Contended, Poisoned, Busy);
+///
+/// let mutex = Mutex::new(1);
+/// let guard = mutex.lock().unwrap();
+/// let busy: Contended<MutexGuard<'_, i32>> = mutex.try_lock().unwrap_err().into();
+/// assert!(busy.is_busy());
+/// assert!(busy.poisoned().is_none());
+/// drop(guard);
+/// ```
+///
+/// # Recovering From Poisoning
+///
+/// ```
+/// use enumizer::alias_try_lock_error;
+/// use std::sync::{Mutex, MutexGuard};
+/// alias_try_lock_error!(Contended, Poisoned, Busy);
+///
+/// let mutex = Mutex::new(1);
+/// let _ = std::panic::catch_unwind(|| {
+///     let _guard = mutex.lock().unwrap();
+///     panic!("poisoning the mutex");
+/// });
+///
+/// let poisoned: Contended<MutexGuard<'_, i32>> = mutex.try_lock().unwrap_err().into();
+/// assert!(poisoned.is_poisoned());
+/// assert_eq!(*poisoned.poisoned().unwrap().into_inner(), 1);
+/// ```
+///
+/// # Custom Traits
+///
+/// Only `Debug` is derived by default, since [`PoisonError`](std::sync::PoisonError) implements
+/// nothing else. You can specify custom traits to derive instead, using the `traits:` keyword
+/// followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_try_lock_error;
+/// alias_try_lock_error!(CustomContended, Poisoned, Busy, traits: []);
+/// let val = CustomContended::<()>::Busy;
+/// assert!(val.is_busy());
+/// ```
+#[macro_export]
+macro_rules! alias_try_lock_error {
+    ($type_name:ident, $poisoned_variant:ident, $would_block_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_try_lock_error [$type_name, $poisoned_variant, $would_block_variant] [T] [Debug] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $poisoned_variant:ident, $would_block_variant:ident], [$T:ident], [], [], [], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T> {
+            $poisoned_variant(std::sync::PoisonError<$T>),
+            $would_block_variant,
+        }
+
+        #[allow(dead_code)]
+        impl<T> $type_name<T> {
+            /// Returns true if the lock was poisoned
+            $vis fn [<is_ $poisoned_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$poisoned_variant(_))
+            }
+
+            /// Returns true if the lock could not be acquired without blocking
+            $vis fn [<is_ $would_block_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$would_block_variant)
+            }
+
+            /// Converts into an `Option` holding the poison error, if the lock was poisoned
+            $vis fn [<$poisoned_variant:lower>](self) -> Option<std::sync::PoisonError<T>> {
+                match self {
+                    $type_name::$poisoned_variant(error) => Some(error),
+                    $type_name::$would_block_variant => None,
+                }
+            }
+        }
+
+        impl<T> From<std::sync::TryLockError<T>> for $type_name<T> {
+            fn from(error: std::sync::TryLockError<T>) -> Self {
+                match error {
+                    std::sync::TryLockError::Poisoned(error) => $type_name::$poisoned_variant(error),
+                    std::sync::TryLockError::WouldBlock => $type_name::$would_block_variant,
+                }
+            }
+        }
+
+        impl<T> From<$type_name<T>> for std::sync::TryLockError<T> {
+            fn from(val: $type_name<T>) -> Self {
+                match val {
+                    $type_name::$poisoned_variant(error) => std::sync::TryLockError::Poisoned(error),
+                    $type_name::$would_block_variant => std::sync::TryLockError::WouldBlock,
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::{self, TrySendError};
+    use std::sync::{Mutex, TryLockError};
+    use std::time::Duration;
+
+    #[test]
+    fn receive() {
+        alias_try_recv_error!(Inbox, NothingYet, Closed);
+        alias_recv_timeout_error!(Wait, Expired, Closed);
+
+        let (sender, receiver) = mpsc::channel::<u8>();
+        assert_eq!(
+            Inbox::from(receiver.try_recv().unwrap_err()),
+            Inbox::NothingYet
+        );
+        let timeout = receiver.recv_timeout(Duration::ZERO).unwrap_err();
+        assert!(Wait::from(timeout).is_expired());
+        drop(sender);
+        assert!(Inbox::from(receiver.try_recv().unwrap_err()).is_closed());
+        let closed = receiver.recv_timeout(Duration::ZERO).unwrap_err();
+        assert_eq!(Wait::from(closed), Wait::Closed);
+    }
+
+    #[test]
+    fn send() {
+        alias_try_send_error!(Offer, Busy, Closed);
+
+        let (sender, receiver) = mpsc::sync_channel(1);
+        sender.try_send(1).unwrap();
+        let busy = Offer::from(sender.try_send(2).unwrap_err());
+        assert!(busy.is_busy());
+        assert_eq!(busy.into_inner(), 2);
+        drop(receiver);
+        let closed = Offer::from(sender.try_send(3).unwrap_err());
+        assert_eq!(closed, Offer::Closed(3));
+        assert_eq!(TrySendError::from(closed), TrySendError::Disconnected(3));
+    }
+
+    #[test]
+    fn lock() {
+        alias_try_lock_error!(Contended, Poisoned, Busy);
+
+        let mutex = Mutex::new(1);
+        let guard = mutex.lock().unwrap();
+        assert!(Contended::from(mutex.try_lock().unwrap_err()).is_busy());
+        drop(guard);
+
+        let _ = std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let _guard = mutex.lock().unwrap();
+                    panic!("poison the mutex");
+                })
+                .join()
+        });
+        let poisoned = Contended::from(mutex.try_lock().unwrap_err());
+        assert!(poisoned.is_poisoned());
+        assert_eq!(*poisoned.poisoned().unwrap().into_inner(), 1);
+        assert!(matches!(
+            TryLockError::from(Contended::<()>::Busy),
+            TryLockError::WouldBlock
+        ));
+    }
+}
@@ -0,0 +1,314 @@
+/// Creates a These-like enum with custom variant names, holding a left value, a right value, or both.
+///
+/// See [`examples::TheseExample`](crate::examples::TheseExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_these;
+///
+/// alias_these!(Overlap, OnlyLeft, OnlyRight, Both);
+///
+/// let left: Overlap<i32, &str> = Overlap::OnlyLeft(1);
+/// let both: Overlap<i32, &str> = Overlap::Both(1, "one");
+///
+/// assert!(left.is_onlyleft());
+/// assert!(both.is_both());
+/// assert!(both.has_left() && both.has_right());
+/// assert!(!left.has_right());
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_these;
+/// alias_these!(Overlap, OnlyLeft, OnlyRight, Both);
+///
+/// let both: Overlap<i32, &str> = Overlap::Both(2, "two");
+/// assert_eq!(both.map_left(|x| x * 10), Overlap::Both(20, "two"));
+/// assert_eq!(both.map_right(str::len), Overlap::Both(2, 3));
+/// assert_eq!(both.map_any(|x| x + 1, str::to_uppercase), Overlap::Both(3, "TWO".to_string()));
+/// assert_eq!(both.flip(), Overlap::Both("two", 2));
+///
+/// assert_eq!(both.into_pair_options(), (Some(2), Some("two")));
+/// assert_eq!(Overlap::<i32, &str>::OnlyRight("x").left(), None);
+/// assert_eq!(Overlap::<i32, &str>::OnlyRight("x").right(), Some("x"));
+///
+/// assert_eq!(Overlap::from_options(Some(1), None::<&str>), Some(Overlap::OnlyLeft(1)));
+/// assert_eq!(Overlap::<i32, &str>::from_options(None, None), None);
+/// ```
+///
+/// # Zip Longest
+///
+/// ```
+/// use enumizer::alias_these;
+/// alias_these!(Overlap, OnlyLeft, OnlyRight, Both);
+///
+/// let mut left = [1, 2, 3].into_iter();
+/// let mut right = ["a"].into_iter();
+/// let zipped: Vec<_> = std::iter::from_fn(|| Overlap::from_options(left.next(), right.next())).collect();
+///
+/// assert_eq!(zipped, [Overlap::Both(1, "a"), Overlap::OnlyLeft(2), Overlap::OnlyLeft(3)]);
+/// ```
+///
+/// # `itertools` Crate Interop
+///
+/// With the `itertools` feature enabled, the generated type converts to and from
+/// `itertools::EitherOrBoth<L, R>`.
+///
+/// ```
+/// # #[cfg(feature = "itertools")] {
+/// use enumizer::alias_these;
+/// use itertools::{EitherOrBoth, Itertools};
+/// alias_these!(Overlap, OnlyLeft, OnlyRight, Both);
+///
+/// let zipped: Vec<Overlap<i32, char>> = [1, 2].into_iter().zip_longest(['a']).map(Overlap::from).collect();
+/// assert_eq!(zipped, [Overlap::Both(1, 'a'), Overlap::OnlyLeft(2)]);
+///
+/// let back: EitherOrBoth<i32, char> = Overlap::OnlyRight('b').into();
+/// assert_eq!(back, EitherOrBoth::Right('b'));
+/// # }
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_these;
+/// alias_these!(CustomThese, This, That, These, traits: [Debug, Clone]);
+/// let val: CustomThese<i32, ()> = CustomThese::This(42);
+/// assert_eq!(format!("{:?}", val.clone()), "This(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_these {
+    ($type_name:ident, $left_variant:ident, $right_variant:ident, $both_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_these [$type_name, $left_variant, $right_variant, $both_variant] [L, R] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $left_variant:ident, $right_variant:ident, $both_variant:ident], [$L:ident, $R:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$L, $R] [$type_name [$left_variant($L), $right_variant($R), $both_variant($L, $R)]], [$($skip),*]);
+        $crate::__alias_these_interop!($type_name, $left_variant, $right_variant, $both_variant);
+
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$L, $R> {
+            $left_variant($L),
+            $right_variant($R),
+            $both_variant($L, $R),
+        }
+
+        #[allow(dead_code)]
+        impl<L, R> $type_name<L, R> {
+            /// Returns true if this holds only a left value
+            $vis fn [<is_ $left_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$left_variant(_))
+            }
+
+            /// Returns true if this holds only a right value
+            $vis fn [<is_ $right_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$right_variant(_))
+            }
+
+            /// Returns true if this holds both values
+            $vis fn [<is_ $both_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$both_variant(_, _))
+            }
+
+            /// Returns true if this holds a left value, alone or together with a right value
+            $vis fn has_left(&self) -> bool {
+                !self.[<is_ $right_variant:lower>]()
+            }
+
+            /// Returns true if this holds a right value, alone or together with a left value
+            $vis fn has_right(&self) -> bool {
+                !self.[<is_ $left_variant:lower>]()
+            }
+
+            /// Converts into an `Option` holding the left value, if present
+            $vis fn left(self) -> Option<L> {
+                self.into_pair_options().0
+            }
+
+            /// Converts into an `Option` holding the right value, if present
+            $vis fn right(self) -> Option<R> {
+                self.into_pair_options().1
+            }
+
+            /// Converts into a pair of options, one for each side
+            $vis fn into_pair_options(self) -> (Option<L>, Option<R>) {
+                match self {
+                    $type_name::$left_variant(l) => (Some(l), None),
+                    $type_name::$right_variant(r) => (None, Some(r)),
+                    $type_name::$both_variant(l, r) => (Some(l), Some(r)),
+                }
+            }
+
+            /// Creates a value from a pair of options, returning `None` if both are `None`
+            $vis fn from_options(left: Option<L>, right: Option<R>) -> Option<Self> {
+                match (left, right) {
+                    (Some(l), None) => Some($type_name::$left_variant(l)),
+                    (None, Some(r)) => Some($type_name::$right_variant(r)),
+                    (Some(l), Some(r)) => Some($type_name::$both_variant(l, r)),
+                    (None, None) => None,
+                }
+            }
+
+            /// Converts from `&Self` to an alias holding references to the values
+            $vis fn as_ref(&self) -> $type_name<&L, &R> {
+                match self {
+                    $type_name::$left_variant(l) => $type_name::$left_variant(l),
+                    $type_name::$right_variant(r) => $type_name::$right_variant(r),
+                    $type_name::$both_variant(l, r) => $type_name::$both_variant(l, r),
+                }
+            }
+
+            /// Applies `f` to the left value if present, leaving the right value untouched
+            $vis fn map_left<T, F: FnOnce(L) -> T>(self, f: F) -> $type_name<T, R> {
+                match self {
+                    $type_name::$left_variant(l) => $type_name::$left_variant(f(l)),
+                    $type_name::$right_variant(r) => $type_name::$right_variant(r),
+                    $type_name::$both_variant(l, r) => $type_name::$both_variant(f(l), r),
+                }
+            }
+
+            /// Applies `f` to the right value if present, leaving the left value untouched
+            $vis fn map_right<T, F: FnOnce(R) -> T>(self, f: F) -> $type_name<L, T> {
+                match self {
+                    $type_name::$left_variant(l) => $type_name::$left_variant(l),
+                    $type_name::$right_variant(r) => $type_name::$right_variant(f(r)),
+                    $type_name::$both_variant(l, r) => $type_name::$both_variant(l, f(r)),
+                }
+            }
+
+            /// Applies `f` to the left value and `g` to the right value, whichever are present
+            $vis fn map_any<M, N, F: FnOnce(L) -> M, G: FnOnce(R) -> N>(self, f: F, g: G) -> $type_name<M, N> {
+                match self {
+                    $type_name::$left_variant(l) => $type_name::$left_variant(f(l)),
+                    $type_name::$right_variant(r) => $type_name::$right_variant(g(r)),
+                    $type_name::$both_variant(l, r) => $type_name::$both_variant(f(l), g(r)),
+                }
+            }
+
+            /// Swaps the two sides
+            $vis fn flip(self) -> $type_name<R, L> {
+                match self {
+                    $type_name::$left_variant(l) => $type_name::$right_variant(l),
+                    $type_name::$right_variant(r) => $type_name::$left_variant(r),
+                    $type_name::$both_variant(l, r) => $type_name::$both_variant(r, l),
+                }
+            }
+        }
+
+        impl<L, R> From<(L, R)> for $type_name<L, R> {
+            fn from((l, r): (L, R)) -> Self {
+                $type_name::$both_variant(l, r)
+            }
+        }
+        }
+    };
+}
+
+#[cfg(feature = "itertools")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_these_interop {
+    ($type_name:ident, $left_variant:ident, $right_variant:ident, $both_variant:ident) => {
+        impl<L, R> From<$crate::__private::itertools::EitherOrBoth<L, R>> for $type_name<L, R> {
+            fn from(val: $crate::__private::itertools::EitherOrBoth<L, R>) -> Self {
+                match val {
+                    $crate::__private::itertools::EitherOrBoth::Left(l) => {
+                        $type_name::$left_variant(l)
+                    }
+                    $crate::__private::itertools::EitherOrBoth::Right(r) => {
+                        $type_name::$right_variant(r)
+                    }
+                    $crate::__private::itertools::EitherOrBoth::Both(l, r) => {
+                        $type_name::$both_variant(l, r)
+                    }
+                }
+            }
+        }
+
+        impl<L, R> From<$type_name<L, R>> for $crate::__private::itertools::EitherOrBoth<L, R> {
+            fn from(val: $type_name<L, R>) -> Self {
+                match val {
+                    $type_name::$left_variant(l) => {
+                        $crate::__private::itertools::EitherOrBoth::Left(l)
+                    }
+                    $type_name::$right_variant(r) => {
+                        $crate::__private::itertools::EitherOrBoth::Right(r)
+                    }
+                    $type_name::$both_variant(l, r) => {
+                        $crate::__private::itertools::EitherOrBoth::Both(l, r)
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "itertools"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_these_interop {
+    ($type_name:ident, $left_variant:ident, $right_variant:ident, $both_variant:ident) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    alias_these!(Overlap, OnlyLeft, OnlyRight, Both);
+
+    const VALUES: [Overlap<u8, char>; 3] = [
+        Overlap::OnlyLeft(1),
+        Overlap::OnlyRight('a'),
+        Overlap::Both(2, 'b'),
+    ];
+
+    #[test]
+    fn options() {
+        for value in VALUES {
+            let (left, right) = value.into_pair_options();
+            assert_eq!(Overlap::from_options(left, right), Some(value));
+            assert_eq!(value.left(), left);
+            assert_eq!(value.right(), right);
+            assert_eq!(value.has_left(), left.is_some());
+            assert_eq!(value.has_right(), right.is_some());
+        }
+        assert_eq!(Overlap::<u8, char>::from_options(None, None), None);
+        assert_eq!(Overlap::from((3, 'c')), Overlap::Both(3, 'c'));
+    }
+
+    #[test]
+    fn map() {
+        let both: Overlap<u8, char> = Overlap::Both(2, 'b');
+        assert_eq!(both.map_left(u16::from), Overlap::Both(2u16, 'b'));
+        assert_eq!(
+            both.map_right(|c| c.to_ascii_uppercase()),
+            Overlap::Both(2, 'B')
+        );
+        assert_eq!(
+            Overlap::<u8, char>::OnlyRight('a').map_any(u16::from, u32::from),
+            Overlap::OnlyRight(97u32)
+        );
+        assert_eq!(both.flip(), Overlap::Both('b', 2));
+        assert_eq!(
+            Overlap::<u8, char>::OnlyLeft(1).flip(),
+            Overlap::OnlyRight(1)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "itertools")]
+    fn either_or_both() {
+        use itertools::EitherOrBoth;
+
+        for value in VALUES {
+            let converted = EitherOrBoth::from(value);
+            assert_eq!(converted.clone().left(), value.left());
+            assert_eq!(converted.clone().right(), value.right());
+            assert_eq!(Overlap::from(converted), value);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+/// Creates a three-state enum equivalent to `Option<bool>`, with custom variant names.
+///
+/// See [`examples::TristateExample`](crate::examples::TristateExample) for a generated example.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_tristate;
+///
+/// alias_tristate!(Consent, Granted, Denied, Unanswered);
+///
+/// let consent = Consent::Granted;
+///
+/// assert!(consent.is_granted());
+/// assert!(!consent.is_denied());
+/// assert!(Consent::Unanswered.is_unanswered());
+/// assert_eq!(Consent::Unanswered.unwrap_or(false), false);
+/// ```
+///
+/// # Logical Combinators
+///
+/// `and`, `or` and `!` follow three-valued (Kleene) logic, where the unknown state only decides
+/// the result when the known state doesn't.
+///
+/// ```
+/// use enumizer::alias_tristate;
+/// alias_tristate!(Consent, Granted, Denied, Unanswered);
+///
+/// assert_eq!(Consent::Denied.and(Consent::Unanswered), Consent::Denied);
+/// assert_eq!(Consent::Granted.and(Consent::Unanswered), Consent::Unanswered);
+/// assert_eq!(Consent::Granted.or(Consent::Unanswered), Consent::Granted);
+/// assert_eq!(Consent::Denied.or(Consent::Unanswered), Consent::Unanswered);
+///
+/// assert_eq!(!Consent::Granted, Consent::Denied);
+/// assert_eq!(!Consent::Unanswered, Consent::Unanswered);
+/// assert_eq!(Consent::Granted & Consent::Denied, Consent::Denied);
+/// assert_eq!(Consent::Granted | Consent::Denied, Consent::Granted);
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `Option<bool>`.
+///
+/// ```
+/// use enumizer::alias_tristate;
+/// alias_tristate!(Consent, Granted, Denied, Unanswered);
+///
+/// let from_option: Consent = Some(false).into();
+/// assert_eq!(from_option, Consent::Denied);
+///
+/// let from_bool: Consent = true.into();
+/// assert_eq!(from_bool, Consent::Granted);
+///
+/// let to_option: Option<bool> = Consent::Unanswered.into();
+/// assert_eq!(to_option, None);
+/// ```
+///
+/// # Serde Support
+///
+/// With the `serde` feature enabled, the generated type serializes exactly like `Option<bool>`.
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use enumizer::alias_tristate;
+/// alias_tristate!(Consent, Granted, Denied, Unanswered);
+///
+/// assert_eq!(serde_json::to_string(&Consent::Granted).unwrap(), "true");
+/// assert_eq!(serde_json::to_string(&Consent::Unanswered).unwrap(), "null");
+/// assert_eq!(serde_json::from_str::<Consent>("false").unwrap(), Consent::Denied);
+/// # }
+/// ```
+///
+/// # Attributes
+///
+/// Use `attrs:` to put attributes on the generated type, like a `#[repr]` that fixes the
+/// discriminants of the variants.
+///
+/// ```
+/// use enumizer::alias_tristate;
+/// alias_tristate!(Flag, On, Off, Unset, attrs: [#[repr(u8)]]);
+/// assert_eq!(Flag::Unset as u8, 2);
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_tristate;
+/// alias_tristate!(CustomFlag, On, Off, Unset, traits: [Debug, PartialEq]);
+/// assert_eq!(format!("{:?}", CustomFlag::Unset), "Unset");
+/// ```
+#[macro_export]
+macro_rules! alias_tristate {
+    ($type_name:ident, $true_variant:ident, $false_variant:ident, $unknown_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_tristate [$type_name, $true_variant, $false_variant, $unknown_variant] [] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $true_variant:ident, $false_variant:ident, $unknown_variant:ident], [], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [] [$type_name [$true_variant, $false_variant, $unknown_variant]], [$($skip),*]);
+        $crate::__alias_tristate_serde!($type_name, $true_variant, $false_variant, $unknown_variant);
+
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name {
+            $true_variant,
+            $false_variant,
+            $unknown_variant,
+        }
+
+        #[allow(dead_code)]
+        impl $type_name {
+            /// Returns true if this is the `Some(true)` equivalent variant
+            $vis fn [<is_ $true_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$true_variant)
+            }
+
+            /// Returns true if this is the `Some(false)` equivalent variant
+            $vis fn [<is_ $false_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$false_variant)
+            }
+
+            /// Returns true if this is the `None` equivalent variant
+            $vis fn [<is_ $unknown_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$unknown_variant)
+            }
+
+            /// Returns the known value, or `default` for the unknown variant
+            $vis fn unwrap_or(self, default: bool) -> bool {
+                Option::<bool>::from(self).unwrap_or(default)
+            }
+
+            /// Kleene conjunction: false if either side is false, unknown if either side is unknown
+            $vis fn and(self, other: Self) -> Self {
+                match (self, other) {
+                    ($type_name::$false_variant, _) | (_, $type_name::$false_variant) => $type_name::$false_variant,
+                    ($type_name::$true_variant, $type_name::$true_variant) => $type_name::$true_variant,
+                    _ => $type_name::$unknown_variant,
+                }
+            }
+
+            /// Kleene disjunction: true if either side is true, unknown if either side is unknown
+            $vis fn or(self, other: Self) -> Self {
+                match (self, other) {
+                    ($type_name::$true_variant, _) | (_, $type_name::$true_variant) => $type_name::$true_variant,
+                    ($type_name::$false_variant, $type_name::$false_variant) => $type_name::$false_variant,
+                    _ => $type_name::$unknown_variant,
+                }
+            }
+        }
+
+        impl ::core::ops::Not for $type_name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                match self {
+                    $type_name::$true_variant => $type_name::$false_variant,
+                    $type_name::$false_variant => $type_name::$true_variant,
+                    $type_name::$unknown_variant => $type_name::$unknown_variant,
+                }
+            }
+        }
+
+        impl ::core::ops::BitAnd for $type_name {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                self.and(other)
+            }
+        }
+
+        impl ::core::ops::BitOr for $type_name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.or(other)
+            }
+        }
+
+        impl From<Option<bool>> for $type_name {
+            fn from(option: Option<bool>) -> Self {
+                match option {
+                    Some(true) => $type_name::$true_variant,
+                    Some(false) => $type_name::$false_variant,
+                    None => $type_name::$unknown_variant,
+                }
+            }
+        }
+
+        impl From<bool> for $type_name {
+            fn from(val: bool) -> Self {
+                Some(val).into()
+            }
+        }
+
+        impl From<$type_name> for Option<bool> {
+            fn from(val: $type_name) -> Self {
+                match val {
+                    $type_name::$true_variant => Some(true),
+                    $type_name::$false_variant => Some(false),
+                    $type_name::$unknown_variant => None,
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_tristate_serde {
+    ($type_name:ident, $true_variant:ident, $false_variant:ident, $unknown_variant:ident) => {
+        impl $crate::__private::serde::Serialize for $type_name {
+            fn serialize<S: $crate::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                let value: Option<bool> = match self {
+                    $type_name::$true_variant => Some(true),
+                    $type_name::$false_variant => Some(false),
+                    $type_name::$unknown_variant => None,
+                };
+                $crate::__private::serde::Serialize::serialize(&value, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $type_name {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                <Option<bool> as $crate::__private::serde::Deserialize<'de>>::deserialize(
+                    deserializer,
+                )
+                .map(Self::from)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alias_tristate_serde {
+    ($type_name:ident, $true_variant:ident, $false_variant:ident, $unknown_variant:ident) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    alias_tristate!(Consent, Granted, Denied, Unanswered);
+
+    const ALL: [Consent; 3] = [Consent::Granted, Consent::Denied, Consent::Unanswered];
+
+    #[test]
+    fn kleene_logic() {
+        for a in ALL {
+            assert_eq!(!!a, a);
+            for b in ALL {
+                assert_eq!(a & b, a.and(b));
+                assert_eq!(a | b, a.or(b));
+                assert_eq!(!(a & b), !a | !b);
+                if let (Some(x), Some(y)) = (Option::<bool>::from(a), Option::<bool>::from(b)) {
+                    assert_eq!(a & b, Consent::from(x && y));
+                    assert_eq!(a | b, Consent::from(x || y));
+                }
+            }
+        }
+        assert_eq!(Consent::Denied & Consent::Unanswered, Consent::Denied);
+        assert_eq!(Consent::Granted | Consent::Unanswered, Consent::Granted);
+        assert!((Consent::Granted & Consent::Unanswered).is_unanswered());
+    }
+
+    #[test]
+    fn conversions() {
+        for option in [Some(true), Some(false), None] {
+            assert_eq!(Option::<bool>::from(Consent::from(option)), option);
+        }
+        assert!(Consent::from(false).is_denied());
+        assert!(Consent::from(true).is_granted());
+        assert!(Consent::Unanswered.unwrap_or(true));
+        assert!(!Consent::Denied.unwrap_or(true));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&ALL).unwrap(), "[true,false,null]");
+        assert_eq!(
+            serde_json::from_str::<[Consent; 3]>("[true,false,null]").unwrap(),
+            ALL
+        );
+    }
+}
@@ -0,0 +1,289 @@
+/// Creates a Validation-like enum with custom variant names, whose failure side accumulates errors.
+///
+/// See [`examples::ValidationExample`](crate::examples::ValidationExample) for a generated example.
+///
+/// The generated type has the same shape as `Result<T, E>`, but its combinators don't
+/// short-circuit: when both sides are invalid, their errors are merged. The error type is a
+/// collection such as `Vec<E>`, and can be any type implementing `Extend` and `IntoIterator`.
+///
+/// # Example
+///
+/// ```
+/// use enumizer::alias_validation;
+///
+/// alias_validation!(Checked, Valid, Invalid);
+///
+/// let valid: Checked<i32, Vec<String>> = Checked::Valid(42);
+/// let invalid: Checked<i32, Vec<String>> = Checked::Invalid(vec!["too small".to_string()]);
+///
+/// assert!(valid.is_valid());
+/// assert!(invalid.is_invalid());
+/// ```
+///
+/// # Accumulating Errors
+///
+/// ```
+/// use enumizer::alias_validation;
+/// alias_validation!(Checked, Valid, Invalid);
+///
+/// fn check_name(name: &str) -> Checked<String, Vec<&'static str>> {
+///     if name.is_empty() { Checked::from_error("name is empty") } else { Checked::Valid(name.to_string()) }
+/// }
+///
+/// fn check_age(age: i32) -> Checked<u8, Vec<&'static str>> {
+///     match u8::try_from(age) {
+///         Ok(age) => Checked::Valid(age),
+///         Err(_) => Checked::from_error("age out of range"),
+///     }
+/// }
+///
+/// assert_eq!(check_name("Ann").zip(check_age(30)), Checked::Valid(("Ann".to_string(), 30)));
+/// assert_eq!(
+///     check_name("").zip(check_age(-1)),
+///     Checked::Invalid(vec!["name is empty", "age out of range"])
+/// );
+///
+/// // `and` keeps the second value, but still collects errors from both sides
+/// assert_eq!(check_name("").and(check_age(200)), Checked::Invalid(vec!["name is empty"]));
+///
+/// let greeting = check_name("Bob").zip_with(check_age(5), |name, age| format!("{name} ({age})"));
+/// assert_eq!(greeting, Checked::Valid("Bob (5)".to_string()));
+///
+/// // Collecting gathers every error instead of stopping at the first one
+/// let ages: Checked<Vec<u8>, Vec<&str>> = [1, -1, 300].into_iter().map(check_age).collect();
+/// assert_eq!(ages, Checked::Invalid(vec!["age out of range", "age out of range"]));
+/// ```
+///
+/// # Generated Methods
+///
+/// ```
+/// use enumizer::alias_validation;
+/// alias_validation!(Checked, Valid, Invalid);
+///
+/// let valid: Checked<i32, Vec<String>> = Checked::Valid(2);
+/// assert_eq!(valid.clone().map(|x| x * 10), Checked::Valid(20));
+/// assert_eq!(valid.clone().valid(), Some(2));
+/// assert_eq!(valid.invalid(), None);
+///
+/// let invalid: Checked<i32, Vec<&str>> = Checked::Invalid(vec!["bad"]);
+/// assert_eq!(invalid.clone().map_invalid(|errors| errors.len()), Checked::Invalid(1));
+/// assert_eq!(invalid.invalid(), Some(vec!["bad"]));
+/// ```
+///
+/// # Conversions
+///
+/// The generated type can be easily converted to and from `Result<T, E>`.
+///
+/// ```
+/// use enumizer::alias_validation;
+/// alias_validation!(Checked, Valid, Invalid);
+///
+/// let from_result: Checked<i32, Vec<&str>> = Err(vec!["bad"]).into();
+/// assert_eq!(from_result, Checked::Invalid(vec!["bad"]));
+///
+/// let to_result: Result<i32, Vec<&str>> = Checked::Valid(1).into();
+/// assert_eq!(to_result, Ok(1));
+/// ```
+///
+/// # Custom Traits
+///
+/// You can specify custom traits to derive instead of the default set.
+/// Use the `traits:` keyword followed by a list of trait names in brackets.
+///
+/// ```
+/// use enumizer::alias_validation;
+/// alias_validation!(CustomValidation, Accepted, Rejected, traits: [Debug, Clone]);
+/// let val: CustomValidation<i32, Vec<()>> = CustomValidation::Accepted(42);
+/// assert_eq!(format!("{:?}", val.clone()), "Accepted(42)");
+/// ```
+#[macro_export]
+macro_rules! alias_validation {
+    ($type_name:ident, $valid_variant:ident, $invalid_variant:ident $(, $($option:tt)*)?) => {
+        $crate::__alias_options!(alias_validation [$type_name, $valid_variant, $invalid_variant] [T, E] [Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash] $(, $($option)*)?);
+    };
+    (@impl $vis:vis [$type_name:ident, $valid_variant:ident, $invalid_variant:ident], [$T:ident, $E:ident], [], [], [$($skip:tt),*], [$($trait:path),*], [$(#[$attr:meta])*], []) => {
+        $crate::__alias_impls!(variants [$T, $E] [$type_name [$valid_variant($T), $invalid_variant($E)]], [$($skip),*]);
+        paste::paste! {
+        #[derive($($trait),*)]
+        $(#[$attr])*
+        $vis enum $type_name<$T, $E> {
+            $valid_variant($T),
+            $invalid_variant($E),
+        }
+
+        #[allow(dead_code)]
+        impl<T, E> $type_name<T, E> {
+            /// Returns true if this is the valid variant
+            $vis fn [<is_ $valid_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$valid_variant(_))
+            }
+
+            /// Returns true if this is the invalid variant
+            $vis fn [<is_ $invalid_variant:lower>](&self) -> bool {
+                matches!(self, $type_name::$invalid_variant(_))
+            }
+
+            /// Converts into an `Option` holding the valid value, discarding the errors
+            $vis fn [<$valid_variant:lower>](self) -> Option<T> {
+                match self {
+                    $type_name::$valid_variant(v) => Some(v),
+                    $type_name::$invalid_variant(_) => None,
+                }
+            }
+
+            /// Converts into an `Option` holding the errors, discarding the valid value
+            $vis fn [<$invalid_variant:lower>](self) -> Option<E> {
+                match self {
+                    $type_name::$valid_variant(_) => None,
+                    $type_name::$invalid_variant(e) => Some(e),
+                }
+            }
+
+            /// Applies `f` to the valid value, leaving the errors untouched
+            $vis fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $type_name<U, E> {
+                match self {
+                    $type_name::$valid_variant(v) => $type_name::$valid_variant(f(v)),
+                    $type_name::$invalid_variant(e) => $type_name::$invalid_variant(e),
+                }
+            }
+
+            /// Applies `f` to the errors, leaving the valid value untouched
+            $vis fn [<map_ $invalid_variant:lower>]<G, F: FnOnce(E) -> G>(self, f: F) -> $type_name<T, G> {
+                match self {
+                    $type_name::$valid_variant(v) => $type_name::$valid_variant(v),
+                    $type_name::$invalid_variant(e) => $type_name::$invalid_variant(f(e)),
+                }
+            }
+
+            /// Creates the invalid variant from a single error
+            $vis fn from_error<I>(error: I) -> Self
+            where
+                E: FromIterator<I>,
+            {
+                $type_name::$invalid_variant(::core::iter::once(error).collect())
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T, E> $type_name<T, E>
+        where
+            E: Extend<<E as IntoIterator>::Item> + IntoIterator,
+        {
+            /// Combines two values into a pair, merging the errors of both if either is invalid
+            $vis fn zip<U>(self, other: $type_name<U, E>) -> $type_name<(T, U), E> {
+                self.zip_with(other, |t, u| (t, u))
+            }
+
+            /// Combines two values with `f`, merging the errors of both if either is invalid
+            $vis fn zip_with<U, R, F: FnOnce(T, U) -> R>(self, other: $type_name<U, E>, f: F) -> $type_name<R, E> {
+                match (self, other) {
+                    ($type_name::$valid_variant(t), $type_name::$valid_variant(u)) => $type_name::$valid_variant(f(t, u)),
+                    ($type_name::$valid_variant(_), $type_name::$invalid_variant(e)) => $type_name::$invalid_variant(e),
+                    ($type_name::$invalid_variant(e), $type_name::$valid_variant(_)) => $type_name::$invalid_variant(e),
+                    ($type_name::$invalid_variant(mut e), $type_name::$invalid_variant(other)) => {
+                        e.extend(other);
+                        $type_name::$invalid_variant(e)
+                    }
+                }
+            }
+
+            /// Returns `other` if both values are valid, merging the errors of both otherwise
+            $vis fn and<U>(self, other: $type_name<U, E>) -> $type_name<U, E> {
+                self.zip_with(other, |_, u| u)
+            }
+        }
+
+        impl<T, E> From<Result<T, E>> for $type_name<T, E> {
+            fn from(result: Result<T, E>) -> Self {
+                match result {
+                    Ok(v) => $type_name::$valid_variant(v),
+                    Err(e) => $type_name::$invalid_variant(e),
+                }
+            }
+        }
+
+        impl<T, E> From<$type_name<T, E>> for Result<T, E> {
+            fn from(val: $type_name<T, E>) -> Self {
+                match val {
+                    $type_name::$valid_variant(v) => Ok(v),
+                    $type_name::$invalid_variant(e) => Err(e),
+                }
+            }
+        }
+
+        impl<T, V, E> FromIterator<$type_name<T, E>> for $type_name<V, E>
+        where
+            V: FromIterator<T>,
+            E: Extend<<E as IntoIterator>::Item> + IntoIterator,
+        {
+            fn from_iter<I: IntoIterator<Item = $type_name<T, E>>>(iter: I) -> Self {
+                let mut errors: Option<E> = None;
+                let values: V = iter
+                    .into_iter()
+                    .filter_map(|item| match item {
+                        $type_name::$valid_variant(v) => Some(v),
+                        $type_name::$invalid_variant(e) => {
+                            match &mut errors {
+                                Some(errors) => errors.extend(e),
+                                None => errors = Some(e),
+                            }
+                            None
+                        }
+                    })
+                    .collect();
+                match errors {
+                    Some(errors) => $type_name::$invalid_variant(errors),
+                    None => $type_name::$valid_variant(values),
+                }
+            }
+        }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    alias_validation!(Checked, Valid, Invalid);
+
+    fn positive(value: i32) -> Checked<i32, Vec<String>> {
+        if value > 0 {
+            Checked::Valid(value)
+        } else {
+            Checked::from_error(format!("{value} is not positive"))
+        }
+    }
+
+    #[test]
+    fn accumulates_errors() {
+        assert_eq!(positive(1).zip(positive(2)), Checked::Valid((1, 2)));
+        assert_eq!(
+            positive(-1).and(positive(2)).and(positive(0)),
+            Checked::Invalid(vec![
+                "-1 is not positive".to_string(),
+                "0 is not positive".to_string()
+            ])
+        );
+        assert_eq!(
+            positive(3).zip_with(positive(4), |a, b| a * b).valid(),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn collect() {
+        let all: Checked<Vec<i32>, Vec<String>> = [1, 2, 3].into_iter().map(positive).collect();
+        assert_eq!(all, Checked::Valid(vec![1, 2, 3]));
+        let some: Checked<Vec<i32>, Vec<String>> = [-1, 2, -3].into_iter().map(positive).collect();
+        assert_eq!(some.invalid().map(|errors| errors.len()), Some(2));
+    }
+
+    #[test]
+    fn conversions() {
+        let checked = Checked::<_, Vec<String>>::from(Ok::<_, Vec<String>>(2)).map(|v| v * 2);
+        assert!(checked.is_valid());
+        assert_eq!(Result::from(checked), Ok(4));
+        let failed = positive(0).map_invalid(|errors| errors.len());
+        assert!(failed.is_invalid());
+        assert_eq!(Result::from(failed), Err(1));
+    }
+}
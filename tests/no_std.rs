@@ -0,0 +1,58 @@
+//! Checks that the default expansion of the macros, and the flags that do not need std, only use
+//! `core` paths.
+
+#![no_std]
+
+enumizer::alias_option!(Nickname, Given, Unset);
+enumizer::alias_option!(Rank, Ranked, Unranked, none_last);
+enumizer::alias_result!(Reply, Delivered, Bounced);
+enumizer::alias_result!(
+    Lookup,
+    Found,
+    Missing,
+    err_first,
+    default_ok,
+    implement_error,
+    implement_unchecked,
+    assert_same_size
+);
+enumizer::alias_result!(Checked, Passed, Rejected, error = u8);
+enumizer::alias_either!(Route, Local, Remote);
+enumizer::alias_either!(Branch, Near, Far, left_is_ok, pin_project);
+enumizer::alias_either3!(Shape, Circle, Square, Triangle);
+enumizer::alias_bound!(Limit, Inclusive, Exclusive, Open);
+enumizer::alias_control_flow!(Step, Halt, Proceed, assert_same_size);
+enumizer::alias_ordering!(Rel, Below, Same, Above);
+enumizer::alias_poll!(Progress, Finished, Waiting, assert_same_size);
+enumizer::alias_maybe_done!(Job, Running, Complete, Collected);
+enumizer::alias_ipaddr!(Address, V4, V6);
+enumizer::alias_tristate!(Switch, On, Off, Unknown);
+enumizer::alias_validation!(Check, Passed, Failed);
+enumizer::alias_these!(Pair, OnlyLeft, OnlyRight, Both);
+
+#[test]
+fn no_std_expansion() {
+    assert!(Nickname::Given(1).is_given());
+    assert!(Rank::Ranked(1) < Rank::Unranked);
+    assert_eq!(Reply::<u8, ()>::Delivered(1).delivered(), Some(1));
+    assert!(Lookup::<u8, ()>::Missing(()) < Lookup::Found(1));
+    assert!(Checked::Passed(1).is_passed());
+    assert!(Route::<u8, ()>::Local(1).is_local());
+    assert!(Step::<u8, ()>::Proceed(1).is_proceed());
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_flag {
+    enumizer::alias_option!(Nickname, Given, Unset, defmt, traits: []);
+    enumizer::alias_result!(Reply, Delivered, Bounced, defmt, traits: []);
+    enumizer::alias_either!(Route, Local, Remote, defmt, traits: []);
+
+    #[test]
+    fn defmt() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<Nickname<u8>>();
+        assert_format::<Reply<u8, u8>>();
+        assert_format::<Route<u8, u8>>();
+    }
+}